@@ -1,7 +1,15 @@
+use super::formatters::changelog::{self, ChangelogPrinter};
+use super::formatters::changes::Change;
+use super::formatters::html::HtmlPrinter;
+use super::formatters::junit::JunitPrinter;
 use super::formatters::markdown::Printer;
-use crate::diff::diff_files;
+use super::formatters::sarif::SarifPrinter;
+use super::formatters::Source;
+use crate::diff::{diff, parse, Diff, DiffError};
+use crate::openapi::OpenAPI;
 use clap::arg_enum;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -17,6 +25,10 @@ pub struct DiffCommand {
     /// Output format for the diff
     #[structopt(short = "f", long = "format", default_value = "markdown", possible_values = &Format::variants(), case_insensitive = true)]
     format: Format,
+
+    /// With the changelog format, inserts the release notes into this Keep a Changelog file instead of printing them
+    #[structopt(long = "prepend-to", parse(from_os_str))]
+    prepend_to: Option<PathBuf>,
 }
 
 arg_enum! {
@@ -25,15 +37,28 @@ arg_enum! {
         Markdown,
         Json,
         Yaml,
+        Sarif,
+        Junit,
+        Html,
+        Changelog,
     }
 }
 
 impl DiffCommand {
     pub fn run(&self) {
-        let res = diff_files(self.base.clone(), self.head.clone());
+        if self.prepend_to.is_some() && !matches!(self.format, Format::Changelog) {
+            // Exits with a nonzero status like other usage errors.
+            clap::Error::with_description(
+                "--prepend-to can only be used with --format changelog",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+
+        let res = self.diff();
 
         match res {
-            Ok(diff) => match self.format {
+            Ok((base, head, diff)) => match self.format {
                 Format::Json => {
                     let json = serde_json::to_string_pretty(&diff)
                         .expect("Could not serialize diff to JSON");
@@ -48,10 +73,131 @@ impl DiffCommand {
                     let md = Printer::print(&diff);
                     println!("{}", md);
                 }
+                Format::Sarif => {
+                    let changes = Change::from_diff(&diff, &base.openapi, &head.openapi);
+                    let sarif = SarifPrinter {
+                        changes: &changes,
+                        base: &base.source(),
+                        head: &head.source(),
+                    }
+                    .print();
+                    println!("{}", sarif);
+                }
+                Format::Junit => {
+                    let changes = Change::from_diff(&diff, &base.openapi, &head.openapi);
+                    let junit = JunitPrinter {
+                        changes: &changes,
+                        base: &base.openapi,
+                        head: &head.openapi,
+                    }
+                    .print();
+                    print!("{}", junit);
+                }
+                Format::Html => {
+                    let changes = Change::from_diff(&diff, &base.openapi, &head.openapi);
+                    let html = HtmlPrinter {
+                        changes: &changes,
+                        base: &base.openapi,
+                        head: &head.openapi,
+                    }
+                    .print();
+                    print!("{}", html);
+                }
+                Format::Changelog => {
+                    let changes = Change::from_diff(&diff, &base.openapi, &head.openapi);
+                    let release = ChangelogPrinter {
+                        changes: &changes,
+                        version: &head.openapi.info.version,
+                        date: &today(),
+                    }
+                    .print();
+
+                    match &self.prepend_to {
+                        Some(path) => {
+                            if let Err(e) = prepend(path, &release) {
+                                eprintln!("Error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                        None => print!("{}", release),
+                    }
+                }
             },
             Err(e) => {
                 eprintln!("Error: {}", e)
             }
         }
     }
+
+    fn diff(&self) -> Result<(Document<'_>, Document<'_>, Diff), DiffError> {
+        let base = Document::read(&self.base)?;
+        let head = Document::read(&self.head)?;
+        let diff = diff(base.openapi.clone(), head.openapi.clone())?;
+
+        Ok((base, head, diff))
+    }
+}
+
+/// A parsed document along with its contents, which some formats point into.
+struct Document<'a> {
+    path: &'a Path,
+    contents: String,
+    openapi: OpenAPI,
+}
+
+impl<'a> Document<'a> {
+    fn read(path: &'a Path) -> Result<Self, DiffError> {
+        let contents = std::fs::read_to_string(path)?;
+        let openapi = parse(&contents)?;
+
+        Ok(Self {
+            path,
+            contents,
+            openapi,
+        })
+    }
+
+    fn source(&self) -> Source<'_> {
+        Source {
+            path: self.path,
+            contents: &self.contents,
+        }
+    }
+}
+
+/// Inserts a release into a changelog file, which is created if needed.
+fn prepend(path: &Path, release: &str) -> std::io::Result<()> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    std::fs::write(path, changelog::prepend(&contents, release))
+}
+
+/// The current UTC date as `YYYY-MM-DD`.
+fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+
+    // Converts days since the epoch to a civil date, from
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
 }
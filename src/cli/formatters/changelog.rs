@@ -0,0 +1,202 @@
+//! Prints changes as release notes in the Keep a Changelog style, grouped by
+//! operation tag.
+
+use super::changes::{Change, Kind, Severity, ENDPOINT_ADDED};
+use std::collections::BTreeMap;
+
+/// The group of changes that do not belong to an operation.
+const GENERAL: &str = "General";
+
+/// The group of operations without tags.
+const UNTAGGED: &str = "Other";
+
+pub struct ChangelogPrinter<'a> {
+    pub changes: &'a [Change],
+    /// The version of the release, usually the head document's
+    /// `info.version`.
+    pub version: &'a str,
+    /// The release date, as `YYYY-MM-DD`.
+    pub date: &'a str,
+}
+
+impl<'a> ChangelogPrinter<'a> {
+    /// Breaking changes are listed in their own section rather than with
+    /// their kind. Changes that only affect the documentation are left out,
+    /// as are new endpoints, which follow from added servers and operations.
+    pub fn print(&self) -> String {
+        let mut result = format!("## [{}] - {}\n", self.version, self.date);
+
+        let sections = [
+            ("Breaking", None),
+            ("Added", Some(Kind::Added)),
+            ("Changed", Some(Kind::Changed)),
+            ("Deprecated", Some(Kind::Deprecated)),
+            ("Removed", Some(Kind::Removed)),
+        ];
+        let mut empty = true;
+
+        for (title, kind) in &sections {
+            let changes = self.changes.iter().filter(|change| {
+                !change.rule.documentation
+                    && change.rule != &ENDPOINT_ADDED
+                    && match kind {
+                        None => change.severity == Severity::Breaking,
+                        Some(kind) => {
+                            change.severity != Severity::Breaking && change.rule.kind == *kind
+                        }
+                    }
+            });
+
+            let section = print_section(changes);
+            if !section.is_empty() {
+                result.push_str(&format!("\n### {}\n{}", title, section));
+                empty = false;
+            }
+        }
+
+        if empty {
+            result.push_str("\nNo changes to the API.\n");
+        }
+
+        result
+    }
+}
+
+/// Groups changes by the first tag of their operation.
+fn print_section<'a>(changes: impl Iterator<Item = &'a Change>) -> String {
+    let mut groups: BTreeMap<(bool, &str), Vec<String>> = BTreeMap::new();
+
+    for change in changes {
+        // General changes come first, and untagged operations last.
+        let group = match &change.operation {
+            Some(operation) => match operation.tags.first() {
+                Some(tag) => (false, tag.as_str()),
+                None => (true, UNTAGGED),
+            },
+            None => (false, GENERAL),
+        };
+
+        let line = match &change.operation {
+            Some(operation) => {
+                let name = match &operation.summary {
+                    Some(summary) => summary.trim_end_matches('.').to_string(),
+                    None => format!("`{}`", operation.name()),
+                };
+
+                format!("- {}: {}", name, change.message)
+            }
+            None => format!("- {}", change.message),
+        };
+
+        let lines = groups.entry(group).or_default();
+        if !lines.contains(&line) {
+            lines.push(line);
+        }
+    }
+
+    let general = groups.remove(&(false, GENERAL));
+    let mut result = String::new();
+
+    for ((_, tag), lines) in general
+        .map(|lines| ((false, GENERAL), lines))
+        .into_iter()
+        .chain(groups)
+    {
+        result.push_str(&format!("\n#### {}\n\n{}\n", tag, lines.join("\n")));
+    }
+
+    result
+}
+
+/// Inserts a release into a Keep a Changelog file, after the title and the
+/// `Unreleased` section and before the latest release.
+pub fn prepend(changelog: &str, release: &str) -> String {
+    let mut offset = 0;
+
+    for line in changelog.split_inclusive('\n') {
+        if line.starts_with("## ") && !line.to_lowercase().starts_with("## [unreleased]") {
+            return format!(
+                "{}{}\n{}",
+                &changelog[..offset],
+                release,
+                &changelog[offset..]
+            );
+        }
+
+        offset += line.len();
+    }
+
+    let mut result = changelog.to_string();
+    if result.is_empty() {
+        result.push_str("# Changelog\n");
+    }
+    while !result.ends_with("\n\n") {
+        result.push('\n');
+    }
+    result.push_str(release);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::{diff, parse};
+
+    #[test]
+    fn grouped_by_tag() {
+        let base = parse("openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\nservers:\n  - url: https://api.example.com\npaths:\n  /pets:\n    get:\n      tags: [pets]\n      summary: List pets\n      responses: {}\n    post:\n      tags: [pets]\n      summary: Create a pet\n      responses: {}\n  /stores:\n    get:\n      tags: [stores]\n      summary: List stores\n      responses: {}\n").expect("Failed to parse base");
+        let head = parse("openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '2'\nservers:\n  - url: https://api.example.com\n  - url: https://eu.api.example.com\npaths:\n  /pets:\n    get:\n      tags: [pets]\n      summary: List all pets\n      parameters:\n        - name: limit\n          in: query\n          schema:\n            type: integer\n      responses: {}\n  /stores:\n    get:\n      tags: [stores]\n      summary: List stores\n      deprecated: true\n      responses: {}\n").expect("Failed to parse head");
+        let diff = diff(base.clone(), head.clone()).expect("Failed to diff");
+        let changes = Change::from_diff(&diff, &base, &head);
+
+        let release = ChangelogPrinter {
+            changes: &changes,
+            version: "2",
+            date: "2024-05-01",
+        }
+        .print();
+
+        assert_eq!(
+            "## [2] - 2024-05-01
+
+### Breaking
+
+#### pets
+
+- Create a pet: Operation was removed.
+
+### Added
+
+#### General
+
+- Server `https://eu.api.example.com` was added.
+
+#### pets
+
+- List all pets: Optional query parameter `limit` was added.
+
+### Deprecated
+
+#### stores
+
+- List stores: Operation was deprecated.
+",
+            release
+        );
+    }
+
+    #[test]
+    fn prepend_after_unreleased() {
+        let changelog =
+            "# Changelog\n\n## [Unreleased]\n\n## [1] - 2024-01-01\n\n- Initial release.\n";
+
+        assert_eq!(
+            "# Changelog\n\n## [Unreleased]\n\n## [2] - 2024-05-01\n\n## [1] - 2024-01-01\n\n- Initial release.\n",
+            prepend(changelog, "## [2] - 2024-05-01\n")
+        );
+        assert_eq!(
+            "# Changelog\n\n## [2] - 2024-05-01\n",
+            prepend("", "## [2] - 2024-05-01\n")
+        );
+    }
+}
@@ -0,0 +1,2220 @@
+//! Flattens a `Diff` into a list of individual changes, each classified by
+//! a rule and a severity, for the formatters that report changes one by one.
+
+use crate::diff::callbacks::CallbacksDiff;
+use crate::diff::common::{escape, Direction};
+use crate::diff::content::ContentDiff;
+use crate::diff::examples::ExampleSource;
+use crate::diff::extensions::ExtensionsDiff;
+use crate::diff::media_type::MediaTypeDiff;
+use crate::diff::operations::OperationDiff;
+use crate::diff::parameter::ParameterDiff;
+use crate::diff::parameters::ParametersDiff;
+use crate::diff::path_items::PathItemDiff;
+use crate::diff::paths::PathsDiff;
+use crate::diff::request_body::RequestBodyDiff;
+use crate::diff::resolver::Resolver;
+use crate::diff::responses::ResponsesDiff;
+use crate::diff::schema::SchemaDiff;
+use crate::diff::security::requirement_key;
+use crate::diff::servers::ServersDiff;
+use crate::diff::Diff;
+use crate::openapi::{OpenAPI, Operation, PathItem, ReferenceOr};
+use std::collections::HashMap;
+
+/// How a change affects existing clients, from most to least severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Breaking,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Breaking => "breaking",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        }
+    }
+}
+
+/// The kind of change, as in the sections of a changelog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+}
+
+/// Describes a class of changes.
+#[derive(Debug, PartialEq)]
+pub struct Rule {
+    pub id: &'static str,
+    pub description: &'static str,
+    pub kind: Kind,
+    /// The usual severity of the change. Some changes are more or less
+    /// severe depending on where they occur.
+    pub severity: Severity,
+    /// Whether the change only affects the documentation of the API.
+    pub documentation: bool,
+}
+
+/// The document a location refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Base,
+    Head,
+}
+
+/// A JSON Pointer into the head document, or into the base document for
+/// things that were removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub side: Side,
+    pub pointer: String,
+}
+
+/// The operation a change belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationContext {
+    pub path: String,
+    /// The lowercase HTTP method.
+    pub method: String,
+    pub summary: Option<String>,
+    pub operation_id: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl OperationContext {
+    fn from_operation(path: &str, method: &str, operation: &Operation) -> Self {
+        Self {
+            path: path.to_string(),
+            method: method.to_string(),
+            summary: operation.summary.clone(),
+            operation_id: operation.operation_id.clone(),
+            tags: operation.tags.clone(),
+        }
+    }
+
+    /// The method and path, such as `GET /pets`.
+    pub fn name(&self) -> String {
+        format!("{} {}", self.method.to_uppercase(), self.path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub rule: &'static Rule,
+    pub severity: Severity,
+    pub message: String,
+    pub location: Location,
+    pub operation: Option<OperationContext>,
+}
+
+impl Change {
+    pub fn is_breaking(&self) -> bool {
+        self.severity == Severity::Breaking
+    }
+
+    /// The message, prefixed with the operation it belongs to.
+    pub fn full_message(&self) -> String {
+        match &self.operation {
+            Some(operation) => format!("`{}`: {}", operation.name(), self.message),
+            None => self.message.clone(),
+        }
+    }
+}
+
+macro_rules! rules {
+    ($($name:ident: $id:literal, $kind:ident, $severity:ident, $documentation:literal, $description:literal;)*) => {
+        $(
+            pub const $name: Rule = Rule {
+                id: $id,
+                description: $description,
+                kind: Kind::$kind,
+                severity: Severity::$severity,
+                documentation: $documentation,
+            };
+        )*
+
+        /// All the rules, in the order they are reported.
+        pub const RULES: &[&Rule] = &[$(&$name),*];
+    };
+}
+
+rules! {
+    OPENAPI_VERSION_CHANGED: "openapi-version-changed", Changed, Info, false,
+        "The OpenAPI Specification version of the document changed.";
+    INFO_CHANGED: "info-changed", Changed, Info, true,
+        "The API metadata, such as its title or version, changed.";
+    TAG_ADDED: "tag-added", Added, Info, true, "A tag was added.";
+    TAG_REMOVED: "tag-removed", Removed, Info, true, "A tag was removed.";
+    TAG_CHANGED: "tag-changed", Changed, Info, true, "The description of a tag changed.";
+    EXTERNAL_DOCS_CHANGED: "external-docs-changed", Changed, Info, true,
+        "External documentation changed.";
+    SECURITY_REQUIREMENT_ADDED: "security-requirement-added", Added, Info, false,
+        "A security requirement was added.";
+    SECURITY_REQUIREMENT_REMOVED: "security-requirement-removed", Removed, Breaking, false,
+        "A security requirement was removed.";
+    SECURITY_SCOPES_CHANGED: "security-scopes-changed", Changed, Breaking, false,
+        "The scopes of a security requirement changed.";
+    SERVER_ADDED: "server-added", Added, Info, false, "A server was added.";
+    SERVER_REMOVED: "server-removed", Removed, Breaking, false, "A server was removed.";
+    SERVER_CHANGED: "server-changed", Changed, Info, false,
+        "A server or the URLs it can be reached at changed.";
+    ENDPOINT_ADDED: "endpoint-added", Added, Info, false,
+        "An existing operation can be reached at a new URL.";
+    ENDPOINT_REMOVED: "endpoint-removed", Removed, Breaking, false,
+        "An existing operation can no longer be reached at a URL.";
+    PATH_ADDED: "path-added", Added, Info, false, "A path was added.";
+    PATH_REMOVED: "path-removed", Removed, Breaking, false, "A path was removed.";
+    OPERATION_ADDED: "operation-added", Added, Info, false, "An operation was added.";
+    OPERATION_REMOVED: "operation-removed", Removed, Breaking, false,
+        "An operation was removed.";
+    OPERATION_DEPRECATED: "operation-deprecated", Deprecated, Warning, false,
+        "An operation was deprecated.";
+    OPERATION_ID_CHANGED: "operation-id-changed", Changed, Warning, false,
+        "The operationId of an operation changed, which renames generated client methods.";
+    OPERATION_DOCS_CHANGED: "operation-docs-changed", Changed, Info, true,
+        "The summary, description, tags or deprecation of an operation changed.";
+    EXTENSION_CHANGED: "extension-changed", Changed, Info, false,
+        "A specification extension was added, removed or changed.";
+    PARAMETER_ADDED: "parameter-added", Added, Info, false, "A parameter was added.";
+    PARAMETER_REMOVED: "parameter-removed", Removed, Warning, false,
+        "A parameter was removed.";
+    PARAMETER_CHANGED: "parameter-changed", Changed, Info, false,
+        "How a parameter is sent, or whether it is required, changed.";
+    PARAMETER_DEPRECATED: "parameter-deprecated", Deprecated, Warning, false,
+        "A parameter was deprecated.";
+    REQUEST_BODY_ADDED: "request-body-added", Added, Info, false,
+        "A request body was added.";
+    REQUEST_BODY_REMOVED: "request-body-removed", Removed, Warning, false,
+        "A request body was removed.";
+    REQUEST_BODY_CHANGED: "request-body-changed", Changed, Info, false,
+        "Whether a request body is required changed.";
+    RESPONSE_ADDED: "response-added", Added, Info, false, "A response status was added.";
+    RESPONSE_REMOVED: "response-removed", Removed, Warning, false,
+        "A response status was removed.";
+    RESPONSE_CHANGED: "response-changed", Changed, Warning, false,
+        "A response reference that cannot be resolved changed.";
+    MEDIA_TYPE_ADDED: "media-type-added", Added, Info, false, "A media type was added.";
+    MEDIA_TYPE_REMOVED: "media-type-removed", Removed, Breaking, false,
+        "A media type was removed.";
+    MEDIA_TYPE_CHANGED: "media-type-changed", Changed, Info, false,
+        "A media type was matched with a wider or narrower media range, or another JSON type.";
+    ENCODING_CHANGED: "encoding-changed", Changed, Warning, false,
+        "How a property of a multipart or form body is encoded changed.";
+    EXAMPLE_CHANGED: "example-changed", Changed, Info, true, "An example changed.";
+    INVALID_EXAMPLE: "invalid-example", Changed, Warning, false,
+        "An example does not validate against the head schema.";
+    CALLBACK_ADDED: "callback-added", Added, Info, false, "A callback was added.";
+    CALLBACK_REMOVED: "callback-removed", Removed, Warning, false, "A callback was removed.";
+    CALLBACK_CHANGED: "callback-changed", Changed, Warning, false,
+        "The runtime expressions of a callback changed.";
+    SCHEMA_REFERENCE_CHANGED: "schema-reference-changed", Changed, Warning, false,
+        "A schema reference that cannot be resolved changed.";
+    SCHEMA_TYPE_CHANGED: "schema-type-changed", Changed, Info, false,
+        "The type or format of a schema changed.";
+    SCHEMA_NULLABLE_CHANGED: "schema-nullable-changed", Changed, Info, false,
+        "Whether a schema accepts null changed.";
+    SCHEMA_ENUM_CHANGED: "schema-enum-changed", Changed, Info, false,
+        "The allowed values of a schema changed.";
+    SCHEMA_CHANGED: "schema-changed", Changed, Warning, false,
+        "A constraint of a schema changed.";
+    SCHEMA_DEPRECATED: "schema-deprecated", Deprecated, Warning, false,
+        "A schema or property was deprecated.";
+    SCHEMA_DOCS_CHANGED: "schema-docs-changed", Changed, Info, true,
+        "The title, description, default or examples of a schema changed.";
+    PROPERTY_ADDED: "property-added", Added, Info, false, "A property was added.";
+    PROPERTY_REMOVED: "property-removed", Removed, Warning, false,
+        "A property was removed.";
+    PROPERTY_REQUIRED_CHANGED: "property-required-changed", Changed, Info, false,
+        "Whether a property is required changed.";
+}
+
+impl Change {
+    /// Lists the changes of `diff`, which was computed from `base` and
+    /// `head`. The documents provide the locations, tags and summaries
+    /// of the changed operations.
+    pub fn from_diff(diff: &Diff, base: &OpenAPI, head: &OpenAPI) -> Vec<Change> {
+        let mut collector = Collector {
+            base,
+            head,
+            resolver: Resolver::new(base.components.as_ref(), head.components.as_ref()),
+            changes: vec![],
+        };
+
+        collector.document(diff);
+        collector.changes
+    }
+}
+
+/// Where the changes of an operation, or of the document, are reported.
+#[derive(Clone)]
+struct Scope {
+    pointer: String,
+    operation: Option<OperationContext>,
+    base_operation: Option<Operation>,
+    head_operation: Option<Operation>,
+}
+
+impl Scope {
+    fn document() -> Self {
+        Self {
+            pointer: String::new(),
+            operation: None,
+            base_operation: None,
+            head_operation: None,
+        }
+    }
+
+    fn at(&self, pointer: &str) -> Location {
+        self.located(Side::Head, pointer)
+    }
+
+    fn located(&self, side: Side, pointer: &str) -> Location {
+        Location {
+            side,
+            pointer: format!("{}{}", self.pointer, pointer),
+        }
+    }
+}
+
+struct Collector<'a> {
+    base: &'a OpenAPI,
+    head: &'a OpenAPI,
+    resolver: Resolver<'a>,
+    changes: Vec<Change>,
+}
+
+impl<'a> Collector<'a> {
+    fn push(
+        &mut self,
+        rule: &'static Rule,
+        severity: Severity,
+        message: String,
+        scope: &Scope,
+        location: Location,
+    ) {
+        self.changes.push(Change {
+            rule,
+            severity,
+            message,
+            location,
+            operation: scope.operation.clone(),
+        });
+    }
+
+    fn document(&mut self, diff: &Diff) {
+        let scope = Scope::document();
+
+        if let Some(version_diff) = &diff.version {
+            self.push(
+                &OPENAPI_VERSION_CHANGED,
+                Severity::Info,
+                format!(
+                    "OpenAPI version changed from `{}` to `{}`.",
+                    version_diff.from, version_diff.to
+                ),
+                &scope,
+                scope.at("/openapi"),
+            );
+        }
+
+        if let Some(info_diff) = &diff.info {
+            let mut fields = vec![];
+            if let Some(version_diff) = &info_diff.version {
+                fields.push(format!(
+                    "version from `{}` to `{}`",
+                    version_diff.from, version_diff.to
+                ));
+            }
+            if info_diff.title.is_some() {
+                fields.push("title".to_string());
+            }
+            if info_diff.description.is_some() {
+                fields.push("description".to_string());
+            }
+            if info_diff.terms_of_service.is_some() {
+                fields.push("terms of service".to_string());
+            }
+            if info_diff.contact.is_some() {
+                fields.push("contact".to_string());
+            }
+            if info_diff.license.is_some() {
+                fields.push("license".to_string());
+            }
+            if info_diff.extensions.is_some() {
+                fields.push("extensions".to_string());
+            }
+
+            self.push(
+                &INFO_CHANGED,
+                Severity::Info,
+                format!("API info changed: {}.", fields.join(", ")),
+                &scope,
+                scope.at("/info"),
+            );
+        }
+
+        if let Some(servers_diff) = &diff.servers {
+            self.servers(servers_diff, &scope);
+        }
+
+        if let Some(tags_diff) = &diff.tags {
+            for tag in &tags_diff.added {
+                self.push(
+                    &TAG_ADDED,
+                    Severity::Info,
+                    format!("Tag `{}` was added.", tag.name),
+                    &scope,
+                    scope.at("/tags"),
+                );
+            }
+
+            for tag in &tags_diff.removed {
+                self.push(
+                    &TAG_REMOVED,
+                    Severity::Info,
+                    format!("Tag `{}` was removed.", tag.name),
+                    &scope,
+                    scope.located(Side::Base, "/tags"),
+                );
+            }
+
+            for (name, _) in sorted(&tags_diff.changed) {
+                self.push(
+                    &TAG_CHANGED,
+                    Severity::Info,
+                    format!("Tag `{}` changed.", name),
+                    &scope,
+                    scope.at("/tags"),
+                );
+            }
+        }
+
+        if diff.external_docs.is_some() {
+            self.push(
+                &EXTERNAL_DOCS_CHANGED,
+                Severity::Info,
+                "API external docs changed.".to_string(),
+                &scope,
+                scope.at("/externalDocs"),
+            );
+        }
+
+        if let Some(security_diff) = &diff.security {
+            let base_security = self.base.security.as_deref().unwrap_or_default();
+            let head_security = self.head.security.as_deref().unwrap_or_default();
+
+            for requirement in &security_diff.added {
+                // Requiring authentication where none was needed breaks
+                // anonymous clients.
+                let severity = if base_security.is_empty() {
+                    Severity::Breaking
+                } else {
+                    Severity::Info
+                };
+                self.push(
+                    &SECURITY_REQUIREMENT_ADDED,
+                    severity,
+                    format!(
+                        "Default security requirement `{}` was added.",
+                        requirement_name(&requirement_key(requirement))
+                    ),
+                    &scope,
+                    scope.at("/security"),
+                );
+            }
+
+            for requirement in &security_diff.removed {
+                let severity = if head_security.is_empty() {
+                    Severity::Info
+                } else {
+                    Severity::Breaking
+                };
+                self.push(
+                    &SECURITY_REQUIREMENT_REMOVED,
+                    severity,
+                    format!(
+                        "Default security requirement `{}` was removed.",
+                        requirement_name(&requirement_key(requirement))
+                    ),
+                    &scope,
+                    scope.located(Side::Base, "/security"),
+                );
+            }
+
+            for (key, requirement_diff) in sorted(&security_diff.changed) {
+                for (scheme, scopes_diff) in sorted(&requirement_diff.scopes) {
+                    let severity = if scopes_diff.added.is_empty() {
+                        Severity::Info
+                    } else {
+                        Severity::Breaking
+                    };
+                    self.push(
+                        &SECURITY_SCOPES_CHANGED,
+                        severity,
+                        format!(
+                            "Scopes of `{}` in default security requirement `{}` changed{}{}.",
+                            scheme,
+                            key,
+                            list(", added", &scopes_diff.added),
+                            list(", removed", &scopes_diff.removed),
+                        ),
+                        &scope,
+                        scope.at("/security"),
+                    );
+                }
+            }
+        }
+
+        if let Some(endpoints_diff) = &diff.endpoints {
+            let endpoints = [
+                (
+                    &endpoints_diff.added,
+                    &ENDPOINT_ADDED,
+                    Severity::Info,
+                    Side::Head,
+                    "now exists",
+                ),
+                (
+                    &endpoints_diff.removed,
+                    &ENDPOINT_REMOVED,
+                    Severity::Breaking,
+                    Side::Base,
+                    "no longer exists",
+                ),
+            ];
+
+            for (endpoints, rule, severity, side, state) in endpoints {
+                for endpoint in endpoints {
+                    let method = endpoint.method.to_lowercase();
+
+                    // Endpoints of added and removed operations are reported
+                    // with the operations.
+                    if operation_reported(diff.paths.as_ref(), &endpoint.path, &method) {
+                        continue;
+                    }
+
+                    let scope = self.operation_scope(
+                        "/paths",
+                        &endpoint.path,
+                        &method,
+                        self.operation_in(Side::Base, "/paths", &endpoint.path, &method),
+                        self.operation_in(Side::Head, "/paths", &endpoint.path, &method),
+                    );
+                    self.push(
+                        rule,
+                        severity,
+                        format!("`{} {}` {}.", endpoint.method, endpoint.url, state),
+                        &scope,
+                        scope.located(side, ""),
+                    );
+                }
+            }
+        }
+
+        if let Some(paths_diff) = &diff.paths {
+            self.paths(paths_diff, "/paths", Direction::Request);
+        }
+
+        if let Some(webhooks_diff) = &diff.webhooks {
+            self.paths(webhooks_diff, "/webhooks", Direction::Response);
+        }
+    }
+
+    /// The path item at `path` under `root`, which is `/paths` or
+    /// `/webhooks`.
+    fn path_item_in(&self, side: Side, root: &str, path: &str) -> Option<PathItem> {
+        let document = match side {
+            Side::Base => self.base,
+            Side::Head => self.head,
+        };
+        let paths = if root == "/webhooks" {
+            &document.webhooks
+        } else {
+            &document.paths
+        };
+        let path_item = paths.get(path)?;
+
+        match side {
+            Side::Base => self.resolver.base_path_item(path_item).cloned(),
+            Side::Head => self.resolver.head_path_item(path_item).cloned(),
+        }
+    }
+
+    fn operation_in(&self, side: Side, root: &str, path: &str, method: &str) -> Option<Operation> {
+        operation(&self.path_item_in(side, root, path)?, method)
+    }
+
+    fn operation_scope(
+        &self,
+        root: &str,
+        path: &str,
+        method: &str,
+        base_operation: Option<Operation>,
+        head_operation: Option<Operation>,
+    ) -> Scope {
+        let context = head_operation
+            .as_ref()
+            .or(base_operation.as_ref())
+            .map(|operation| OperationContext::from_operation(path, method, operation));
+
+        Scope {
+            pointer: format!("{}/{}/{}", root, escape(path), method),
+            operation: context,
+            base_operation,
+            head_operation,
+        }
+    }
+
+    /// `direction` is the direction of the requests under `root`, which is
+    /// `Direction::Response` for webhooks.
+    fn paths(&mut self, diff: &PathsDiff, root: &str, direction: Direction) {
+        let scope = Scope::document();
+
+        for (path, path_item) in &diff.added {
+            let pointer = format!("{}/{}", root, escape(path));
+
+            match self.resolver.head_path_item(path_item).cloned() {
+                Some(path_item) if !path_item.operations().is_empty() => {
+                    for (method, operation) in path_item.operations() {
+                        let scope =
+                            self.operation_scope(root, path, method, None, Some(operation.clone()));
+                        self.push(
+                            &OPERATION_ADDED,
+                            Severity::Info,
+                            "Operation was added.".to_string(),
+                            &scope,
+                            scope.at(""),
+                        );
+                    }
+                }
+                _ => self.push(
+                    &PATH_ADDED,
+                    Severity::Info,
+                    format!("Path `{}` was added.", path),
+                    &scope,
+                    scope.at(&pointer),
+                ),
+            }
+        }
+
+        for (path, path_item) in &diff.removed {
+            let pointer = format!("{}/{}", root, escape(path));
+
+            match self.resolver.base_path_item(path_item).cloned() {
+                Some(path_item) if !path_item.operations().is_empty() => {
+                    for (method, operation) in path_item.operations() {
+                        let scope =
+                            self.operation_scope(root, path, method, Some(operation.clone()), None);
+                        self.push(
+                            &OPERATION_REMOVED,
+                            Severity::Breaking,
+                            "Operation was removed.".to_string(),
+                            &scope,
+                            scope.located(Side::Base, ""),
+                        );
+                    }
+                }
+                _ => self.push(
+                    &PATH_REMOVED,
+                    Severity::Breaking,
+                    format!("Path `{}` was removed.", path),
+                    &scope,
+                    scope.located(Side::Base, &pointer),
+                ),
+            }
+        }
+
+        for (path, path_item_diff) in sorted(&diff.changed) {
+            let base_path_item = self.path_item_in(Side::Base, root, path);
+            let head_path_item = self.path_item_in(Side::Head, root, path);
+
+            self.path_item(
+                path_item_diff,
+                &format!("{}/{}", root, escape(path)),
+                path,
+                base_path_item.as_ref(),
+                head_path_item.as_ref(),
+                direction,
+                None,
+            );
+        }
+    }
+
+    /// `parent` is the operation of a callback's path item.
+    #[allow(clippy::too_many_arguments)]
+    fn path_item(
+        &mut self,
+        diff: &PathItemDiff,
+        pointer: &str,
+        path: &str,
+        base: Option<&PathItem>,
+        head: Option<&PathItem>,
+        direction: Direction,
+        parent: Option<&OperationContext>,
+    ) {
+        let path_scope = Scope {
+            pointer: pointer.to_string(),
+            operation: parent.cloned(),
+            ..Scope::document()
+        };
+
+        if let Some(extensions_diff) = &diff.extensions {
+            self.extensions(extensions_diff, &path_scope, "");
+        }
+
+        let context = |method: &str, operation: &Operation| {
+            parent
+                .cloned()
+                .unwrap_or_else(|| OperationContext::from_operation(path, method, operation))
+        };
+
+        for (method, operation) in &diff.operations_added {
+            let scope = Scope {
+                pointer: format!("{}/{}", pointer, method),
+                operation: Some(context(method, operation)),
+                ..Scope::document()
+            };
+            self.push(
+                &OPERATION_ADDED,
+                Severity::Info,
+                "Operation was added.".to_string(),
+                &scope,
+                scope.at(""),
+            );
+        }
+
+        for (method, operation) in &diff.operations_removed {
+            let scope = Scope {
+                pointer: format!("{}/{}", pointer, method),
+                operation: Some(context(method, operation)),
+                ..Scope::document()
+            };
+            self.push(
+                &OPERATION_REMOVED,
+                Severity::Breaking,
+                "Operation was removed.".to_string(),
+                &scope,
+                scope.located(Side::Base, ""),
+            );
+        }
+
+        for (method, operation_diff) in sorted(&diff.operations_changed) {
+            let base_operation = base.and_then(|path_item| operation(path_item, method));
+            let head_operation = head.and_then(|path_item| operation(path_item, method));
+            let operation_context = head_operation
+                .as_ref()
+                .or(base_operation.as_ref())
+                .map(|operation| context(method, operation));
+
+            let scope = Scope {
+                pointer: format!("{}/{}", pointer, method),
+                operation: operation_context,
+                base_operation,
+                head_operation,
+            };
+            self.operation(operation_diff, &scope, direction);
+        }
+    }
+
+    /// `direction` is the direction of the operation's request.
+    fn operation(&mut self, diff: &OperationDiff, scope: &Scope, direction: Direction) {
+        if let Some(summary_diff) = &diff.summary {
+            self.push(
+                &OPERATION_DOCS_CHANGED,
+                Severity::Info,
+                format!(
+                    "Summary changed from `{}` to `{}`.",
+                    summary_diff.from.as_deref().unwrap_or("none"),
+                    summary_diff.to.as_deref().unwrap_or("none")
+                ),
+                scope,
+                scope.at("/summary"),
+            );
+        }
+
+        if diff.description.is_some() {
+            self.push(
+                &OPERATION_DOCS_CHANGED,
+                Severity::Info,
+                "Description changed.".to_string(),
+                scope,
+                scope.at("/description"),
+            );
+        }
+
+        if diff.tags.has_changes() {
+            self.push(
+                &OPERATION_DOCS_CHANGED,
+                Severity::Info,
+                format!(
+                    "Tags changed{}{}.",
+                    list(", added", &diff.tags.added),
+                    list(", removed", &diff.tags.removed)
+                ),
+                scope,
+                scope.at("/tags"),
+            );
+        }
+
+        if diff.external_docs.is_some() {
+            self.push(
+                &OPERATION_DOCS_CHANGED,
+                Severity::Info,
+                "External docs changed.".to_string(),
+                scope,
+                scope.at("/externalDocs"),
+            );
+        }
+
+        if let Some(operation_id_diff) = &diff.operation_id {
+            self.push(
+                &OPERATION_ID_CHANGED,
+                Severity::Warning,
+                format!(
+                    "Operation id changed from `{}` to `{}`.",
+                    operation_id_diff.from.as_deref().unwrap_or("none"),
+                    operation_id_diff.to.as_deref().unwrap_or("none")
+                ),
+                scope,
+                scope.at("/operationId"),
+            );
+        }
+
+        if let Some(deprecated_diff) = &diff.deprecated {
+            if deprecated_diff.to {
+                self.push(
+                    &OPERATION_DEPRECATED,
+                    Severity::Warning,
+                    "Operation was deprecated.".to_string(),
+                    scope,
+                    scope.at("/deprecated"),
+                );
+            } else {
+                self.push(
+                    &OPERATION_DOCS_CHANGED,
+                    Severity::Info,
+                    "Operation is no longer deprecated.".to_string(),
+                    scope,
+                    scope.at(""),
+                );
+            }
+        }
+
+        if let Some(servers_diff) = &diff.servers {
+            self.servers(servers_diff, scope);
+        }
+
+        if let Some(extensions_diff) = &diff.extensions {
+            self.extensions(extensions_diff, scope, "");
+        }
+
+        self.parameters(&diff.parameters, scope, direction);
+
+        if let Some(request_body_diff) = &diff.request_body {
+            self.request_body(request_body_diff, scope, direction);
+        }
+
+        self.responses(&diff.responses, scope, direction.reversed());
+
+        if let Some(callbacks_diff) = &diff.callbacks {
+            self.callbacks(callbacks_diff, scope, direction);
+        }
+    }
+
+    fn servers(&mut self, diff: &ServersDiff, scope: &Scope) {
+        for server in &diff.added {
+            self.push(
+                &SERVER_ADDED,
+                Severity::Info,
+                format!("Server `{}` was added.", server.url),
+                scope,
+                scope.at("/servers"),
+            );
+        }
+
+        for server in &diff.removed {
+            self.push(
+                &SERVER_REMOVED,
+                Severity::Breaking,
+                format!("Server `{}` was removed.", server.url),
+                scope,
+                scope.located(Side::Base, "/servers"),
+            );
+        }
+
+        for (url, server_diff) in sorted(&diff.changed) {
+            let mut details = vec![];
+            if let Some(url_diff) = &server_diff.url {
+                details.push(format!("its URL is now `{}`", url_diff.to));
+            }
+            let mut severity = Severity::Info;
+            if let Some(urls_diff) = &server_diff.urls {
+                if !urls_diff.removed.is_empty() {
+                    severity = Severity::Breaking;
+                    details.push(format!(
+                        "it can no longer be reached at {}",
+                        quoted(&urls_diff.removed)
+                    ));
+                }
+                if !urls_diff.added.is_empty() {
+                    details.push(format!(
+                        "it can now be reached at {}",
+                        quoted(&urls_diff.added)
+                    ));
+                }
+            }
+            if server_diff.variables.is_some() {
+                details.push("its variables changed".to_string());
+            }
+            if server_diff.description.is_some() {
+                details.push("its description changed".to_string());
+            }
+            if server_diff.extensions.is_some() {
+                details.push("its extensions changed".to_string());
+            }
+
+            self.push(
+                &SERVER_CHANGED,
+                severity,
+                format!("Server `{}` changed: {}.", url, details.join(", ")),
+                scope,
+                scope.at("/servers"),
+            );
+        }
+    }
+
+    /// `pointer` is the location of the extended object within `scope`.
+    fn extensions(&mut self, diff: &ExtensionsDiff, scope: &Scope, pointer: &str) {
+        for (name, _) in &diff.added {
+            self.push(
+                &EXTENSION_CHANGED,
+                Severity::Info,
+                format!("Extension `{}` was added.", name),
+                scope,
+                scope.at(&format!("{}/{}", pointer, escape(name))),
+            );
+        }
+
+        for (name, _) in &diff.removed {
+            self.push(
+                &EXTENSION_CHANGED,
+                Severity::Info,
+                format!("Extension `{}` was removed.", name),
+                scope,
+                scope.located(Side::Base, &format!("{}/{}", pointer, escape(name))),
+            );
+        }
+
+        for (name, extension_diff) in sorted(&diff.changed) {
+            let changes: Vec<String> = extension_diff
+                .changes
+                .iter()
+                .map(|change| {
+                    let at = if change.pointer.is_empty() {
+                        String::new()
+                    } else {
+                        format!("`{}` ", change.pointer)
+                    };
+
+                    match (&change.from, &change.to) {
+                        (Some(from), Some(to)) => {
+                            format!("{}changed from `{}` to `{}`", at, from, to)
+                        }
+                        (None, Some(to)) => format!("{}`{}` was added", at, to),
+                        (Some(from), None) => format!("{}`{}` was removed", at, from),
+                        (None, None) => String::new(),
+                    }
+                })
+                .collect();
+
+            self.push(
+                &EXTENSION_CHANGED,
+                Severity::Info,
+                format!("Extension `{}` changed: {}.", name, changes.join(", ")),
+                scope,
+                scope.at(&format!("{}/{}", pointer, escape(name))),
+            );
+        }
+    }
+
+    fn parameters(&mut self, diff: &ParametersDiff, scope: &Scope, direction: Direction) {
+        for parameter in &diff.added {
+            let parameter = match self.resolver.head_parameter(parameter) {
+                Some(parameter) => parameter,
+                None => continue,
+            };
+            let key = ParametersDiff::param_key(&parameter);
+            let required = ParameterDiff::parameter_data(&parameter).required;
+            let severity = if required && direction == Direction::Request {
+                Severity::Breaking
+            } else {
+                Severity::Info
+            };
+
+            self.push(
+                &PARAMETER_ADDED,
+                severity,
+                format!(
+                    "{} {} was added.",
+                    if required { "Required" } else { "Optional" },
+                    parameter_name(&key)
+                ),
+                scope,
+                scope.at(&self.parameter_pointer(scope, Side::Head, &key)),
+            );
+        }
+
+        for parameter in &diff.removed {
+            let parameter = match self.resolver.base_parameter(parameter) {
+                Some(parameter) => parameter,
+                None => continue,
+            };
+            let key = ParametersDiff::param_key(&parameter);
+            let severity = if direction == Direction::Request {
+                Severity::Warning
+            } else {
+                Severity::Breaking
+            };
+
+            self.push(
+                &PARAMETER_REMOVED,
+                severity,
+                format!("{} was removed.", capitalize(&parameter_name(&key))),
+                scope,
+                scope.located(Side::Base, &self.parameter_pointer(scope, Side::Base, &key)),
+            );
+        }
+
+        for (key, parameter_diff) in sorted(&diff.changed) {
+            let pointer = self.parameter_pointer(scope, Side::Head, key);
+            let name = capitalize(&parameter_name(key));
+            let mut severity = Severity::Info;
+            let mut details = vec![];
+
+            if let Some(required_diff) = &parameter_diff.required {
+                if required_diff.to {
+                    if direction == Direction::Request {
+                        severity = Severity::Breaking;
+                    }
+                    details.push("it is now required".to_string());
+                } else {
+                    details.push("it is now optional".to_string());
+                }
+            }
+            if let Some(in_diff) = &parameter_diff.in_change {
+                severity = Severity::Breaking;
+                details.push(format!("it moved from {} to {}", in_diff.from, in_diff.to));
+            }
+            if let Some(style_diff) = &parameter_diff.style {
+                details.push(format!(
+                    "its style changed from `{}` to `{}`",
+                    style_diff.from, style_diff.to
+                ));
+            }
+            if let Some(explode_diff) = &parameter_diff.explode {
+                details.push(format!("explode is now `{}`", explode_diff.to));
+            }
+            if let Some(allow_reserved_diff) = &parameter_diff.allow_reserved {
+                details.push(format!("allowReserved is now `{}`", allow_reserved_diff.to));
+            }
+            if let Some(allow_empty_value_diff) = &parameter_diff.allow_empty_value {
+                if allow_empty_value_diff.breaking {
+                    severity = Severity::Breaking;
+                }
+                details.push(format!(
+                    "allowEmptyValue is now `{}`",
+                    allow_empty_value_diff.to
+                ));
+            }
+            if let Some(serialization_diff) = &parameter_diff.serialization_changed {
+                details.push(format!(
+                    "it is now described by `{}` instead of `{}`",
+                    serialization_diff.to, serialization_diff.from
+                ));
+            }
+            if parameter_diff.wire_format_changed {
+                severity = Severity::Breaking;
+            }
+            if parameter_diff.description.is_some() {
+                details.push("its description changed".to_string());
+            }
+            if parameter_diff.example.is_some() || parameter_diff.examples.is_some() {
+                details.push("its examples changed".to_string());
+            }
+
+            if !details.is_empty() {
+                self.push(
+                    &PARAMETER_CHANGED,
+                    severity,
+                    format!("{} changed: {}.", name, details.join(", ")),
+                    scope,
+                    scope.at(&pointer),
+                );
+            }
+
+            if let Some(deprecated_diff) = &parameter_diff.deprecated {
+                if deprecated_diff.to {
+                    self.push(
+                        &PARAMETER_DEPRECATED,
+                        Severity::Warning,
+                        format!("{} was deprecated.", name),
+                        scope,
+                        scope.at(&pointer),
+                    );
+                } else {
+                    self.push(
+                        &PARAMETER_CHANGED,
+                        Severity::Info,
+                        format!("{} is no longer deprecated.", name),
+                        scope,
+                        scope.at(&pointer),
+                    );
+                }
+            }
+
+            if let Some(extensions_diff) = &parameter_diff.extensions {
+                self.extensions(extensions_diff, scope, &pointer);
+            }
+
+            let subject = parameter_name(key);
+
+            if let Some(schema_diff) = &parameter_diff.schema {
+                self.schema(
+                    schema_diff,
+                    scope,
+                    &format!("{}/schema", pointer),
+                    &subject,
+                    "",
+                    direction,
+                );
+            }
+
+            if let Some(content_diff) = &parameter_diff.content {
+                self.content(content_diff, scope, &pointer, &subject, direction);
+            }
+
+            for invalid_example in &parameter_diff.invalid_examples {
+                self.invalid_example(invalid_example, scope, &pointer, &subject);
+            }
+        }
+    }
+
+    /// The location of a parameter of the scope's operation, or of its
+    /// parameter list if the parameter is not found.
+    fn parameter_pointer(&self, scope: &Scope, side: Side, key: &str) -> String {
+        let operation = match side {
+            Side::Base => scope.base_operation.as_ref(),
+            Side::Head => scope.head_operation.as_ref(),
+        };
+
+        let index = operation.and_then(|operation| {
+            operation.parameters.iter().position(|parameter| {
+                let parameter = match side {
+                    Side::Base => self.resolver.base_parameter(parameter),
+                    Side::Head => self.resolver.head_parameter(parameter),
+                };
+
+                parameter.is_some_and(|parameter| ParametersDiff::param_key(&parameter) == key)
+            })
+        });
+
+        match index {
+            Some(index) => format!("/parameters/{}", index),
+            None => "/parameters".to_string(),
+        }
+    }
+
+    fn request_body(&mut self, diff: &RequestBodyDiff, scope: &Scope, direction: Direction) {
+        if let Some(reference_diff) = &diff.reference {
+            self.push(
+                &REQUEST_BODY_CHANGED,
+                Severity::Warning,
+                format!(
+                    "Request body now refers to `{}`.",
+                    reference_diff
+                        .to
+                        .as_deref()
+                        .unwrap_or("an inline request body")
+                ),
+                scope,
+                scope.at("/requestBody"),
+            );
+        }
+
+        if let Some(request_body) = &diff.added {
+            let required = match request_body {
+                ReferenceOr::Item(request_body) => request_body.required.unwrap_or(false),
+                ReferenceOr::Reference { .. } => false,
+            };
+            let severity = if required && direction == Direction::Request {
+                Severity::Breaking
+            } else {
+                Severity::Info
+            };
+
+            self.push(
+                &REQUEST_BODY_ADDED,
+                severity,
+                format!(
+                    "{} request body was added.",
+                    if required { "Required" } else { "Optional" }
+                ),
+                scope,
+                scope.at("/requestBody"),
+            );
+        }
+
+        if diff.removed.is_some() {
+            self.push(
+                &REQUEST_BODY_REMOVED,
+                Severity::Warning,
+                "Request body was removed.".to_string(),
+                scope,
+                scope.located(Side::Base, "/requestBody"),
+            );
+        }
+
+        if let Some(required_diff) = &diff.required_changed {
+            let severity = if required_diff.to && direction == Direction::Request {
+                Severity::Breaking
+            } else {
+                Severity::Info
+            };
+
+            self.push(
+                &REQUEST_BODY_CHANGED,
+                severity,
+                format!(
+                    "Request body is now {}.",
+                    if required_diff.to {
+                        "required"
+                    } else {
+                        "optional"
+                    }
+                ),
+                scope,
+                scope.at("/requestBody"),
+            );
+        }
+
+        if diff.description_changed.is_some() {
+            self.push(
+                &OPERATION_DOCS_CHANGED,
+                Severity::Info,
+                "Request body description changed.".to_string(),
+                scope,
+                scope.at("/requestBody"),
+            );
+        }
+
+        if let Some(extensions_diff) = &diff.extensions_changed {
+            self.extensions(extensions_diff, scope, "/requestBody");
+        }
+
+        if let Some(content_diff) = &diff.content_changed {
+            self.content(
+                content_diff,
+                scope,
+                "/requestBody",
+                "the request body",
+                direction,
+            );
+        }
+    }
+
+    fn responses(&mut self, diff: &ResponsesDiff, scope: &Scope, direction: Direction) {
+        for (status_code, _) in &diff.added {
+            self.push(
+                &RESPONSE_ADDED,
+                Severity::Info,
+                format!("Response `{}` was added.", status_code),
+                scope,
+                scope.at(&format!("/responses/{}", status_code)),
+            );
+        }
+
+        for (status_code, _) in &diff.removed {
+            self.push(
+                &RESPONSE_REMOVED,
+                Severity::Warning,
+                format!("Response `{}` was removed.", status_code),
+                scope,
+                scope.located(Side::Base, &format!("/responses/{}", status_code)),
+            );
+        }
+
+        let mut changed: Vec<_> = diff.changed.iter().collect();
+        changed.sort_by_key(|(status_code, _)| (*status_code).clone());
+
+        for (status_code, response_diff) in changed {
+            let pointer = format!("/responses/{}", status_code);
+            let subject = format!("response `{}`", status_code);
+
+            if let Some(reference_diff) = &response_diff.reference {
+                self.push(
+                    &RESPONSE_CHANGED,
+                    Severity::Warning,
+                    format!(
+                        "Response `{}` now refers to `{}`.",
+                        status_code,
+                        reference_diff.to.as_deref().unwrap_or("an inline response")
+                    ),
+                    scope,
+                    scope.at(&pointer),
+                );
+            }
+
+            if let Some(content_diff) = &response_diff.content {
+                self.content(content_diff, scope, &pointer, &subject, direction);
+            }
+
+            if let Some(extensions_diff) = &response_diff.extensions {
+                self.extensions(extensions_diff, scope, &pointer);
+            }
+        }
+    }
+
+    /// `pointer` is the location of the object with the `content` map.
+    fn content(
+        &mut self,
+        diff: &ContentDiff,
+        scope: &Scope,
+        pointer: &str,
+        subject: &str,
+        direction: Direction,
+    ) {
+        for (media_type, _) in &diff.added {
+            self.push(
+                &MEDIA_TYPE_ADDED,
+                Severity::Info,
+                format!("Media type `{}` was added to {}.", media_type, subject),
+                scope,
+                scope.at(&format!("{}/content/{}", pointer, escape(media_type))),
+            );
+        }
+
+        for (media_type, _) in &diff.removed {
+            self.push(
+                &MEDIA_TYPE_REMOVED,
+                Severity::Breaking,
+                format!("Media type `{}` was removed from {}.", media_type, subject),
+                scope,
+                scope.located(
+                    Side::Base,
+                    &format!("{}/content/{}", pointer, escape(media_type)),
+                ),
+            );
+        }
+
+        for (media_type, media_type_diff) in sorted(&diff.changed) {
+            self.media_type(
+                media_type_diff,
+                scope,
+                &format!("{}/content/{}", pointer, escape(media_type)),
+                &format!("{} (`{}`)", subject, media_type),
+                direction,
+            );
+        }
+    }
+
+    fn media_type(
+        &mut self,
+        diff: &MediaTypeDiff,
+        scope: &Scope,
+        pointer: &str,
+        subject: &str,
+        direction: Direction,
+    ) {
+        if let Some(range_diff) = &diff.range_changed {
+            self.push(
+                &MEDIA_TYPE_CHANGED,
+                if range_diff.breaking {
+                    Severity::Breaking
+                } else {
+                    Severity::Info
+                },
+                format!(
+                    "Media type of {} changed from `{}` to `{}`.",
+                    subject, range_diff.from, range_diff.to
+                ),
+                scope,
+                scope.at(pointer),
+            );
+        }
+
+        if diff.schema_added.is_some() {
+            self.push(
+                &SCHEMA_CHANGED,
+                Severity::Warning,
+                format!("A schema was added to {}.", subject),
+                scope,
+                scope.at(&format!("{}/schema", pointer)),
+            );
+        }
+
+        if diff.schema_removed.is_some() {
+            self.push(
+                &SCHEMA_CHANGED,
+                Severity::Warning,
+                format!("The schema of {} was removed.", subject),
+                scope,
+                scope.located(Side::Base, &format!("{}/schema", pointer)),
+            );
+        }
+
+        if let Some(schema_diff) = &diff.schema_changed {
+            self.schema(
+                schema_diff,
+                scope,
+                &format!("{}/schema", pointer),
+                subject,
+                "",
+                direction,
+            );
+        }
+
+        if diff.example_changed.is_some() || diff.examples_changed.is_some() {
+            self.push(
+                &EXAMPLE_CHANGED,
+                Severity::Info,
+                format!("Examples of {} changed.", subject),
+                scope,
+                scope.at(pointer),
+            );
+        }
+
+        if let Some(encodings_diff) = &diff.encoding_changed {
+            let mut properties: Vec<&String> = encodings_diff
+                .added
+                .iter()
+                .chain(&encodings_diff.removed)
+                .map(|(property, _)| property)
+                .chain(encodings_diff.changed.keys())
+                .collect();
+            properties.sort();
+
+            self.push(
+                &ENCODING_CHANGED,
+                Severity::Warning,
+                format!(
+                    "Encoding of {} changed for {}.",
+                    subject,
+                    quoted(&properties)
+                ),
+                scope,
+                scope.at(&format!("{}/encoding", pointer)),
+            );
+        }
+
+        if let Some(extensions_diff) = &diff.extensions_changed {
+            self.extensions(extensions_diff, scope, pointer);
+        }
+
+        for invalid_example in &diff.invalid_examples {
+            self.invalid_example(invalid_example, scope, pointer, subject);
+        }
+    }
+
+    fn invalid_example(
+        &mut self,
+        invalid_example: &crate::diff::examples::InvalidExample,
+        scope: &Scope,
+        pointer: &str,
+        subject: &str,
+    ) {
+        let errors: Vec<String> = invalid_example
+            .errors
+            .iter()
+            .map(|error| error.message.clone())
+            .collect();
+        let (side, message) = match invalid_example.source {
+            ExampleSource::Base => (
+                Side::Base,
+                format!(
+                    "Existing example `{}` of {} is no longer valid: {}",
+                    invalid_example.name,
+                    subject,
+                    errors.join(" ")
+                ),
+            ),
+            ExampleSource::Head => (
+                Side::Head,
+                format!(
+                    "Example `{}` of {} is invalid: {}",
+                    invalid_example.name,
+                    subject,
+                    errors.join(" ")
+                ),
+            ),
+        };
+
+        self.push(
+            &INVALID_EXAMPLE,
+            Severity::Warning,
+            message,
+            scope,
+            scope.located(side, pointer),
+        );
+    }
+
+    fn callbacks(&mut self, diff: &CallbacksDiff, scope: &Scope, direction: Direction) {
+        for (name, _) in &diff.added {
+            self.push(
+                &CALLBACK_ADDED,
+                Severity::Info,
+                format!("Callback `{}` was added.", name),
+                scope,
+                scope.at(&format!("/callbacks/{}", escape(name))),
+            );
+        }
+
+        for (name, _) in &diff.removed {
+            self.push(
+                &CALLBACK_REMOVED,
+                Severity::Warning,
+                format!("Callback `{}` was removed.", name),
+                scope,
+                scope.located(Side::Base, &format!("/callbacks/{}", escape(name))),
+            );
+        }
+
+        for (name, callback_diff) in sorted(&diff.changed) {
+            let pointer = format!("/callbacks/{}", escape(name));
+
+            if let Some(reference_diff) = &callback_diff.reference {
+                self.push(
+                    &CALLBACK_CHANGED,
+                    Severity::Warning,
+                    format!(
+                        "Callback `{}` now refers to `{}`.",
+                        name,
+                        reference_diff.to.as_deref().unwrap_or("an inline callback")
+                    ),
+                    scope,
+                    scope.at(&pointer),
+                );
+            }
+
+            for (expression, _) in &callback_diff.expressions_added {
+                self.push(
+                    &CALLBACK_CHANGED,
+                    Severity::Info,
+                    format!("Callback `{}` is now also sent to `{}`.", name, expression),
+                    scope,
+                    scope.at(&format!("{}/{}", pointer, escape(expression))),
+                );
+            }
+
+            for (expression, _) in &callback_diff.expressions_removed {
+                self.push(
+                    &CALLBACK_CHANGED,
+                    Severity::Warning,
+                    format!("Callback `{}` is no longer sent to `{}`.", name, expression),
+                    scope,
+                    scope.located(Side::Base, &format!("{}/{}", pointer, escape(expression))),
+                );
+            }
+
+            let base_callback = scope
+                .base_operation
+                .as_ref()
+                .and_then(|operation| operation.callbacks.get(name))
+                .and_then(|callback| self.resolver.base_callback(callback));
+            let head_callback = scope
+                .head_operation
+                .as_ref()
+                .and_then(|operation| operation.callbacks.get(name))
+                .and_then(|callback| self.resolver.head_callback(callback));
+
+            for (expression, path_item_diff) in sorted(&callback_diff.expressions_changed) {
+                self.path_item(
+                    path_item_diff,
+                    &format!("{}{}/{}", scope.pointer, pointer, escape(expression)),
+                    expression,
+                    base_callback
+                        .as_ref()
+                        .and_then(|callback| callback.get(expression)),
+                    head_callback
+                        .as_ref()
+                        .and_then(|callback| callback.get(expression)),
+                    direction.reversed(),
+                    scope.operation.as_ref(),
+                );
+            }
+        }
+    }
+
+    /// `property` is the path of the schema within the schema of `subject`,
+    /// such as `pets[].name`, which is empty for the schema itself.
+    fn schema(
+        &mut self,
+        diff: &SchemaDiff,
+        scope: &Scope,
+        pointer: &str,
+        subject: &str,
+        property: &str,
+        direction: Direction,
+    ) {
+        let described = if property.is_empty() {
+            format!("the schema of {}", subject)
+        } else {
+            format!("property `{}` of {}", property, subject)
+        };
+        let request = direction == Direction::Request;
+
+        if let Some(reference_diff) = &diff.reference_changed {
+            self.push(
+                &SCHEMA_REFERENCE_CHANGED,
+                Severity::Warning,
+                format!(
+                    "Reference of {} changed from `{}` to `{}`.",
+                    described,
+                    reference_diff.from.as_deref().unwrap_or("inline"),
+                    reference_diff.to.as_deref().unwrap_or("inline")
+                ),
+                scope,
+                scope.at(pointer),
+            );
+        }
+
+        for (name, type_diff) in [
+            ("Type", &diff.type_changed),
+            ("Format", &diff.format_changed),
+        ] {
+            if let Some(type_diff) = type_diff {
+                self.push(
+                    &SCHEMA_TYPE_CHANGED,
+                    if type_diff.breaking {
+                        Severity::Breaking
+                    } else {
+                        Severity::Info
+                    },
+                    format!(
+                        "{} of {} changed from `{}` to `{}`.",
+                        name,
+                        described,
+                        type_diff.from.as_deref().unwrap_or("any"),
+                        type_diff.to.as_deref().unwrap_or("any")
+                    ),
+                    scope,
+                    scope.at(pointer),
+                );
+            }
+        }
+
+        if let Some(nullable_diff) = &diff.nullable_changed {
+            // Clients can no longer send null, or can now receive it.
+            let breaking = if request {
+                !nullable_diff.to
+            } else {
+                nullable_diff.to
+            };
+
+            self.push(
+                &SCHEMA_NULLABLE_CHANGED,
+                severity(breaking, Severity::Info),
+                format!(
+                    "{} is {} nullable.",
+                    capitalize(&described),
+                    if nullable_diff.to { "now" } else { "no longer" }
+                ),
+                scope,
+                scope.at(pointer),
+            );
+        }
+
+        if let Some(enum_diff) = &diff.enum_changed {
+            // Clients can no longer send some values, or can now receive
+            // values they do not know.
+            let breaking = if request {
+                !enum_diff.values_removed.is_empty() || enum_diff.added
+            } else {
+                !enum_diff.values_added.is_empty() || enum_diff.removed
+            };
+            let mut details = vec![];
+            if enum_diff.added {
+                details.push("it is now restricted".to_string());
+            }
+            if enum_diff.removed {
+                details.push("it is no longer restricted".to_string());
+            }
+            if !enum_diff.values_added.is_empty() {
+                details.push(format!("added {}", quoted(&enum_diff.values_added)));
+            }
+            if !enum_diff.values_removed.is_empty() {
+                details.push(format!("removed {}", quoted(&enum_diff.values_removed)));
+            }
+
+            self.push(
+                &SCHEMA_ENUM_CHANGED,
+                severity(breaking, Severity::Info),
+                format!(
+                    "Allowed values of {} changed: {}.",
+                    described,
+                    details.join(", ")
+                ),
+                scope,
+                scope.at(&format!("{}/enum", pointer)),
+            );
+        }
+
+        if let Some(required_diff) = &diff.required_changed {
+            for name in &required_diff.added {
+                self.push(
+                    &PROPERTY_REQUIRED_CHANGED,
+                    severity(request, Severity::Info),
+                    format!(
+                        "Property `{}` of {} is now required.",
+                        join(property, name),
+                        subject
+                    ),
+                    scope,
+                    scope.at(&format!("{}/required", pointer)),
+                );
+            }
+
+            for name in &required_diff.removed {
+                self.push(
+                    &PROPERTY_REQUIRED_CHANGED,
+                    severity(!request, Severity::Info),
+                    format!(
+                        "Property `{}` of {} is no longer required.",
+                        join(property, name),
+                        subject
+                    ),
+                    scope,
+                    scope.at(&format!("{}/required", pointer)),
+                );
+            }
+        }
+
+        if let Some(properties_diff) = &diff.properties_changed {
+            for (name, _) in &properties_diff.added {
+                self.push(
+                    &PROPERTY_ADDED,
+                    Severity::Info,
+                    format!(
+                        "Property `{}` was added to {}.",
+                        join(property, name),
+                        subject
+                    ),
+                    scope,
+                    scope.at(&format!("{}/properties/{}", pointer, escape(name))),
+                );
+            }
+
+            for (name, _) in &properties_diff.removed {
+                self.push(
+                    &PROPERTY_REMOVED,
+                    severity(!request, Severity::Warning),
+                    format!(
+                        "Property `{}` was removed from {}.",
+                        join(property, name),
+                        subject
+                    ),
+                    scope,
+                    scope.located(
+                        Side::Base,
+                        &format!("{}/properties/{}", pointer, escape(name)),
+                    ),
+                );
+            }
+
+            for (name, property_diff) in sorted(&properties_diff.changed) {
+                self.schema(
+                    property_diff,
+                    scope,
+                    &format!("{}/properties/{}", pointer, escape(name)),
+                    subject,
+                    &join(property, name),
+                    direction,
+                );
+            }
+        }
+
+        if let Some(items_diff) = &diff.items_changed {
+            self.schema(
+                items_diff,
+                scope,
+                &format!("{}/items", pointer),
+                subject,
+                &format!("{}[]", property),
+                direction,
+            );
+        }
+
+        if let Some(prefix_items_diff) = &diff.prefix_items_changed {
+            for (index, _) in &prefix_items_diff.added {
+                self.push(
+                    &SCHEMA_CHANGED,
+                    Severity::Warning,
+                    format!("Prefix item {} was added to {}.", index, described),
+                    scope,
+                    scope.at(&format!("{}/prefixItems/{}", pointer, index)),
+                );
+            }
+
+            for (index, _) in &prefix_items_diff.removed {
+                self.push(
+                    &SCHEMA_CHANGED,
+                    Severity::Warning,
+                    format!("Prefix item {} was removed from {}.", index, described),
+                    scope,
+                    scope.located(Side::Base, &format!("{}/prefixItems/{}", pointer, index)),
+                );
+            }
+
+            for (index, item_diff) in &prefix_items_diff.changed {
+                self.schema(
+                    item_diff,
+                    scope,
+                    &format!("{}/prefixItems/{}", pointer, index),
+                    subject,
+                    &format!("{}[{}]", property, index),
+                    direction,
+                );
+            }
+        }
+
+        if let Some(additional_properties_diff) = &diff.additional_properties_changed {
+            if let Some(allowed_diff) = &additional_properties_diff.allowed_changed {
+                self.push(
+                    &SCHEMA_CHANGED,
+                    severity(request && !allowed_diff.to, Severity::Info),
+                    format!(
+                        "Additional properties are {} allowed in {}.",
+                        if allowed_diff.to { "now" } else { "no longer" },
+                        described
+                    ),
+                    scope,
+                    scope.at(&format!("{}/additionalProperties", pointer)),
+                );
+            }
+
+            if additional_properties_diff.schema_added.is_some()
+                || additional_properties_diff.schema_removed.is_some()
+            {
+                self.push(
+                    &SCHEMA_CHANGED,
+                    Severity::Warning,
+                    format!("The additional properties schema of {} changed.", described),
+                    scope,
+                    scope.at(&format!("{}/additionalProperties", pointer)),
+                );
+            }
+
+            if let Some(schema_diff) = &additional_properties_diff.schema_changed {
+                self.schema(
+                    schema_diff,
+                    scope,
+                    &format!("{}/additionalProperties", pointer),
+                    subject,
+                    &join(property, "*"),
+                    direction,
+                );
+            }
+        }
+
+        for (name, only_diff) in [
+            ("read only", &diff.read_only_changed),
+            ("write only", &diff.write_only_changed),
+        ] {
+            if let Some(only_diff) = only_diff {
+                self.push(
+                    &SCHEMA_CHANGED,
+                    Severity::Warning,
+                    format!(
+                        "{} is {} {}.",
+                        capitalize(&described),
+                        if only_diff.to { "now" } else { "no longer" },
+                        name
+                    ),
+                    scope,
+                    scope.at(pointer),
+                );
+            }
+        }
+
+        if diff.defs_changed.is_some() {
+            self.push(
+                &SCHEMA_CHANGED,
+                Severity::Info,
+                format!("Definitions of {} changed.", described),
+                scope,
+                scope.at(&format!("{}/$defs", pointer)),
+            );
+        }
+
+        if let Some(deprecated_diff) = &diff.deprecated_changed {
+            if deprecated_diff.to {
+                self.push(
+                    &SCHEMA_DEPRECATED,
+                    Severity::Warning,
+                    format!("{} was deprecated.", capitalize(&described)),
+                    scope,
+                    scope.at(pointer),
+                );
+            } else {
+                self.push(
+                    &SCHEMA_DOCS_CHANGED,
+                    Severity::Info,
+                    format!("{} is no longer deprecated.", capitalize(&described)),
+                    scope,
+                    scope.at(pointer),
+                );
+            }
+        }
+
+        let mut documentation = vec![];
+        if diff.title_changed.is_some() {
+            documentation.push("title");
+        }
+        if diff.description_changed.is_some() {
+            documentation.push("description");
+        }
+        if diff.default_changed.is_some() {
+            documentation.push("default");
+        }
+        if diff.example_changed.is_some() || diff.examples_changed.is_some() {
+            documentation.push("examples");
+        }
+        if !documentation.is_empty() {
+            self.push(
+                &SCHEMA_DOCS_CHANGED,
+                Severity::Info,
+                format!("The {} of {} changed.", documentation.join(", "), described),
+                scope,
+                scope.at(pointer),
+            );
+        }
+
+        if let Some(extensions_diff) = &diff.extensions_changed {
+            self.extensions(extensions_diff, scope, pointer);
+        }
+    }
+}
+
+/// Sorts operations by method as `PathItem::operations` does.
+pub fn method_order(method: &str) -> usize {
+    [
+        "get", "put", "post", "delete", "options", "head", "patch", "trace",
+    ]
+    .iter()
+    .position(|operation_method| operation_method.eq_ignore_ascii_case(method))
+    .map_or(0, |index| index + 1)
+}
+
+fn operation(path_item: &PathItem, method: &str) -> Option<Operation> {
+    path_item.operation(method).cloned()
+}
+
+/// Whether `diff` reports the operation as added or removed.
+fn operation_reported(diff: Option<&PathsDiff>, path: &str, method: &str) -> bool {
+    let diff = match diff {
+        Some(diff) => diff,
+        None => return false,
+    };
+
+    diff.added
+        .iter()
+        .chain(&diff.removed)
+        .any(|(added, _)| added == path)
+        || diff.changed.get(path).is_some_and(|path_item_diff| {
+            path_item_diff
+                .operations_added
+                .iter()
+                .chain(&path_item_diff.operations_removed)
+                .any(|(operation_method, _)| operation_method == method)
+        })
+}
+
+fn sorted<K: Ord, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+fn severity(breaking: bool, otherwise: Severity) -> Severity {
+    if breaking {
+        Severity::Breaking
+    } else {
+        otherwise
+    }
+}
+
+/// The empty requirement allows anonymous access.
+fn requirement_name(key: &str) -> &str {
+    if key.is_empty() {
+        "anonymous"
+    } else {
+        key
+    }
+}
+
+/// `query.limit` becomes ``query parameter `limit` ``.
+fn parameter_name(key: &str) -> String {
+    let (location, name) = key.split_at(key.find('.').unwrap_or(0));
+    format!("{} parameter `{}`", location, name.trim_start_matches('.'))
+}
+
+fn join(property: &str, name: &str) -> String {
+    if property.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", property, name)
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn quoted<T: std::fmt::Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| format!("`{}`", value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn list(label: &str, values: &[String]) -> String {
+    if values.is_empty() {
+        String::new()
+    } else {
+        format!("{} {}", label, quoted(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::{diff, parse};
+
+    fn changes(base: &str, head: &str) -> Vec<Change> {
+        let base = parse(base).expect("Failed to parse base");
+        let head = parse(head).expect("Failed to parse head");
+        let diff = diff(base.clone(), head.clone()).expect("Failed to diff");
+
+        Change::from_diff(&diff, &base, &head)
+    }
+
+    fn summary(changes: &[Change]) -> Vec<(&'static str, Severity, &str)> {
+        changes
+            .iter()
+            .map(|change| {
+                (
+                    change.rule.id,
+                    change.severity,
+                    change.location.pointer.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    const PETS: &str = "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets:\n    get:\n      tags: [pets]\n      summary: List pets\n      parameters:\n        - name: limit\n          in: query\n          schema:\n            type: integer\n      responses:\n        '200':\n          description: Pets\n          content:\n            application/json:\n              schema:\n                type: object\n                properties:\n                  name:\n                    type: string\n    post:\n      responses: {}\n";
+
+    #[test]
+    fn breaking_changes_are_classified() {
+        let head = PETS
+            .replace(
+                "          in: query\n",
+                "          in: query\n          required: true\n",
+            )
+            .replace(
+                "                  name:\n                    type: string\n",
+                "                  id:\n                    type: string\n",
+            )
+            .replace("    post:\n      responses: {}\n", "");
+
+        let changes = changes(PETS, &head);
+
+        assert_eq!(
+            vec![
+                ("operation-removed", Severity::Breaking, "/paths/~1pets/post"),
+                ("parameter-changed", Severity::Breaking, "/paths/~1pets/get/parameters/0"),
+                (
+                    "property-added",
+                    Severity::Info,
+                    "/paths/~1pets/get/responses/200/content/application~1json/schema/properties/id"
+                ),
+                (
+                    "property-removed",
+                    Severity::Breaking,
+                    "/paths/~1pets/get/responses/200/content/application~1json/schema/properties/name"
+                ),
+            ],
+            summary(&changes)
+        );
+        assert_eq!(
+            "`GET /pets`: Query parameter `limit` changed: it is now required.",
+            changes[1].full_message()
+        );
+        assert_eq!(
+            Some("List pets".into()),
+            changes[1].operation.as_ref().unwrap().summary
+        );
+    }
+
+    const CALLBACK: &str = "      callbacks:\n        onAdopted:\n          '{$request.body#/url}':\n            post:\n              requestBody:\n                content:\n                  application/json:\n                    schema:\n                      type: object\n                      properties:\n                        id:\n                          type: integer\n                          format: int32\n              responses: {}\n";
+
+    #[test]
+    fn callbacks() {
+        let base = PETS.replace("    post:\n", &format!("    post:\n{}", CALLBACK));
+        let head = base.replace("format: int32", "format: int64");
+
+        let changes = changes(&base, &head);
+
+        assert_eq!(
+            vec![(
+                "schema-type-changed",
+                Severity::Breaking,
+                "/paths/~1pets/post/callbacks/onAdopted/{$request.body#~1url}/post/requestBody/content/application~1json/schema/properties/id"
+            )],
+            summary(&changes)
+        );
+    }
+
+    #[test]
+    fn webhooks() {
+        let base = PETS.replace("openapi: 3.0.0", "openapi: 3.1.0").replace(
+            "paths:\n",
+            "webhooks:\n  newPet:\n    post:\n      summary: New pet\n      requestBody:\n        content:\n          application/json:\n            schema:\n              type: object\n              properties:\n                nickname:\n                  type: string\n      responses: {}\n    put:\n      responses: {}\npaths:\n",
+        );
+        let head = base
+            .replace("    put:\n      responses: {}\npaths:\n", "paths:\n")
+            .replace("nickname:", "name:");
+
+        let changes = changes(&base, &head);
+
+        assert_eq!(
+            vec![
+                ("operation-removed", Severity::Breaking, "/webhooks/newPet/put"),
+                (
+                    "property-added",
+                    Severity::Info,
+                    "/webhooks/newPet/post/requestBody/content/application~1json/schema/properties/name"
+                ),
+                (
+                    "property-removed",
+                    Severity::Breaking,
+                    "/webhooks/newPet/post/requestBody/content/application~1json/schema/properties/nickname"
+                ),
+            ],
+            summary(&changes)
+        );
+        assert_eq!(
+            Some("New pet".into()),
+            changes[1].operation.as_ref().unwrap().summary
+        );
+    }
+
+    #[test]
+    fn security() {
+        let base = PETS.replace(
+            "paths:\n",
+            "security:\n  - oauth: [read, write]\n  - apiKey: []\npaths:\n",
+        );
+        let head = PETS.replace(
+            "paths:\n",
+            "security:\n  - oauth: [read]\n  - bearer: []\npaths:\n",
+        );
+
+        let changes = changes(&base, &head);
+
+        assert_eq!(
+            vec![
+                ("security-requirement-added", Severity::Info, "/security"),
+                (
+                    "security-requirement-removed",
+                    Severity::Breaking,
+                    "/security"
+                ),
+                ("security-scopes-changed", Severity::Info, "/security"),
+            ],
+            summary(&changes)
+        );
+    }
+
+    #[test]
+    fn servers() {
+        let base = PETS.replace(
+            "paths:\n",
+            "servers:\n  - url: https://api.example.com\n  - url: https://{region}.example.com\n    variables:\n      region:\n        default: eu\n        enum: [eu, us]\npaths:\n",
+        );
+        let head = base
+            .replace("  - url: https://api.example.com\n", "")
+            .replace("enum: [eu, us]", "enum: [eu]");
+
+        let changes = changes(&base, &head);
+
+        assert_eq!(
+            vec![
+                ("server-removed", Severity::Breaking, "/servers"),
+                ("server-changed", Severity::Breaking, "/servers"),
+                ("endpoint-removed", Severity::Breaking, "/paths/~1pets/get"),
+                ("endpoint-removed", Severity::Breaking, "/paths/~1pets/get"),
+                ("endpoint-removed", Severity::Breaking, "/paths/~1pets/post"),
+                ("endpoint-removed", Severity::Breaking, "/paths/~1pets/post"),
+            ],
+            summary(&changes)
+        );
+    }
+
+    #[test]
+    fn examples() {
+        let base = PETS.replace(
+            "            type: integer\n",
+            "            type: integer\n          example: 10\n",
+        );
+        let head = base.replace("example: 10", "example: ten");
+
+        let changes = changes(&base, &head);
+
+        assert_eq!(
+            vec![
+                (
+                    "parameter-changed",
+                    Severity::Info,
+                    "/paths/~1pets/get/parameters/0"
+                ),
+                (
+                    "invalid-example",
+                    Severity::Warning,
+                    "/paths/~1pets/get/parameters/0"
+                ),
+            ],
+            summary(&changes)
+        );
+    }
+
+    #[test]
+    fn extensions() {
+        let base = PETS.replace(
+            "    post:\n",
+            "    post:\n      x-rate-limit:\n        requests: 10\n",
+        );
+        let head = base.replace("requests: 10", "requests: 5");
+
+        let changes = changes(&base, &head);
+
+        assert_eq!(
+            vec![(
+                "extension-changed",
+                Severity::Info,
+                "/paths/~1pets/post/x-rate-limit"
+            )],
+            summary(&changes)
+        );
+    }
+}
@@ -0,0 +1,713 @@
+//! Prints changes as a standalone HTML report, with a summary table,
+//! filters, a collapsible section per operation and side-by-side views of
+//! the schemas that changed.
+
+use super::changes::{method_order, Change, Kind, OperationContext, Severity};
+use super::escape_xml;
+use crate::openapi::OpenAPI;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+const SEVERITIES: [Severity; 3] = [Severity::Breaking, Severity::Warning, Severity::Info];
+
+const KINDS: [(Kind, &str); 4] = [
+    (Kind::Added, "Added"),
+    (Kind::Changed, "Changed"),
+    (Kind::Deprecated, "Deprecated"),
+    (Kind::Removed, "Removed"),
+];
+
+/// Above this many line pairs, changed lines of schemas are not
+/// highlighted.
+const MAX_HIGHLIGHTED_LINES: usize = 4_000_000;
+
+const STYLE: &str = "
+body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 2rem; color: #1f2328; }
+code, pre { font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; font-size: 0.85rem; }
+table { border-collapse: collapse; margin-bottom: 1.5rem; }
+th, td { border: 1px solid #d0d7de; padding: 0.3rem 0.8rem; text-align: left; }
+td.count { text-align: right; }
+.filters { display: flex; flex-wrap: wrap; gap: 1.5rem; align-items: center; padding: 0.8rem; margin-bottom: 1.5rem; background: #f6f8fa; border: 1px solid #d0d7de; border-radius: 6px; }
+details.operation { border: 1px solid #d0d7de; border-radius: 6px; margin-bottom: 0.8rem; padding: 0.5rem 0.8rem; }
+details.operation > summary { cursor: pointer; font-weight: 600; }
+.method { display: inline-block; min-width: 4rem; font-family: ui-monospace, monospace; }
+.description { font-weight: normal; color: #59636e; }
+.badge { display: inline-block; border-radius: 1rem; padding: 0 0.5rem; margin-left: 0.3rem; font-size: 0.75rem; color: #fff; }
+.badge.breaking, .change.breaking .severity { background: #cf222e; }
+.badge.warning, .change.warning .severity { background: #9a6700; }
+.badge.info, .change.info .severity { background: #0969da; }
+ul.changes { list-style: none; padding-left: 0; }
+.change { margin: 0.3rem 0; }
+.change .severity { display: inline-block; min-width: 4.5rem; text-align: center; border-radius: 4px; color: #fff; font-size: 0.75rem; }
+.change .rule, .change .pointer { color: #59636e; font-size: 0.75rem; }
+.side-by-side { display: grid; grid-template-columns: 1fr 1fr; gap: 0.8rem; }
+.side-by-side pre { margin: 0; padding: 0.5rem; background: #f6f8fa; overflow: auto; max-height: 40rem; }
+pre .removed { background: #ffebe9; display: block; }
+pre .added { background: #dafbe1; display: block; }
+";
+
+const SCRIPT: &str = "
+function applyFilters() {
+  const severities = Array.from(document.querySelectorAll('input[name=severity]:checked')).map((input) => input.value);
+  const tag = document.getElementById('tag').value;
+  const path = document.getElementById('path').value.toLowerCase();
+
+  document.querySelectorAll('.operation').forEach((operation) => {
+    let visible = 0;
+    operation.querySelectorAll('.change').forEach((change) => {
+      change.hidden = !severities.includes(change.dataset.severity);
+      if (!change.hidden) visible++;
+    });
+
+    const tags = JSON.parse(operation.dataset.tags);
+    operation.hidden = visible === 0
+      || (tag !== '' && !tags.includes(tag))
+      || !operation.dataset.path.toLowerCase().includes(path);
+  });
+}
+
+document.querySelectorAll('.filters input, .filters select').forEach((input) => input.addEventListener('input', applyFilters));
+";
+
+/// The schemas of an operation before and after, one of which may be
+/// missing.
+struct Comparison {
+    label: String,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+pub struct HtmlPrinter<'a> {
+    pub changes: &'a [Change],
+    pub base: &'a OpenAPI,
+    pub head: &'a OpenAPI,
+}
+
+impl<'a> HtmlPrinter<'a> {
+    pub fn print(&self) -> String {
+        let base = serde_json::to_value(self.base).expect("Could not serialize base document");
+        let head = serde_json::to_value(self.head).expect("Could not serialize head document");
+
+        let mut document_changes = vec![];
+        let mut operations: BTreeMap<(String, usize), (&OperationContext, Vec<&Change>)> =
+            BTreeMap::new();
+
+        for change in self.changes {
+            match &change.operation {
+                Some(operation) => {
+                    operations
+                        .entry((operation.path.clone(), method_order(&operation.method)))
+                        .or_insert_with(|| (operation, vec![]))
+                        .1
+                        .push(change);
+                }
+                None => document_changes.push(change),
+            }
+        }
+
+        let tags: BTreeSet<&String> = operations
+            .values()
+            .flat_map(|(operation, _)| &operation.tags)
+            .collect();
+
+        let mut result = String::new();
+        result.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        result.push_str("<meta charset=\"utf-8\">\n");
+        result.push_str(&format!(
+            "<title>OpenAPI diff: {}</title>\n",
+            escape_xml(&self.head.info.title)
+        ));
+        result.push_str(&format!("<style>{}</style>\n", STYLE));
+        result.push_str("</head>\n<body>\n");
+        result.push_str(&format!(
+            "<h1>OpenAPI diff: {}</h1>\n<p>Version <code>{}</code> compared with <code>{}</code>.</p>\n",
+            escape_xml(&self.head.info.title),
+            escape_xml(&self.head.info.version),
+            escape_xml(&self.base.info.version)
+        ));
+
+        if self.changes.is_empty() {
+            result.push_str("<p>No changes.</p>\n</body>\n</html>\n");
+            return result;
+        }
+
+        result.push_str(&self.print_summary());
+        result.push_str(&print_filters(&tags));
+
+        if !document_changes.is_empty() {
+            result.push_str(&print_section(
+                "<summary>Document</summary>",
+                "",
+                &[],
+                &document_changes,
+                &[],
+            ));
+        }
+
+        for (operation, changes) in operations.values() {
+            let comparisons = comparisons(&base, &head, operation);
+            let description = operation
+                .summary
+                .as_ref()
+                .or(operation.operation_id.as_ref())
+                .map(|description| {
+                    format!(
+                        " <span class=\"description\">{}</span>",
+                        escape_xml(description)
+                    )
+                })
+                .unwrap_or_default();
+            let badges: String = SEVERITIES
+                .iter()
+                .filter_map(|severity| {
+                    let count = changes
+                        .iter()
+                        .filter(|change| change.severity == *severity)
+                        .count();
+
+                    if count == 0 {
+                        None
+                    } else {
+                        Some(format!(
+                            " <span class=\"badge {}\">{} {}</span>",
+                            severity.name(),
+                            count,
+                            severity.name()
+                        ))
+                    }
+                })
+                .collect();
+
+            let summary = format!(
+                "<summary><span class=\"method\">{}</span> <code>{}</code>{}{}</summary>",
+                operation.method.to_uppercase(),
+                escape_xml(&operation.path),
+                description,
+                badges
+            );
+
+            result.push_str(&print_section(
+                &summary,
+                &operation.path,
+                &operation.tags,
+                changes,
+                &comparisons,
+            ));
+        }
+
+        result.push_str(&format!("<script>{}</script>\n", SCRIPT));
+        result.push_str("</body>\n</html>\n");
+        result
+    }
+
+    /// The number of changes by severity and kind.
+    fn print_summary(&self) -> String {
+        let mut result =
+            String::from("<h2>Summary</h2>\n<table class=\"summary\">\n<tr><th>Severity</th>");
+
+        for (_, name) in &KINDS {
+            result.push_str(&format!("<th>{}</th>", name));
+        }
+        result.push_str("<th>Total</th></tr>\n");
+
+        let count = |severity: Option<Severity>, kind: Option<Kind>| {
+            self.changes
+                .iter()
+                .filter(|change| severity.map_or(true, |severity| change.severity == severity))
+                .filter(|change| kind.map_or(true, |kind| change.rule.kind == kind))
+                .count()
+        };
+
+        for severity in SEVERITIES
+            .iter()
+            .map(|severity| Some(*severity))
+            .chain(vec![None])
+        {
+            result.push_str(&format!(
+                "<tr><th>{}</th>",
+                severity.map_or("Total", Severity::name)
+            ));
+
+            for (kind, _) in &KINDS {
+                result.push_str(&format!(
+                    "<td class=\"count\">{}</td>",
+                    count(severity, Some(*kind))
+                ));
+            }
+
+            result.push_str(&format!(
+                "<td class=\"count\">{}</td></tr>\n",
+                count(severity, None)
+            ));
+        }
+
+        result.push_str("</table>\n");
+        result
+    }
+}
+
+fn print_filters(tags: &BTreeSet<&String>) -> String {
+    let mut result = String::from("<div class=\"filters\">\n<span>Severity:");
+
+    for severity in &SEVERITIES {
+        result.push_str(&format!(
+            " <label><input type=\"checkbox\" name=\"severity\" value=\"{0}\" checked> {0}</label>",
+            severity.name()
+        ));
+    }
+
+    result.push_str("</span>\n<label>Tag: <select id=\"tag\"><option value=\"\">All tags</option>");
+    for tag in tags {
+        result.push_str(&format!(
+            "<option value=\"{0}\">{0}</option>",
+            escape_xml(tag)
+        ));
+    }
+    result.push_str("</select></label>\n");
+
+    result.push_str(
+        "<label>Path: <input type=\"search\" id=\"path\" placeholder=\"/pets\"></label>\n</div>\n",
+    );
+    result
+}
+
+fn print_section(
+    summary: &str,
+    path: &str,
+    tags: &[String],
+    changes: &[&Change],
+    comparisons: &[Comparison],
+) -> String {
+    let mut result = format!(
+        "<details class=\"operation\" open data-path=\"{}\" data-tags=\"{}\">\n{}\n<ul class=\"changes\">\n",
+        escape_xml(path),
+        escape_xml(&serde_json::to_string(tags).unwrap_or_default()),
+        summary
+    );
+
+    for change in changes {
+        result.push_str(&format!(
+            "<li class=\"change {0}\" data-severity=\"{0}\"><span class=\"severity\">{0}</span> {1} <span class=\"rule\">{2}</span> <code class=\"pointer\">{3}</code></li>\n",
+            change.severity.name(),
+            inline_code(&change.message),
+            change.rule.id,
+            escape_xml(&change.location.pointer),
+        ));
+    }
+
+    result.push_str("</ul>\n");
+
+    for comparison in comparisons {
+        let (before, after) = side_by_side(&comparison.before, &comparison.after);
+
+        result.push_str(&format!(
+            "<details class=\"schema\">\n<summary>{}</summary>\n<div class=\"side-by-side\">\n<div><h4>Before</h4><pre>{}</pre></div>\n<div><h4>After</h4><pre>{}</pre></div>\n</div>\n</details>\n",
+            inline_code(&comparison.label),
+            before,
+            after
+        ));
+    }
+
+    result.push_str("</details>\n");
+    result
+}
+
+/// Escapes `text` and renders the parts between backticks as code.
+fn inline_code(text: &str) -> String {
+    text.split('`')
+        .enumerate()
+        .map(|(index, part)| {
+            if index % 2 == 1 {
+                format!("<code>{}</code>", escape_xml(part))
+            } else {
+                escape_xml(part)
+            }
+        })
+        .collect()
+}
+
+/// Renders both schemas as JSON, highlighting the lines that only one of
+/// them has.
+fn side_by_side(before: &Option<Value>, after: &Option<Value>) -> (String, String) {
+    let lines = |value: &Option<Value>| -> Vec<String> {
+        match value {
+            Some(value) => serde_json::to_string_pretty(value)
+                .unwrap_or_default()
+                .lines()
+                .map(String::from)
+                .collect(),
+            None => vec![],
+        }
+    };
+    let before = lines(before);
+    let after = lines(after);
+
+    let (before_common, after_common) = common_lines(&before, &after);
+
+    let render = |lines: &[String], common: &[bool], class: &str| -> String {
+        if lines.is_empty() {
+            return "<em>None</em>".to_string();
+        }
+
+        lines
+            .iter()
+            .zip(common)
+            .map(|(line, common)| {
+                if *common {
+                    format!("{}\n", escape_xml(line))
+                } else {
+                    format!("<span class=\"{}\">{}</span>", class, escape_xml(line))
+                }
+            })
+            .collect()
+    };
+
+    (
+        render(&before, &before_common, "removed"),
+        render(&after, &after_common, "added"),
+    )
+}
+
+/// Marks the lines of the longest common subsequence of both lists. Every
+/// line is marked when the lists are too long to compare.
+fn common_lines(before: &[String], after: &[String]) -> (Vec<bool>, Vec<bool>) {
+    if before.len() * after.len() > MAX_HIGHLIGHTED_LINES {
+        return (vec![true; before.len()], vec![true; after.len()]);
+    }
+
+    let mut lengths = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            lengths[i][j] = if before[i] == after[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut before_common = vec![false; before.len()];
+    let mut after_common = vec![false; after.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < before.len() && j < after.len() {
+        if before[i] == after[j] {
+            before_common[i] = true;
+            after_common[j] = true;
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    (before_common, after_common)
+}
+
+/// The schemas of the parameters, request body and responses of an
+/// operation that differ between the documents, with references inlined.
+fn comparisons(base: &Value, head: &Value, operation: &OperationContext) -> Vec<Comparison> {
+    let base_operation = operation_value(base, operation);
+    let head_operation = operation_value(head, operation);
+    let mut result = vec![];
+
+    let base_parameters = parameters(base, base_operation);
+    let head_parameters = parameters(head, head_operation);
+    let keys: BTreeSet<&(String, String)> = base_parameters
+        .keys()
+        .chain(head_parameters.keys())
+        .collect();
+
+    for key in keys {
+        let (location, name) = key;
+        push_comparison(
+            &mut result,
+            format!("Schema of {} parameter `{}`", location, name),
+            base_parameters.get(key).map(|schema| inline(base, schema)),
+            head_parameters.get(key).map(|schema| inline(head, schema)),
+        );
+    }
+
+    compare_content(
+        &mut result,
+        "request body",
+        (base, request_body(base, base_operation)),
+        (head, request_body(head, head_operation)),
+    );
+
+    let base_responses = responses(base, base_operation);
+    let head_responses = responses(head, head_operation);
+    let statuses: BTreeSet<&String> = base_responses.keys().chain(head_responses.keys()).collect();
+
+    for status in statuses {
+        compare_content(
+            &mut result,
+            &format!("response `{}`", status),
+            (base, base_responses.get(status).copied()),
+            (head, head_responses.get(status).copied()),
+        );
+    }
+
+    result
+}
+
+fn push_comparison(
+    comparisons: &mut Vec<Comparison>,
+    label: String,
+    before: Option<Value>,
+    after: Option<Value>,
+) {
+    if before != after {
+        comparisons.push(Comparison {
+            label,
+            before,
+            after,
+        });
+    }
+}
+
+/// Compares the schemas of the media types of two objects with `content`.
+/// Media types are matched by key, or with each other if each object has
+/// a single other one.
+fn compare_content(
+    comparisons: &mut Vec<Comparison>,
+    subject: &str,
+    (base, base_object): (&Value, Option<&Value>),
+    (head, head_object): (&Value, Option<&Value>),
+) {
+    let content = |object: Option<&Value>| -> BTreeMap<String, Option<Value>> {
+        object
+            .and_then(|object| object.get("content"))
+            .and_then(Value::as_object)
+            .map(|content| {
+                content
+                    .iter()
+                    .map(|(media_type, value)| (media_type.clone(), value.get("schema").cloned()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let mut base_content = content(base_object);
+    let mut head_content = content(head_object);
+
+    let base_only: Vec<String> = base_content
+        .keys()
+        .filter(|media_type| !head_content.contains_key(*media_type))
+        .cloned()
+        .collect();
+    let head_only: Vec<String> = head_content
+        .keys()
+        .filter(|media_type| !base_content.contains_key(*media_type))
+        .cloned()
+        .collect();
+
+    if let ([base_media_type], [head_media_type]) = (base_only.as_slice(), head_only.as_slice()) {
+        push_comparison(
+            comparisons,
+            format!(
+                "Schema of {} (`{}` → `{}`)",
+                subject, base_media_type, head_media_type
+            ),
+            base_content
+                .remove(base_media_type)
+                .flatten()
+                .map(|schema| inline(base, &schema)),
+            head_content
+                .remove(head_media_type)
+                .flatten()
+                .map(|schema| inline(head, &schema)),
+        );
+    }
+
+    let media_types: BTreeSet<String> = base_content
+        .keys()
+        .chain(head_content.keys())
+        .cloned()
+        .collect();
+
+    for media_type in media_types {
+        push_comparison(
+            comparisons,
+            format!("Schema of {} (`{}`)", subject, media_type),
+            base_content
+                .get(&media_type)
+                .cloned()
+                .flatten()
+                .map(|schema| inline(base, &schema)),
+            head_content
+                .get(&media_type)
+                .cloned()
+                .flatten()
+                .map(|schema| inline(head, &schema)),
+        );
+    }
+}
+
+fn request_body<'v>(document: &'v Value, operation: Option<&'v Value>) -> Option<&'v Value> {
+    operation
+        .and_then(|operation| operation.get("requestBody"))
+        .map(|request_body| resolve(document, request_body))
+}
+
+fn responses<'v>(document: &'v Value, operation: Option<&'v Value>) -> BTreeMap<String, &'v Value> {
+    operation
+        .and_then(|operation| operation.get("responses"))
+        .and_then(Value::as_object)
+        .map(|responses| {
+            responses
+                .iter()
+                .map(|(status, response)| (status.clone(), resolve(document, response)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn operation_value<'v>(document: &'v Value, operation: &OperationContext) -> Option<&'v Value> {
+    let path_item = document
+        .get("paths")
+        .and_then(|paths| paths.get(&operation.path))
+        .or_else(|| {
+            document
+                .get("webhooks")
+                .and_then(|webhooks| webhooks.get(&operation.path))
+        })?;
+
+    resolve(document, path_item).get(&operation.method)
+}
+
+/// The schema of each parameter of an operation, by location and name.
+fn parameters<'v>(
+    document: &'v Value,
+    operation: Option<&'v Value>,
+) -> BTreeMap<(String, String), &'v Value> {
+    operation
+        .and_then(|operation| operation.get("parameters"))
+        .and_then(Value::as_array)
+        .map(|parameters| {
+            parameters
+                .iter()
+                .filter_map(|parameter| {
+                    let parameter = resolve(document, parameter);
+                    let location = parameter.get("in")?.as_str()?.to_string();
+                    let name = parameter.get("name")?.as_str()?.to_string();
+                    let schema = parameter.get("schema").or_else(|| {
+                        parameter
+                            .get("content")?
+                            .as_object()?
+                            .values()
+                            .next()?
+                            .get("schema")
+                    })?;
+
+                    Some(((location, name), schema))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Follows local references.
+fn resolve<'v>(document: &'v Value, value: &'v Value) -> &'v Value {
+    let mut value = value;
+
+    for _ in 0..16 {
+        match value
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| document.pointer(pointer))
+        {
+            Some(target) => value = target,
+            None => break,
+        }
+    }
+
+    value
+}
+
+/// Replaces local references with their targets, except for recursive
+/// references.
+fn inline(document: &Value, value: &Value) -> Value {
+    fn inline_with(document: &Value, value: &Value, stack: &mut Vec<String>) -> Value {
+        match value {
+            Value::Object(object) => {
+                if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+                    let target = reference
+                        .strip_prefix('#')
+                        .and_then(|pointer| document.pointer(pointer));
+
+                    if let Some(target) = target {
+                        if !stack.iter().any(|seen| seen == reference) {
+                            stack.push(reference.to_string());
+                            let inlined = inline_with(document, target, stack);
+                            stack.pop();
+                            return inlined;
+                        }
+                    }
+                }
+
+                Value::Object(
+                    object
+                        .iter()
+                        .map(|(key, value)| (key.clone(), inline_with(document, value, stack)))
+                        .collect(),
+                )
+            }
+            Value::Array(values) => Value::Array(
+                values
+                    .iter()
+                    .map(|value| inline_with(document, value, stack))
+                    .collect(),
+            ),
+            _ => value.clone(),
+        }
+    }
+
+    inline_with(document, value, &mut vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::{diff, parse};
+
+    #[test]
+    fn operation_sections_and_schemas() {
+        let base = parse("openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets:\n    get:\n      tags: [pets]\n      summary: List pets\n      responses:\n        '200':\n          description: Pets\n          content:\n            application/json:\n              schema:\n                $ref: '#/components/schemas/Pet'\ncomponents:\n  schemas:\n    Pet:\n      type: object\n      properties:\n        name:\n          type: string\n").expect("Failed to parse base");
+        let head = parse("openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '2'\npaths:\n  /pets:\n    get:\n      tags: [pets]\n      summary: List pets\n      responses:\n        '200':\n          description: Pets\n          content:\n            application/json:\n              schema:\n                $ref: '#/components/schemas/Pet'\ncomponents:\n  schemas:\n    Pet:\n      type: object\n      properties:\n        id:\n          type: string\n").expect("Failed to parse head");
+        let diff = diff(base.clone(), head.clone()).expect("Failed to diff");
+        let changes = Change::from_diff(&diff, &base, &head);
+
+        let html = HtmlPrinter {
+            changes: &changes,
+            base: &base,
+            head: &head,
+        }
+        .print();
+
+        assert!(html.contains("<tr><th>breaking</th><td class=\"count\">0</td><td class=\"count\">0</td><td class=\"count\">0</td><td class=\"count\">1</td><td class=\"count\">1</td></tr>"));
+        assert!(html.contains("<option value=\"pets\">pets</option>"));
+        assert!(html.contains("<details class=\"operation\" open data-path=\"/pets\" data-tags=\"[&quot;pets&quot;]\">\n<summary><span class=\"method\">GET</span> <code>/pets</code> <span class=\"description\">List pets</span> <span class=\"badge breaking\">1 breaking</span> <span class=\"badge info\">1 info</span></summary>"));
+        assert!(html.contains("<summary>Schema of response <code>200</code> (<code>application/json</code>)</summary>"));
+        assert!(html.contains("<span class=\"removed\">    &quot;name&quot;: {</span>"));
+        assert!(html.contains("<span class=\"added\">    &quot;id&quot;: {</span>"));
+        assert!(!html.contains("$ref"));
+    }
+
+    #[test]
+    fn recursive_references_are_kept() {
+        let document: Value = serde_json::from_str(
+            r##"{"components": {"schemas": {"Node": {"properties": {"next": {"$ref": "#/components/schemas/Node"}}}}}}"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_json::json!({"properties": {"next": {"$ref": "#/components/schemas/Node"}}}),
+            inline(
+                &document,
+                &serde_json::json!({"$ref": "#/components/schemas/Node"})
+            )
+        );
+    }
+}
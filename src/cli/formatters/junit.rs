@@ -0,0 +1,268 @@
+//! Prints changes as a JUnit XML report, with a testsuite per path and a
+//! testcase per operation that fails on breaking changes.
+
+use super::changes::{method_order, Change, OperationContext, Side};
+use super::escape_xml;
+use crate::diff::resolver::Resolver;
+use crate::openapi::OpenAPI;
+use std::collections::BTreeMap;
+
+/// The testsuite of changes that do not belong to an operation.
+const DOCUMENT: &str = "Document";
+
+#[derive(Default)]
+struct TestCase<'a> {
+    changes: Vec<&'a Change>,
+}
+
+impl<'a> TestCase<'a> {
+    fn failed(&self) -> bool {
+        self.changes.iter().any(|change| change.is_breaking())
+    }
+}
+
+pub struct JunitPrinter<'a> {
+    pub changes: &'a [Change],
+    pub base: &'a OpenAPI,
+    pub head: &'a OpenAPI,
+}
+
+impl<'a> JunitPrinter<'a> {
+    pub fn print(&self) -> String {
+        let suites = self.suites();
+        let tests: usize = suites.values().map(BTreeMap::len).sum();
+        let failures: usize = suites
+            .values()
+            .flat_map(BTreeMap::values)
+            .filter(|test_case| test_case.failed())
+            .count();
+
+        let mut result = String::new();
+        result.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        result.push_str(&format!(
+            "<testsuites name=\"anicca\" tests=\"{}\" failures=\"{}\">\n",
+            tests, failures
+        ));
+
+        for (path, test_cases) in &suites {
+            result.push_str(&self.print_suite(path, test_cases));
+        }
+
+        result.push_str("</testsuites>\n");
+        result
+    }
+
+    /// Every operation of either document, by path and then by method in
+    /// the order of `PathItem::operations`, with its changes.
+    fn suites(&self) -> BTreeMap<String, BTreeMap<(usize, String), TestCase<'a>>> {
+        let resolver = Resolver::new(self.base.components.as_ref(), self.head.components.as_ref());
+        let mut suites: BTreeMap<String, BTreeMap<(usize, String), TestCase>> = BTreeMap::new();
+
+        let base_path_items = self
+            .base
+            .paths
+            .iter()
+            .filter_map(|(path, path_item)| Some((path, resolver.base_path_item(path_item)?)));
+        let head_path_items = self
+            .head
+            .paths
+            .iter()
+            .filter_map(|(path, path_item)| Some((path, resolver.head_path_item(path_item)?)));
+
+        for (path, path_item) in base_path_items.chain(head_path_items) {
+            for (method, _) in path_item.operations() {
+                suites
+                    .entry(path.clone())
+                    .or_default()
+                    .entry((method_order(method), method.to_uppercase()))
+                    .or_default();
+            }
+        }
+
+        for change in self.changes {
+            let (suite, test_case) = match &change.operation {
+                Some(OperationContext { path, method, .. }) => {
+                    (path.clone(), (method_order(method), method.to_uppercase()))
+                }
+                None => (DOCUMENT.to_string(), (0, DOCUMENT.to_string())),
+            };
+
+            suites
+                .entry(suite)
+                .or_default()
+                .entry(test_case)
+                .or_default()
+                .changes
+                .push(change);
+        }
+
+        suites
+    }
+
+    fn print_suite(&self, path: &str, test_cases: &BTreeMap<(usize, String), TestCase>) -> String {
+        let failures = test_cases
+            .values()
+            .filter(|test_case| test_case.failed())
+            .count();
+
+        let mut result = format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+            escape_xml(path),
+            test_cases.len(),
+            failures
+        );
+
+        for ((_, method), test_case) in test_cases {
+            let name = if path == DOCUMENT {
+                DOCUMENT.to_string()
+            } else {
+                format!("{} {}", method, path)
+            };
+
+            result.push_str(&self.print_test_case(path, &name, test_case));
+        }
+
+        result.push_str("  </testsuite>\n");
+        result
+    }
+
+    fn print_test_case(&self, path: &str, name: &str, test_case: &TestCase) -> String {
+        let opening = format!(
+            "    <testcase classname=\"{}\" name=\"{}\"",
+            escape_xml(path),
+            escape_xml(name)
+        );
+
+        if test_case.changes.is_empty() {
+            return format!("{}/>\n", opening);
+        }
+
+        let mut result = format!("{}>\n", opening);
+        let mut output = String::new();
+
+        for change in &test_case.changes {
+            if change.is_breaking() {
+                let details = format!(
+                    "{}\nRule: {} ({})\nLocation: {} in the {} document",
+                    change.message,
+                    change.rule.id,
+                    change.rule.description,
+                    pointer(change),
+                    match change.location.side {
+                        Side::Base => "base",
+                        Side::Head => "head",
+                    }
+                );
+
+                result.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    change.rule.id,
+                    escape_xml(&change.message),
+                    escape_xml(&details),
+                ));
+            } else {
+                output.push_str(&format!(
+                    "[{}] {} ({} at {})\n",
+                    change.severity.name(),
+                    change.message,
+                    change.rule.id,
+                    pointer(change),
+                ));
+            }
+        }
+
+        if !output.is_empty() {
+            result.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                escape_xml(&output)
+            ));
+        }
+
+        result.push_str("    </testcase>\n");
+        result
+    }
+}
+
+fn pointer(change: &Change) -> String {
+    if change.location.pointer.is_empty() {
+        "/".to_string()
+    } else {
+        change.location.pointer.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::{diff, parse};
+
+    #[test]
+    fn breaking_changes_fail() {
+        let base = parse("openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets:\n    get:\n      responses: {}\n    post:\n      responses: {}\n  /pets/{id}:\n    get:\n      summary: Show a pet\n      responses: {}\n").expect("Failed to parse base");
+        let head = parse("openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets:\n    get:\n      responses: {}\n  /pets/{id}:\n    get:\n      summary: Show a <pet>\n      responses: {}\n").expect("Failed to parse head");
+        let diff = diff(base.clone(), head.clone()).expect("Failed to diff");
+        let changes = Change::from_diff(&diff, &base, &head);
+
+        let junit = JunitPrinter {
+            changes: &changes,
+            base: &base,
+            head: &head,
+        }
+        .print();
+
+        assert_eq!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<testsuites name=\"anicca\" tests=\"3\" failures=\"1\">
+  <testsuite name=\"/pets\" tests=\"2\" failures=\"1\">
+    <testcase classname=\"/pets\" name=\"GET /pets\"/>
+    <testcase classname=\"/pets\" name=\"POST /pets\">
+      <failure type=\"operation-removed\" message=\"Operation was removed.\">Operation was removed.
+Rule: operation-removed (An operation was removed.)
+Location: /paths/~1pets/post in the base document</failure>
+    </testcase>
+  </testsuite>
+  <testsuite name=\"/pets/{id}\" tests=\"1\" failures=\"0\">
+    <testcase classname=\"/pets/{id}\" name=\"GET /pets/{id}\">
+      <system-out>[info] Summary changed from `Show a pet` to `Show a &lt;pet&gt;`. (operation-docs-changed at /paths/~1pets~1{id}/get/summary)
+</system-out>
+    </testcase>
+  </testsuite>
+</testsuites>
+",
+            junit
+        );
+    }
+
+    #[test]
+    fn widened_webhook_payload_fails() {
+        let document = |format: &str| {
+            parse(&format!("openapi: 3.1.0\ninfo:\n  title: Pets\n  version: '1'\nwebhooks:\n  newPet:\n    post:\n      requestBody:\n        content:\n          application/json:\n            schema:\n              type: object\n              properties:\n                id:\n                  type: integer\n                  format: {}\n      responses: {{}}\n", format)).expect("Failed to parse document")
+        };
+        let base = document("int32");
+        let head = document("int64");
+        let diff = diff(base.clone(), head.clone()).expect("Failed to diff");
+        let changes = Change::from_diff(&diff, &base, &head);
+
+        let junit = JunitPrinter {
+            changes: &changes,
+            base: &base,
+            head: &head,
+        }
+        .print();
+
+        assert_eq!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<testsuites name=\"anicca\" tests=\"1\" failures=\"1\">
+  <testsuite name=\"newPet\" tests=\"1\" failures=\"1\">
+    <testcase classname=\"newPet\" name=\"POST newPet\">
+      <failure type=\"schema-type-changed\" message=\"Format of property `id` of the request body (`application/json`) changed from `int32` to `int64`.\">Format of property `id` of the request body (`application/json`) changed from `int32` to `int64`.
+Rule: schema-type-changed (The type or format of a schema changed.)
+Location: /webhooks/newPet/post/requestBody/content/application~1json/schema/properties/id in the head document</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+",
+            junit
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::diff::callbacks::CallbacksDiff;
+
+pub struct CallbacksPrinter<'a> {
+    pub callbacks: &'a CallbacksDiff,
+}
+
+impl<'a> CallbacksPrinter<'a> {
+    pub fn print(&self) -> String {
+        let mut result = String::new();
+
+        for callback_pair in &self.callbacks.added {
+            result.push_str(&format!(
+                "    - Callback `{}` was added.\n",
+                callback_pair.0
+            ));
+        }
+
+        for callback_pair in &self.callbacks.removed {
+            result.push_str(&format!(
+                "    - Callback `{}` was removed.\n",
+                callback_pair.0
+            ));
+        }
+
+        for (name, callback_diff) in &self.callbacks.changed {
+            result.push_str(&format!("    - Callback `{}` changed:\n", name));
+
+            if callback_diff.reference.is_some() {
+                result.push_str("      - Callback reference changed.\n");
+            }
+
+            for expression_pair in &callback_diff.expressions_added {
+                result.push_str(&format!(
+                    "      - Expression `{}` was added.\n",
+                    expression_pair.0
+                ));
+            }
+
+            for expression_pair in &callback_diff.expressions_removed {
+                result.push_str(&format!(
+                    "      - Expression `{}` was removed.\n",
+                    expression_pair.0
+                ));
+            }
+
+            for (expression, path_item_diff) in &callback_diff.expressions_changed {
+                for operation_method in &path_item_diff.operations_added {
+                    result.push_str(&format!(
+                        "      - Operation `{} {}` was added.\n",
+                        operation_method.0.to_uppercase(),
+                        expression
+                    ));
+                }
+
+                for operation_method in &path_item_diff.operations_removed {
+                    result.push_str(&format!(
+                        "      - Operation `{} {}` was removed.\n",
+                        operation_method.0.to_uppercase(),
+                        expression
+                    ));
+                }
+
+                for method in path_item_diff.operations_changed.keys() {
+                    result.push_str(&format!(
+                        "      - Operation `{} {}` changed.\n",
+                        method.to_uppercase(),
+                        expression
+                    ));
+                }
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,37 @@
+use crate::diff::endpoints::EndpointsDiff;
+
+pub struct EndpointsPrinter<'a> {
+    pub diff: &'a EndpointsDiff,
+}
+
+impl<'a> EndpointsPrinter<'a> {
+    pub fn print(&self) -> String {
+        let mut result = String::new();
+
+        if !self.diff.has_changes() {
+            return result;
+        }
+
+        result.push_str("### Endpoints\n\n");
+
+        for endpoint in &self.diff.added {
+            result.push_str(
+                format!("  - `{} {}` was added.\n", endpoint.method, endpoint.url).as_str(),
+            );
+        }
+
+        for endpoint in &self.diff.removed {
+            result.push_str(
+                format!(
+                    "  - `{} {}` no longer exists.\n",
+                    endpoint.method, endpoint.url
+                )
+                .as_str(),
+            );
+        }
+
+        result.push('\n');
+
+        result
+    }
+}
@@ -0,0 +1,45 @@
+use crate::diff::examples::{ExampleSource, InvalidExample};
+
+pub struct InvalidExamplesPrinter<'a> {
+    pub invalid_examples: &'a [InvalidExample],
+    pub indent: usize,
+}
+
+impl<'a> InvalidExamplesPrinter<'a> {
+    pub fn print(&self) -> String {
+        let mut result = String::new();
+
+        for invalid_example in self.invalid_examples {
+            let summary = match invalid_example.source {
+                ExampleSource::Head => "no longer validates against the schema",
+                ExampleSource::Base => "from base would not validate against the new schema",
+            };
+
+            result.push_str(&format!(
+                "{:indent$}- Example `{}` {}:\n",
+                "",
+                invalid_example.name,
+                summary,
+                indent = self.indent
+            ));
+
+            for error in &invalid_example.errors {
+                let path = if error.path.is_empty() {
+                    "/"
+                } else {
+                    &error.path
+                };
+
+                result.push_str(&format!(
+                    "{:indent$}- `{}`: {}\n",
+                    "",
+                    path,
+                    error.message,
+                    indent = self.indent + 2
+                ));
+            }
+        }
+
+        result
+    }
+}
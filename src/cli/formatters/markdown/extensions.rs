@@ -1,4 +1,4 @@
-use crate::diff::extensions::ExtensionsDiff;
+use crate::diff::extensions::{ExtensionsDiff, ValueChange};
 
 pub struct ExtensionsPrinter<'a> {
     pub extensions: &'a ExtensionsDiff,
@@ -18,6 +18,58 @@ impl<'a> ExtensionsPrinter<'a> {
             ));
         }
 
+        for p in &self.extensions.removed {
+            result.push_str(&format!(
+                "{:indent$}- Extension `{}` was removed.\n",
+                "",
+                p.0,
+                indent = self.indent
+            ));
+        }
+
+        let mut changed: Vec<_> = self.extensions.changed.iter().collect();
+        changed.sort_by_key(|(name, _)| *name);
+
+        for (name, diff) in changed {
+            for change in &diff.changes {
+                result.push_str(&self.print_change(name, change));
+            }
+        }
+
         result
     }
+
+    fn print_change(&self, name: &str, change: &ValueChange) -> String {
+        let location = if change.pointer.is_empty() {
+            format!("`{}`", name)
+        } else {
+            format!("`{}` at `{}`", name, change.pointer)
+        };
+
+        match (&change.from, &change.to) {
+            (Some(from), Some(to)) => format!(
+                "{:indent$}- Extension {} changed from `{}` to `{}`.\n",
+                "",
+                location,
+                from,
+                to,
+                indent = self.indent
+            ),
+            (None, Some(to)) => format!(
+                "{:indent$}- Extension {} `{}` was added.\n",
+                "",
+                location,
+                to,
+                indent = self.indent
+            ),
+            (Some(from), None) => format!(
+                "{:indent$}- Extension {} `{}` was removed.\n",
+                "",
+                location,
+                from,
+                indent = self.indent
+            ),
+            (None, None) => String::new(),
+        }
+    }
 }
@@ -0,0 +1,60 @@
+use super::extensions::ExtensionsPrinter;
+use crate::diff::external_docs::ExternalDocsDiff;
+
+pub struct ExternalDocsPrinter<'a> {
+    pub diff: &'a ExternalDocsDiff,
+    pub indent: usize,
+}
+
+impl<'a> ExternalDocsPrinter<'a> {
+    pub fn print(&self) -> String {
+        let mut result = String::new();
+
+        if let Some(added) = &self.diff.added {
+            result.push_str(&format!(
+                "{:indent$}- External docs `{}` were added.\n",
+                "",
+                added.url,
+                indent = self.indent
+            ));
+        }
+
+        if let Some(removed) = &self.diff.removed {
+            result.push_str(&format!(
+                "{:indent$}- External docs `{}` were removed.\n",
+                "",
+                removed.url,
+                indent = self.indent
+            ));
+        }
+
+        if let Some(url_diff) = &self.diff.url {
+            result.push_str(&format!(
+                "{:indent$}- External docs URL changed from `{}` to `{}`.\n",
+                "",
+                url_diff.from,
+                url_diff.to,
+                indent = self.indent
+            ));
+        }
+
+        if self.diff.description.is_some() {
+            result.push_str(&format!(
+                "{:indent$}- External docs description changed.\n",
+                "",
+                indent = self.indent
+            ));
+        }
+
+        if let Some(extensions_diff) = &self.diff.extensions {
+            let extensions = ExtensionsPrinter {
+                extensions: extensions_diff,
+                indent: self.indent,
+            }
+            .print();
+            result.push_str(&extensions);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,213 @@
+use super::examples::InvalidExamplesPrinter;
+use super::extensions::ExtensionsPrinter;
+use super::schema::SchemaPrinter;
+use crate::diff::compatibility::Compatibility;
+use crate::diff::encodings::EncodingDiff;
+use crate::diff::examples::ExamplesDiff;
+use crate::diff::media_range::MediaRangeDiff;
+use crate::diff::media_type::MediaTypeDiff;
+
+pub struct MediaTypePrinter<'a> {
+    pub diff: &'a MediaTypeDiff,
+    pub indent: usize,
+}
+
+impl<'a> MediaTypePrinter<'a> {
+    pub fn print(&self) -> String {
+        let mut result = String::new();
+
+        if let Some(range_diff) = &self.diff.range_changed {
+            result.push_str(&self.print_range_change(range_diff));
+        }
+
+        if self.diff.schema_added.is_some() {
+            result.push_str(&self.line("Schema was added."));
+        }
+
+        if self.diff.schema_removed.is_some() {
+            result.push_str(&self.line("Schema was removed."));
+        }
+
+        if let Some(schema_diff) = &self.diff.schema_changed {
+            let schema = SchemaPrinter {
+                diff: schema_diff,
+                indent: self.indent,
+            }
+            .print();
+            result.push_str(&schema);
+        }
+
+        if self.diff.example_changed.is_some() {
+            result.push_str(&self.line("Example changed."));
+        }
+
+        if let Some(examples_diff) = &self.diff.examples_changed {
+            result.push_str(&self.print_examples(examples_diff));
+        }
+
+        if let Some(encodings_diff) = &self.diff.encoding_changed {
+            for encoding_pair in &encodings_diff.added {
+                result.push_str(&self.line(&format!(
+                    "Encoding for property `{}` was added.",
+                    encoding_pair.0
+                )));
+            }
+
+            for encoding_pair in &encodings_diff.removed {
+                result.push_str(&self.line(&format!(
+                    "Encoding for property `{}` was removed.",
+                    encoding_pair.0
+                )));
+            }
+
+            for (property, encoding_diff) in &encodings_diff.changed {
+                result.push_str(
+                    &self.line(&format!("Encoding for property `{}` changed:", property)),
+                );
+                result.push_str(&self.print_encoding(encoding_diff));
+            }
+        }
+
+        if let Some(extensions_diff) = &self.diff.extensions_changed {
+            let extensions = ExtensionsPrinter {
+                extensions: extensions_diff,
+                indent: self.indent,
+            }
+            .print();
+            result.push_str(&extensions);
+        }
+
+        let invalid_examples = InvalidExamplesPrinter {
+            invalid_examples: &self.diff.invalid_examples,
+            indent: self.indent,
+        }
+        .print();
+        result.push_str(&invalid_examples);
+
+        result
+    }
+
+    fn print_range_change(&self, diff: &MediaRangeDiff) -> String {
+        let compatibility = match diff.compatibility {
+            Compatibility::Equivalent => "equivalent",
+            Compatibility::Widened => "widened",
+            Compatibility::Narrowed => "narrowed",
+            Compatibility::Incompatible => "incompatible",
+        };
+
+        self.line(&format!(
+            "Media type changed from `{}` to `{}` ({}{}).",
+            diff.from,
+            diff.to,
+            compatibility,
+            if diff.breaking { ", breaking" } else { "" },
+        ))
+    }
+
+    fn print_examples(&self, diff: &ExamplesDiff) -> String {
+        let mut result = String::new();
+
+        for example_pair in &diff.added {
+            result.push_str(&self.line(&format!("Example `{}` was added.", example_pair.0)));
+        }
+
+        for example_pair in &diff.removed {
+            result.push_str(&self.line(&format!("Example `{}` was removed.", example_pair.0)));
+        }
+
+        for name in diff.changed.keys() {
+            result.push_str(&self.line(&format!("Example `{}` changed.", name)));
+        }
+
+        result
+    }
+
+    fn print_encoding(&self, diff: &EncodingDiff) -> String {
+        let mut result = String::new();
+        let indent = self.indent + 2;
+
+        if let Some(content_type_diff) = &diff.content_type {
+            result.push_str(&format!(
+                "{:indent$}- Content type changed from `{}` to `{}`.\n",
+                "",
+                content_type_diff.from.as_deref().unwrap_or("default"),
+                content_type_diff.to.as_deref().unwrap_or("default"),
+                indent = indent
+            ));
+        }
+
+        if let Some(headers_diff) = &diff.headers {
+            for header_pair in &headers_diff.added {
+                result.push_str(&format!(
+                    "{:indent$}- Header `{}` was added.\n",
+                    "",
+                    header_pair.0,
+                    indent = indent
+                ));
+            }
+
+            for header_pair in &headers_diff.removed {
+                result.push_str(&format!(
+                    "{:indent$}- Header `{}` was removed.\n",
+                    "",
+                    header_pair.0,
+                    indent = indent
+                ));
+            }
+
+            for name in headers_diff.changed.keys() {
+                result.push_str(&format!(
+                    "{:indent$}- Header `{}` changed.\n",
+                    "",
+                    name,
+                    indent = indent
+                ));
+            }
+        }
+
+        if let Some(style_diff) = &diff.style {
+            result.push_str(&format!(
+                "{:indent$}- Style changed from `{}` to `{}`.\n",
+                "",
+                style_diff.from,
+                style_diff.to,
+                indent = indent
+            ));
+        }
+
+        if let Some(explode_diff) = &diff.explode {
+            result.push_str(&format!(
+                "{:indent$}- Explode changed from `{}` to `{}`.\n",
+                "",
+                explode_diff.from,
+                explode_diff.to,
+                indent = indent
+            ));
+        }
+
+        if let Some(allow_reserved_diff) = &diff.allow_reserved {
+            result.push_str(&format!(
+                "{:indent$}- Allow reserved changed from `{}` to `{}`.\n",
+                "",
+                allow_reserved_diff.from,
+                allow_reserved_diff.to,
+                indent = indent
+            ));
+        }
+
+        if let Some(extensions_diff) = &diff.extensions {
+            let extensions = ExtensionsPrinter {
+                extensions: extensions_diff,
+                indent,
+            }
+            .print();
+            result.push_str(&extensions);
+        }
+
+        result
+    }
+
+    fn line(&self, text: &str) -> String {
+        format!("{:indent$}- {}\n", "", text, indent = self.indent)
+    }
+}
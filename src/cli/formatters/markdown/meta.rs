@@ -1,4 +1,7 @@
 use super::extensions::ExtensionsPrinter;
+use super::external_docs::ExternalDocsPrinter;
+use super::servers::ServersPrinter;
+use crate::diff::security::requirement_key;
 use crate::diff::Diff;
 
 pub struct MetaPrinter<'a> {
@@ -168,6 +171,127 @@ impl<'a> MetaPrinter<'a> {
             }
         }
 
+        if let Some(servers_diff) = &self.diff.servers {
+            result.push_str("Servers have changed:\n");
+
+            let servers = ServersPrinter {
+                diff: servers_diff,
+                indent: 2,
+            }
+            .print();
+            result.push_str(&servers);
+        }
+
+        if let Some(tags_diff) = &self.diff.tags {
+            result.push_str("Tags have changed:\n");
+
+            for tag in &tags_diff.added {
+                result.push_str(format!("  - Tag `{}` was added.\n", tag.name).as_str());
+            }
+
+            for tag in &tags_diff.removed {
+                result.push_str(format!("  - Tag `{}` was removed.\n", tag.name).as_str());
+            }
+
+            for (name, tag_diff) in &tags_diff.changed {
+                result.push_str(format!("  - Tag `{}` has changed:\n", name).as_str());
+
+                if tag_diff.description.is_some() {
+                    result.push_str("    - Description changed.\n");
+                }
+
+                if let Some(external_docs_diff) = &tag_diff.external_docs {
+                    let external_docs = ExternalDocsPrinter {
+                        diff: external_docs_diff,
+                        indent: 4,
+                    }
+                    .print();
+                    result.push_str(&external_docs);
+                }
+
+                if let Some(extensions_diff) = &tag_diff.extensions {
+                    let extensions_printed_diff = ExtensionsPrinter {
+                        extensions: extensions_diff,
+                        indent: 4,
+                    }
+                    .print();
+                    result.push_str(&extensions_printed_diff);
+                }
+            }
+        }
+
+        if let Some(external_docs_diff) = &self.diff.external_docs {
+            result.push_str("API external docs have changed:\n");
+
+            let external_docs = ExternalDocsPrinter {
+                diff: external_docs_diff,
+                indent: 2,
+            }
+            .print();
+            result.push_str(&external_docs);
+        }
+
+        if let Some(security_diff) = &self.diff.security {
+            result.push_str("Default security has changed:\n");
+
+            for requirement in &security_diff.added {
+                result.push_str(
+                    format!(
+                        "  - Security requirement `{}` was added.\n",
+                        requirement_name(&requirement_key(requirement))
+                    )
+                    .as_str(),
+                );
+            }
+
+            for requirement in &security_diff.removed {
+                result.push_str(
+                    format!(
+                        "  - Security requirement `{}` was removed.\n",
+                        requirement_name(&requirement_key(requirement))
+                    )
+                    .as_str(),
+                );
+            }
+
+            for (key, requirement_diff) in &security_diff.changed {
+                for (scheme, scopes_diff) in &requirement_diff.scopes {
+                    if !scopes_diff.added.is_empty() {
+                        result.push_str(
+                            format!(
+                                "  - Scopes `{}` of `{}` were added to requirement `{}`.\n",
+                                scopes_diff.added.join(","),
+                                scheme,
+                                key
+                            )
+                            .as_str(),
+                        );
+                    }
+
+                    if !scopes_diff.removed.is_empty() {
+                        result.push_str(
+                            format!(
+                                "  - Scopes `{}` of `{}` were removed from requirement `{}`.\n",
+                                scopes_diff.removed.join(","),
+                                scheme,
+                                key
+                            )
+                            .as_str(),
+                        );
+                    }
+                }
+            }
+        }
+
         result
     }
 }
+
+/// The empty requirement allows anonymous access.
+fn requirement_name(key: &str) -> &str {
+    if key.is_empty() {
+        "anonymous"
+    } else {
+        key
+    }
+}
@@ -1,4 +1,9 @@
+pub mod callbacks;
+pub mod endpoints;
+pub mod examples;
 pub mod extensions;
+pub mod external_docs;
+pub mod media_type;
 pub mod meta;
 pub mod operations;
 pub mod parameters;
@@ -6,8 +11,10 @@ pub mod paths;
 pub mod request_body;
 pub mod responses;
 pub mod schema;
+pub mod servers;
 
 use crate::diff::Diff;
+use endpoints::EndpointsPrinter;
 use meta::MetaPrinter;
 use paths::PathsPrinter;
 
@@ -24,14 +31,35 @@ impl Printer {
 
         result.push_str("## OpenAPI diff\n\n");
 
-        let meta = MetaPrinter { diff: &diff }.print();
+        let meta = MetaPrinter { diff }.print();
         result.push_str(&meta);
 
         if let Some(paths_diff) = &diff.paths {
-            let paths = PathsPrinter { diff: paths_diff }.print();
+            let paths = PathsPrinter {
+                diff: paths_diff,
+                title: "Paths",
+            }
+            .print();
             result.push_str(&paths);
         }
 
+        if let Some(endpoints_diff) = &diff.endpoints {
+            let endpoints = EndpointsPrinter {
+                diff: endpoints_diff,
+            }
+            .print();
+            result.push_str(&endpoints);
+        }
+
+        if let Some(webhooks_diff) = &diff.webhooks {
+            let webhooks = PathsPrinter {
+                diff: webhooks_diff,
+                title: "Webhooks",
+            }
+            .print();
+            result.push_str(&webhooks);
+        }
+
         result
     }
 }
@@ -1,6 +1,10 @@
+use super::callbacks::CallbacksPrinter;
+use super::extensions::ExtensionsPrinter;
+use super::external_docs::ExternalDocsPrinter;
 use super::parameters::ParametersPrinter;
 use super::request_body::RequestBodyPrinter;
 use super::responses::ResponsesPrinter;
+use super::servers::ServersPrinter;
 use crate::diff::operations::OperationDiff;
 
 pub struct OperationsPrinter<'a> {
@@ -57,8 +61,7 @@ impl<'a> OperationsPrinter<'a> {
                     result.push_str("    - Operation description was removed.\n");
                 }
             } else if let Some(to) = &diff.to {
-                result
-                    .push_str(format!("    - Operation description added: `{}`\n", to).as_str());
+                result.push_str(format!("    - Operation description added: `{}`\n", to).as_str());
             }
         }
 
@@ -77,6 +80,44 @@ impl<'a> OperationsPrinter<'a> {
             }
         }
 
+        if let Some(diff) = &self.operation_diff.deprecated {
+            if diff.to {
+                result.push_str("    - Operation was deprecated.\n");
+            } else {
+                result.push_str("    - Operation is no longer deprecated.\n");
+            }
+        }
+
+        if let Some(external_docs_diff) = &self.operation_diff.external_docs {
+            let external_docs = ExternalDocsPrinter {
+                diff: external_docs_diff,
+                indent: 4,
+            }
+            .print();
+
+            result.push_str(&external_docs);
+        }
+
+        if let Some(servers_diff) = &self.operation_diff.servers {
+            let servers = ServersPrinter {
+                diff: servers_diff,
+                indent: 4,
+            }
+            .print();
+
+            result.push_str(&servers);
+        }
+
+        if let Some(extensions_diff) = &self.operation_diff.extensions {
+            let extensions = ExtensionsPrinter {
+                extensions: extensions_diff,
+                indent: 4,
+            }
+            .print();
+
+            result.push_str(&extensions);
+        }
+
         let params = ParametersPrinter {
             parameters: &self.operation_diff.parameters,
         }
@@ -85,10 +126,7 @@ impl<'a> OperationsPrinter<'a> {
         result.push_str(&params);
 
         if let Some(request_body) = &self.operation_diff.request_body {
-            let request_body = RequestBodyPrinter {
-                request_body: &request_body,
-            }
-            .print();
+            let request_body = RequestBodyPrinter { request_body }.print();
 
             result.push_str(&request_body);
         }
@@ -100,6 +138,12 @@ impl<'a> OperationsPrinter<'a> {
 
         result.push_str(&params);
 
+        if let Some(callbacks) = &self.operation_diff.callbacks {
+            let callbacks = CallbacksPrinter { callbacks }.print();
+
+            result.push_str(&callbacks);
+        }
+
         result
     }
 }
@@ -1,3 +1,9 @@
+use super::examples::InvalidExamplesPrinter;
+use super::extensions::ExtensionsPrinter;
+use super::media_type::MediaTypePrinter;
+use super::schema::SchemaPrinter;
+use crate::diff::common::BooleanDiff;
+use crate::diff::parameter::ParameterDiff;
 use crate::diff::parameters::ParametersDiff;
 use crate::openapi::{Parameter, ReferenceOr};
 
@@ -126,6 +132,185 @@ impl<'a> ParametersPrinter<'a> {
             }
         }
 
+        for (key, diff) in &self.parameters.changed {
+            let (location, name) = key.split_once('.').unwrap_or(("", key));
+            let label = match location {
+                "query" => "Query parameter",
+                "header" => "Header",
+                "path" => "Path parameter",
+                "cookie" => "Cookie",
+                _ => "Parameter",
+            };
+
+            result.push_str(format!("      - {} `{}` changed:\n", label, name).as_str());
+            result.push_str(&Self::print_changed_parameter(diff));
+        }
+
         result
     }
+
+    fn print_changed_parameter(diff: &ParameterDiff) -> String {
+        let mut result = String::new();
+
+        if let Some(deprecated) = &diff.deprecated {
+            if deprecated.to {
+                result.push_str("        - Parameter was deprecated.\n");
+            } else {
+                result.push_str("        - Parameter is no longer deprecated.\n");
+            }
+        }
+
+        if let Some(required) = &diff.required {
+            if required.to {
+                result.push_str("        - Parameter is now required.\n");
+            } else {
+                result.push_str("        - Parameter is no longer required.\n");
+            }
+        }
+
+        if let Some(in_change) = &diff.in_change {
+            result.push_str(
+                format!(
+                    "        - Location changed from `{}` to `{}`.\n",
+                    in_change.from, in_change.to
+                )
+                .as_str(),
+            );
+        }
+
+        if let Some(serialization) = &diff.serialization_changed {
+            result.push_str(
+                format!(
+                    "        - Parameter now uses `{}` instead of `{}`.\n",
+                    serialization.to, serialization.from
+                )
+                .as_str(),
+            );
+        }
+
+        if let Some(style) = &diff.style {
+            result.push_str(
+                format!(
+                    "        - Style changed from `{}` to `{}`.\n",
+                    style.from, style.to
+                )
+                .as_str(),
+            );
+        }
+
+        result.push_str(&Self::print_boolean_change("Explode", &diff.explode));
+        result.push_str(&Self::print_boolean_change(
+            "Allow reserved",
+            &diff.allow_reserved,
+        ));
+
+        if let Some(allow_empty_value) = &diff.allow_empty_value {
+            result.push_str(&format!(
+                "        - Allow empty value changed from `{}` to `{}`{}.\n",
+                allow_empty_value.from,
+                allow_empty_value.to,
+                if allow_empty_value.breaking {
+                    " (breaking)"
+                } else {
+                    ""
+                },
+            ));
+        }
+
+        if diff.wire_format_changed {
+            result.push_str("        - Clients must serialize this parameter differently.\n");
+        }
+
+        if diff.description.is_some() {
+            result.push_str("        - Description changed.\n");
+        }
+
+        if let Some(schema_diff) = &diff.schema {
+            let schema = SchemaPrinter {
+                diff: schema_diff,
+                indent: 8,
+            }
+            .print();
+            result.push_str(&schema);
+        }
+
+        if let Some(content_diff) = &diff.content {
+            for media_type_pair in &content_diff.added {
+                result.push_str(&format!(
+                    "        - Media type `{}` was added.\n",
+                    media_type_pair.0
+                ));
+            }
+
+            for media_type_pair in &content_diff.removed {
+                result.push_str(&format!(
+                    "        - Media type `{}` was removed.\n",
+                    media_type_pair.0
+                ));
+            }
+
+            for (media_type, media_type_diff) in &content_diff.changed {
+                result.push_str(&format!("        - Media type `{}` changed:\n", media_type));
+
+                let media_type = MediaTypePrinter {
+                    diff: media_type_diff,
+                    indent: 10,
+                }
+                .print();
+                result.push_str(&media_type);
+            }
+        }
+
+        if diff.example.is_some() {
+            result.push_str("        - Example changed.\n");
+        }
+
+        if let Some(examples_diff) = &diff.examples {
+            for example_pair in &examples_diff.added {
+                result.push_str(&format!(
+                    "        - Example `{}` was added.\n",
+                    example_pair.0
+                ));
+            }
+
+            for example_pair in &examples_diff.removed {
+                result.push_str(&format!(
+                    "        - Example `{}` was removed.\n",
+                    example_pair.0
+                ));
+            }
+
+            for name in examples_diff.changed.keys() {
+                result.push_str(&format!("        - Example `{}` changed.\n", name));
+            }
+        }
+
+        if let Some(extensions_diff) = &diff.extensions {
+            let extensions = ExtensionsPrinter {
+                extensions: extensions_diff,
+                indent: 8,
+            }
+            .print();
+            result.push_str(&extensions);
+        }
+
+        let invalid_examples = InvalidExamplesPrinter {
+            invalid_examples: &diff.invalid_examples,
+            indent: 8,
+        }
+        .print();
+        result.push_str(&invalid_examples);
+
+        result
+    }
+
+    fn print_boolean_change(name: &str, diff: &Option<BooleanDiff>) -> String {
+        match diff {
+            Some(diff) => format!(
+                "        - {} changed from `{}` to `{}`.\n",
+                name, diff.from, diff.to
+            ),
+            None => String::new(),
+        }
+    }
 }
@@ -1,9 +1,12 @@
+use super::extensions::ExtensionsPrinter;
 use super::operations::OperationsPrinter;
 use crate::diff::paths::PathsDiff;
 use crate::openapi::ReferenceOr;
 
 pub struct PathsPrinter<'a> {
     pub diff: &'a PathsDiff,
+    /// The section heading, `Paths` or `Webhooks`.
+    pub title: &'a str,
 }
 
 impl<'a> PathsPrinter<'a> {
@@ -14,7 +17,7 @@ impl<'a> PathsPrinter<'a> {
             return result;
         }
 
-        result.push_str("### Paths\n\n");
+        result.push_str(&format!("### {}\n\n", self.title));
         result.push_str(&self.print_added_paths());
         result.push_str(&self.print_removed_paths());
         result.push_str(&self.print_changed_paths());
@@ -30,13 +33,25 @@ impl<'a> PathsPrinter<'a> {
         }
 
         for (path, path_item_diff) in &self.diff.changed {
+            if let Some(extensions_diff) = &path_item_diff.extensions {
+                result.push_str(format!("  - On path `{}`\n", path).as_str());
+
+                let extensions = ExtensionsPrinter {
+                    extensions: extensions_diff,
+                    indent: 4,
+                }
+                .print();
+
+                result.push_str(&extensions);
+            }
+
             for operation_method in &path_item_diff.operations_added {
                 match &operation_method.1.operation_id {
                     Some(op_id) => {
                         result.push_str(
                             format!(
                                 "  - Operation {} {} was added ({}).\n",
-                                operation_method.0, path, op_id 
+                                operation_method.0, path, op_id
                             )
                             .as_str(),
                         );
@@ -81,10 +96,7 @@ impl<'a> PathsPrinter<'a> {
                     format!("  - On operation `{} {}`\n", method.to_uppercase(), path).as_str(),
                 );
 
-                let op_diff = OperationsPrinter {
-                    operation_diff: &operation_diff,
-                }
-                .print();
+                let op_diff = OperationsPrinter { operation_diff }.print();
 
                 result.push_str(&op_diff);
             }
@@ -177,7 +189,7 @@ impl<'a> PathsPrinter<'a> {
             }
         }
 
-        result.push_str("\n");
+        result.push('\n');
 
         result
     }
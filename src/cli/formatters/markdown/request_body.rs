@@ -1,4 +1,5 @@
-use super::schema::SchemaPrinter;
+use super::extensions::ExtensionsPrinter;
+use super::media_type::MediaTypePrinter;
 use crate::diff::request_body::RequestBodyDiff;
 
 pub struct RequestBodyPrinter<'a> {
@@ -9,6 +10,14 @@ impl<'a> RequestBodyPrinter<'a> {
     pub fn print(&self) -> String {
         let mut result = String::new();
 
+        if let Some(reference_diff) = &self.request_body.reference {
+            result.push_str(&format!(
+                "    - Request body reference changed from `{}` to `{}`.\n",
+                reference_diff.from.as_deref().unwrap_or("inline"),
+                reference_diff.to.as_deref().unwrap_or("inline")
+            ));
+        }
+
         if self.request_body.added.is_some() {
             result.push_str("    - A request body was added.\n");
         }
@@ -17,6 +26,27 @@ impl<'a> RequestBodyPrinter<'a> {
             result.push_str("    - Request body was removed.\n");
         }
 
+        if let Some(required_diff) = &self.request_body.required_changed {
+            if required_diff.to {
+                result.push_str("    - Request body is now required.\n");
+            } else {
+                result.push_str("    - Request body is no longer required.\n");
+            }
+        }
+
+        if self.request_body.description_changed.is_some() {
+            result.push_str("    - Request body description changed.\n");
+        }
+
+        if let Some(extensions_diff) = &self.request_body.extensions_changed {
+            let extensions = ExtensionsPrinter {
+                extensions: extensions_diff,
+                indent: 4,
+            }
+            .print();
+            result.push_str(&extensions);
+        }
+
         if let Some(content_diff) = &self.request_body.content_changed {
             for media_type_pair in &content_diff.added {
                 result.push_str(&format!(
@@ -38,17 +68,15 @@ impl<'a> RequestBodyPrinter<'a> {
                     media_type
                 ));
 
-                if let Some(schema_diff) = &media_type_diff.schema_changed {
-                    let schema = SchemaPrinter {
-                        diff: &schema_diff,
-                        indent: 6,
-                    }
-                    .print();
-                    result.push_str(&schema);
+                let media_type = MediaTypePrinter {
+                    diff: media_type_diff,
+                    indent: 6,
                 }
+                .print();
+                result.push_str(&media_type);
             }
         }
 
         result
     }
-}
\ No newline at end of file
+}
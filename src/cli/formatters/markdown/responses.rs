@@ -1,4 +1,5 @@
-use super::schema::SchemaPrinter;
+use super::extensions::ExtensionsPrinter;
+use super::media_type::MediaTypePrinter;
 use crate::diff::responses::ResponsesDiff;
 
 pub struct ResponsesPrinter<'a> {
@@ -29,6 +30,14 @@ impl<'a> ResponsesPrinter<'a> {
                 status_code
             ));
 
+            if let Some(reference_diff) = &response_diff.reference {
+                result.push_str(&format!(
+                    "        - Response reference changed from `{}` to `{}`.\n",
+                    reference_diff.from.as_deref().unwrap_or("inline"),
+                    reference_diff.to.as_deref().unwrap_or("inline")
+                ));
+            }
+
             if let Some(content_diff) = &response_diff.content {
                 for media_type_pair in &content_diff.added {
                     result.push_str(&format!(
@@ -50,15 +59,22 @@ impl<'a> ResponsesPrinter<'a> {
                         media_type
                     ));
 
-                    if let Some(schema_diff) = &media_type_diff.schema_changed {
-                        let schema = SchemaPrinter {
-                            diff: &schema_diff,
-                            indent: 10,
-                        }
-                        .print();
-                        result.push_str(&schema);
+                    let media_type = MediaTypePrinter {
+                        diff: media_type_diff,
+                        indent: 10,
                     }
+                    .print();
+                    result.push_str(&media_type);
+                }
+            }
+
+            if let Some(extensions_diff) = &response_diff.extensions {
+                let extensions = ExtensionsPrinter {
+                    extensions: extensions_diff,
+                    indent: 8,
                 }
+                .print();
+                result.push_str(&extensions);
             }
         }
 
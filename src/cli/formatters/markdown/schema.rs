@@ -1,4 +1,7 @@
-use crate::diff::schema::SchemaDiff;
+use super::extensions::ExtensionsPrinter;
+use crate::diff::common::OptionalValueDiff;
+use crate::diff::compatibility::Compatibility;
+use crate::diff::schema::{DataTypeDiff, SchemaDiff};
 
 pub struct SchemaPrinter<'a> {
     pub diff: &'a SchemaDiff,
@@ -9,16 +12,68 @@ impl<'a> SchemaPrinter<'a> {
     pub fn print(&self) -> String {
         let mut result = String::new();
 
+        if let Some(reference_change) = &self.diff.reference_changed {
+            result.push_str(&format!(
+                "{:indent$}- Schema reference changed from `{}` to `{}`.\n",
+                "",
+                reference_change
+                    .from
+                    .clone()
+                    .unwrap_or_else(|| "inline".into()),
+                reference_change
+                    .to
+                    .clone()
+                    .unwrap_or_else(|| "inline".into()),
+                indent = self.indent,
+            ));
+        }
+
         if let Some(type_change) = &self.diff.type_changed {
+            result.push_str(&self.print_data_type_change("type", type_change));
+        }
+
+        if let Some(format_change) = &self.diff.format_changed {
+            result.push_str(&self.print_data_type_change("format", format_change));
+        }
+
+        if let Some(title_change) = &self.diff.title_changed {
             result.push_str(&format!(
-                "{:indent$}- Schema type changed from `{}` to `{}`.\n",
+                "{:indent$}- Schema title changed from `{}` to `{}`.\n",
                 "",
-                type_change.from.clone().unwrap_or("null".into()),
-                type_change.to.clone().unwrap_or("null".into()),
+                title_change.from.clone().unwrap_or_else(|| "null".into()),
+                title_change.to.clone().unwrap_or_else(|| "null".into()),
                 indent = self.indent,
             ));
         }
 
+        if let Some(deprecated_change) = &self.diff.deprecated_changed {
+            if deprecated_change.to {
+                result.push_str(&format!(
+                    "{:indent$}- Schema was deprecated.\n",
+                    "",
+                    indent = self.indent,
+                ));
+            } else {
+                result.push_str(&format!(
+                    "{:indent$}- Schema is no longer deprecated.\n",
+                    "",
+                    indent = self.indent,
+                ));
+            }
+        }
+
+        if let Some(default_change) = &self.diff.default_changed {
+            result.push_str(&self.print_value_change("default value", default_change));
+        }
+
+        if let Some(example_change) = &self.diff.example_changed {
+            result.push_str(&self.print_value_change("example", example_change));
+        }
+
+        if let Some(examples_change) = &self.diff.examples_changed {
+            result.push_str(&self.print_value_change("examples", examples_change));
+        }
+
         if self.diff.description_changed.is_some() {
             result.push_str(&format!(
                 "{:indent$}- Schema description changed.\n",
@@ -27,6 +82,38 @@ impl<'a> SchemaPrinter<'a> {
             ));
         }
 
+        if let Some(read_only_diff) = &self.diff.read_only_changed {
+            if read_only_diff.to {
+                result.push_str(&format!(
+                    "{:indent$}- Schema is now read only.\n",
+                    "",
+                    indent = self.indent,
+                ));
+            } else {
+                result.push_str(&format!(
+                    "{:indent$}- Schema is no longer read only.\n",
+                    "",
+                    indent = self.indent,
+                ));
+            }
+        }
+
+        if let Some(write_only_diff) = &self.diff.write_only_changed {
+            if write_only_diff.to {
+                result.push_str(&format!(
+                    "{:indent$}- Schema is now write only.\n",
+                    "",
+                    indent = self.indent,
+                ));
+            } else {
+                result.push_str(&format!(
+                    "{:indent$}- Schema is no longer write only.\n",
+                    "",
+                    indent = self.indent,
+                ));
+            }
+        }
+
         if let Some(properties_diff) = &self.diff.properties_changed {
             for p in &properties_diff.added {
                 result.push_str(&format!(
@@ -79,6 +166,185 @@ impl<'a> SchemaPrinter<'a> {
             result.push_str(&schema_diff);
         }
 
+        if let Some(prefix_items_diff) = &self.diff.prefix_items_changed {
+            for (index, _) in &prefix_items_diff.added {
+                result.push_str(&format!(
+                    "{:indent$}- Prefix item {} was added.\n",
+                    "",
+                    index,
+                    indent = self.indent
+                ));
+            }
+
+            for (index, _) in &prefix_items_diff.removed {
+                result.push_str(&format!(
+                    "{:indent$}- Prefix item {} was removed.\n",
+                    "",
+                    index,
+                    indent = self.indent
+                ));
+            }
+
+            for (index, diff) in &prefix_items_diff.changed {
+                result.push_str(&format!(
+                    "{:indent$}- Prefix item {} changed:\n",
+                    "",
+                    index,
+                    indent = self.indent
+                ));
+
+                let schema_diff = SchemaPrinter {
+                    diff,
+                    indent: self.indent + 2,
+                }
+                .print();
+                result.push_str(&schema_diff);
+            }
+        }
+
+        if let Some(defs_diff) = &self.diff.defs_changed {
+            for (name, _) in &defs_diff.added {
+                result.push_str(&format!(
+                    "{:indent$}- Definition `{}` was added.\n",
+                    "",
+                    name,
+                    indent = self.indent
+                ));
+            }
+
+            for (name, _) in &defs_diff.removed {
+                result.push_str(&format!(
+                    "{:indent$}- Definition `{}` was removed.\n",
+                    "",
+                    name,
+                    indent = self.indent
+                ));
+            }
+
+            for (name, diff) in &defs_diff.changed {
+                result.push_str(&format!(
+                    "{:indent$}- Definition `{}` changed:\n",
+                    "",
+                    name,
+                    indent = self.indent
+                ));
+
+                let schema_diff = SchemaPrinter {
+                    diff,
+                    indent: self.indent + 2,
+                }
+                .print();
+                result.push_str(&schema_diff);
+            }
+        }
+
+        if let Some(additional_properties_diff) = &self.diff.additional_properties_changed {
+            if let Some(allowed_diff) = &additional_properties_diff.allowed_changed {
+                if allowed_diff.to {
+                    result.push_str(&format!(
+                        "{:indent$}- Additional properties are now allowed.\n",
+                        "",
+                        indent = self.indent
+                    ));
+                } else {
+                    result.push_str(&format!(
+                        "{:indent$}- Additional properties are no longer allowed.\n",
+                        "",
+                        indent = self.indent
+                    ));
+                }
+            }
+
+            if additional_properties_diff.schema_added.is_some() {
+                result.push_str(&format!(
+                    "{:indent$}- Additional properties schema was added.\n",
+                    "",
+                    indent = self.indent
+                ));
+            }
+
+            if additional_properties_diff.schema_removed.is_some() {
+                result.push_str(&format!(
+                    "{:indent$}- Additional properties schema was removed.\n",
+                    "",
+                    indent = self.indent
+                ));
+            }
+
+            if let Some(schema_diff) = &additional_properties_diff.schema_changed {
+                result.push_str(&format!(
+                    "{:indent$}- Additional properties schema changed:\n",
+                    "",
+                    indent = self.indent
+                ));
+
+                let schema_diff = SchemaPrinter {
+                    diff: schema_diff,
+                    indent: self.indent + 2,
+                }
+                .print();
+
+                result.push_str(&schema_diff);
+            }
+        }
+
+        if let Some(extensions_diff) = &self.diff.extensions_changed {
+            let extensions = ExtensionsPrinter {
+                extensions: extensions_diff,
+                indent: self.indent,
+            }
+            .print();
+            result.push_str(&extensions);
+        }
+
         result
     }
+
+    fn print_data_type_change(&self, name: &str, diff: &DataTypeDiff) -> String {
+        let compatibility = match diff.compatibility {
+            Compatibility::Equivalent => "equivalent",
+            Compatibility::Widened => "widened",
+            Compatibility::Narrowed => "narrowed",
+            Compatibility::Incompatible => "incompatible",
+        };
+
+        format!(
+            "{:indent$}- Schema {} changed from `{}` to `{}` ({}{}).\n",
+            "",
+            name,
+            diff.from.clone().unwrap_or_else(|| "null".into()),
+            diff.to.clone().unwrap_or_else(|| "null".into()),
+            compatibility,
+            if diff.breaking { ", breaking" } else { "" },
+            indent = self.indent,
+        )
+    }
+
+    fn print_value_change(&self, name: &str, diff: &OptionalValueDiff) -> String {
+        match (&diff.from, &diff.to) {
+            (Some(from), Some(to)) => format!(
+                "{:indent$}- Schema {} changed from `{}` to `{}`.\n",
+                "",
+                name,
+                from,
+                to,
+                indent = self.indent,
+            ),
+            (None, Some(to)) => format!(
+                "{:indent$}- Schema {} `{}` was added.\n",
+                "",
+                name,
+                to,
+                indent = self.indent,
+            ),
+            (Some(from), None) => format!(
+                "{:indent$}- Schema {} `{}` was removed.\n",
+                "",
+                name,
+                from,
+                indent = self.indent,
+            ),
+            (None, None) => String::new(),
+        }
+    }
 }
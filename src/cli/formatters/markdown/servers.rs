@@ -0,0 +1,133 @@
+use super::extensions::ExtensionsPrinter;
+use crate::diff::servers::{ServerDiff, ServersDiff};
+
+pub struct ServersPrinter<'a> {
+    pub diff: &'a ServersDiff,
+    pub indent: usize,
+}
+
+impl<'a> ServersPrinter<'a> {
+    pub fn print(&self) -> String {
+        let mut result = String::new();
+
+        for server in &self.diff.added {
+            result
+                .push_str(&self.line(self.indent, &format!("Server `{}` was added.", server.url)));
+        }
+
+        for server in &self.diff.removed {
+            result.push_str(&self.line(
+                self.indent,
+                &format!("Server `{}` was removed.", server.url),
+            ));
+        }
+
+        let mut changed: Vec<_> = self.diff.changed.iter().collect();
+        changed.sort_by_key(|(url, _)| *url);
+
+        for (url, server_diff) in changed {
+            result.push_str(&self.line(self.indent, &format!("Server `{}` changed:", url)));
+            result.push_str(&self.print_server(server_diff));
+        }
+
+        result
+    }
+
+    fn print_server(&self, diff: &ServerDiff) -> String {
+        let mut result = String::new();
+        let indent = self.indent + 2;
+
+        if let Some(url_diff) = &diff.url {
+            result.push_str(&self.line(
+                indent,
+                &format!("URL changed from `{}` to `{}`.", url_diff.from, url_diff.to),
+            ));
+        }
+
+        if let Some(urls_diff) = &diff.urls {
+            for url in &urls_diff.added {
+                result.push_str(&self.line(indent, &format!("`{}` is now reachable.", url)));
+            }
+
+            for url in &urls_diff.removed {
+                result.push_str(&self.line(indent, &format!("`{}` is no longer reachable.", url)));
+            }
+        }
+
+        if diff.description.is_some() {
+            result.push_str(&self.line(indent, "Description changed."));
+        }
+
+        if let Some(variables_diff) = &diff.variables {
+            for (name, _) in &variables_diff.added {
+                result.push_str(&self.line(indent, &format!("Variable `{}` was added.", name)));
+            }
+
+            for (name, _) in &variables_diff.removed {
+                result.push_str(&self.line(indent, &format!("Variable `{}` was removed.", name)));
+            }
+
+            let mut changed: Vec<_> = variables_diff.changed.iter().collect();
+            changed.sort_by_key(|(name, _)| *name);
+
+            for (name, variable_diff) in changed {
+                if let Some(enumeration_diff) = &variable_diff.enumeration {
+                    for value in &enumeration_diff.added {
+                        result.push_str(&self.line(
+                            indent,
+                            &format!("Value `{}` was added to variable `{}`.", value, name),
+                        ));
+                    }
+
+                    for value in &enumeration_diff.removed {
+                        result.push_str(&self.line(
+                            indent,
+                            &format!("Value `{}` was removed from variable `{}`.", value, name),
+                        ));
+                    }
+                }
+
+                if let Some(default_diff) = &variable_diff.default {
+                    result.push_str(&self.line(
+                        indent,
+                        &format!(
+                            "Default of variable `{}` changed from `{}` to `{}`.",
+                            name, default_diff.from, default_diff.to
+                        ),
+                    ));
+                }
+
+                if variable_diff.description.is_some() {
+                    result.push_str(&self.line(
+                        indent,
+                        &format!("Description of variable `{}` changed.", name),
+                    ));
+                }
+
+                if let Some(extensions_diff) = &variable_diff.extensions {
+                    let extensions = ExtensionsPrinter {
+                        extensions: extensions_diff,
+                        indent,
+                    }
+                    .print();
+                    result.push_str(&extensions);
+                }
+            }
+        }
+
+        if let Some(extensions_diff) = &diff.extensions {
+            let extensions = ExtensionsPrinter {
+                extensions: extensions_diff,
+                indent,
+            }
+            .print();
+            result.push_str(&extensions);
+        }
+
+        result
+    }
+
+    fn line(&self, indent: usize, text: &str) -> String {
+        format!("{:indent$}- {}\n", "", text, indent = indent)
+    }
+}
@@ -1 +1,102 @@
+pub mod changelog;
+pub mod changes;
+pub mod html;
+pub mod junit;
 pub mod markdown;
+pub mod sarif;
+
+use crate::diff::common::unescape;
+use std::path::Path;
+
+/// A document as it was read from disk, for formatters that point into it.
+pub struct Source<'a> {
+    pub path: &'a Path,
+    pub contents: &'a str,
+}
+
+impl<'a> Source<'a> {
+    /// The path as a URI reference, relative if the path is relative.
+    pub fn uri(&self) -> String {
+        self.path.to_string_lossy().replace('\\', "/")
+    }
+
+    /// Finds the line of the value at a JSON Pointer, in JSON or YAML.
+    /// This is a heuristic that follows the indentation of object keys, and
+    /// skips array indices. It returns the line of the deepest key found, or
+    /// 1.
+    pub fn line(&self, pointer: &str) -> usize {
+        let lines: Vec<&str> = self.contents.lines().collect();
+        let mut found: Option<(usize, usize)> = None;
+
+        for token in pointer.split('/').skip(1) {
+            let token = unescape(token);
+            let keys = [
+                format!("{}:", token),
+                format!("\"{}\":", token),
+                format!("'{}':", token),
+            ];
+            let start = found.map_or(0, |(line, _)| line + 1);
+            let mut next = None;
+
+            for (index, line) in lines.iter().enumerate().skip(start) {
+                let key = line.trim_start().trim_start_matches(['-', ' ']);
+                if key.is_empty() {
+                    continue;
+                }
+
+                let indent = line.len() - key.len();
+                if let Some((_, parent_indent)) = found {
+                    if indent <= parent_indent {
+                        break;
+                    }
+                }
+
+                if keys.iter().any(|prefix| key.starts_with(prefix.as_str())) {
+                    next = Some((index, indent));
+                    break;
+                }
+            }
+
+            match next {
+                Some(next) => found = Some(next),
+                // Numeric tokens that are not keys are array indices.
+                None if token.parse::<usize>().is_ok() => continue,
+                None => break,
+            }
+        }
+
+        found.map_or(1, |(line, _)| line + 1)
+    }
+}
+
+/// Escapes text for XML and HTML content and attribute values.
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_of_pointer() {
+        let yaml = Source {
+            path: Path::new("pets.yaml"),
+            contents: "openapi: 3.0.0\npaths:\n  /pets:\n    get:\n      parameters:\n      - name: limit\n        in: query\n        schema:\n          type: integer\n      responses:\n        '200':\n          description: Pets\n",
+        };
+        let json = Source {
+            path: Path::new("pets.json"),
+            contents: "{\n  \"paths\": {\n    \"/pets\": {\n      \"get\": {\n        \"responses\": {\n          \"200\": {}\n        }\n      }\n    }\n  }\n}\n",
+        };
+
+        assert_eq!(8, yaml.line("/paths/~1pets/get/parameters/0/schema"));
+        assert_eq!(11, yaml.line("/paths/~1pets/get/responses/200"));
+        assert_eq!(4, yaml.line("/paths/~1pets/get/requestBody"));
+        assert_eq!(6, json.line("/paths/~1pets/get/responses/200"));
+        assert_eq!(1, json.line("/webhooks"));
+    }
+}
@@ -0,0 +1,270 @@
+//! Prints changes as a SARIF 2.1.0 log, for code scanning dashboards and
+//! SARIF viewers.
+
+use super::changes::{Change, Severity, Side, RULES};
+use super::Source;
+use serde::Serialize;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Serialize)]
+struct Log {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: &'static str,
+    name: String,
+    short_description: Message,
+    default_configuration: Configuration,
+}
+
+#[derive(Serialize)]
+struct Configuration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: &'static str,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: PhysicalLocation,
+    logical_locations: Vec<LogicalLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LogicalLocation {
+    /// The JSON Pointer of the changed value.
+    fully_qualified_name: String,
+    kind: &'static str,
+}
+
+pub struct SarifPrinter<'a> {
+    pub changes: &'a [Change],
+    pub base: &'a Source<'a>,
+    pub head: &'a Source<'a>,
+}
+
+impl<'a> SarifPrinter<'a> {
+    pub fn print(&self) -> String {
+        let rules = RULES
+            .iter()
+            .map(|rule| ReportingDescriptor {
+                id: rule.id,
+                name: pascal_case(rule.id),
+                short_description: Message {
+                    text: rule.description.to_string(),
+                },
+                default_configuration: Configuration {
+                    level: level(rule.severity),
+                },
+            })
+            .collect();
+
+        let results = self
+            .changes
+            .iter()
+            .map(|change| self.result(change))
+            .collect();
+
+        let log = Log {
+            schema: SCHEMA,
+            version: "2.1.0",
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "anicca",
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        };
+
+        serde_json::to_string_pretty(&log).expect("Could not serialize diff to SARIF")
+    }
+
+    fn result(&self, change: &Change) -> SarifResult {
+        let source = match change.location.side {
+            Side::Base => self.base,
+            Side::Head => self.head,
+        };
+        let pointer = &change.location.pointer;
+
+        SarifResult {
+            rule_id: change.rule.id,
+            rule_index: RULES
+                .iter()
+                .position(|rule| rule.id == change.rule.id)
+                .unwrap_or_default(),
+            level: level(change.severity),
+            message: Message {
+                text: change.full_message(),
+            },
+            locations: vec![SarifLocation {
+                physical_location: PhysicalLocation {
+                    artifact_location: ArtifactLocation { uri: source.uri() },
+                    region: Region {
+                        start_line: source.line(pointer),
+                    },
+                },
+                logical_locations: vec![LogicalLocation {
+                    fully_qualified_name: if pointer.is_empty() {
+                        "/".to_string()
+                    } else {
+                        pointer.clone()
+                    },
+                    kind: "member",
+                }],
+            }],
+        }
+    }
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Breaking => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// `operation-removed` becomes `OperationRemoved`.
+fn pascal_case(id: &str) -> String {
+    id.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::{diff, parse};
+    use std::path::Path;
+
+    #[test]
+    fn removed_operation_points_into_base() {
+        let base = Source {
+            path: Path::new("base.yaml"),
+            contents: "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets:\n    get:\n      responses: {}\n    post:\n      responses: {}\n",
+        };
+        let head = Source {
+            path: Path::new("head.yaml"),
+            contents: "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets:\n    get:\n      responses: {}\n",
+        };
+        let base_document = parse(base.contents).expect("Failed to parse base");
+        let head_document = parse(head.contents).expect("Failed to parse head");
+        let diff = diff(base_document.clone(), head_document.clone()).expect("Failed to diff");
+        let changes = Change::from_diff(&diff, &base_document, &head_document);
+
+        let sarif: serde_json::Value = serde_json::from_str(
+            &SarifPrinter {
+                changes: &changes,
+                base: &base,
+                head: &head,
+            }
+            .print(),
+        )
+        .expect("Invalid JSON");
+        let run = &sarif["runs"][0];
+        let result = run["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|result| result["ruleId"] == "operation-removed")
+            .expect("Missing result");
+        let rule_index = result["ruleIndex"].as_u64().unwrap() as usize;
+
+        assert_eq!("2.1.0", sarif["version"]);
+        assert_eq!(
+            "operation-removed",
+            run["tool"]["driver"]["rules"][rule_index]["id"]
+        );
+        assert_eq!(
+            "OperationRemoved",
+            run["tool"]["driver"]["rules"][rule_index]["name"]
+        );
+        assert_eq!("error", result["level"]);
+        assert_eq!(
+            "`POST /pets`: Operation was removed.",
+            result["message"]["text"]
+        );
+
+        let location = &result["locations"][0];
+        assert_eq!(
+            "base.yaml",
+            location["physicalLocation"]["artifactLocation"]["uri"]
+        );
+        assert_eq!(9, location["physicalLocation"]["region"]["startLine"]);
+        assert_eq!(
+            "/paths/~1pets/post",
+            location["logicalLocations"][0]["fullyQualifiedName"]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use super::common::{Direction, OptionalStringDiff};
+use super::path_items::PathItemDiff;
+use super::resolver::Resolver;
+use crate::openapi::{Callback, PathItem, ReferenceOr};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+pub type CallbackPair = (String, ReferenceOr<Callback>);
+
+pub type ExpressionPair = (String, PathItem);
+
+/// Diffs the callbacks of an operation, matching them by name.
+#[derive(Debug, Serialize)]
+pub struct CallbacksDiff {
+    pub added: Vec<CallbackPair>,
+    pub removed: Vec<CallbackPair>,
+    pub changed: HashMap<String, CallbackDiff>,
+}
+
+impl CallbacksDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    pub fn from_callbacks(
+        base: &BTreeMap<String, ReferenceOr<Callback>>,
+        head: &BTreeMap<String, ReferenceOr<Callback>>,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut callbacks_added = vec![];
+        let mut callbacks_removed = vec![];
+        let mut callbacks_changed: HashMap<String, CallbackDiff> = HashMap::new();
+
+        for (name, callback) in base {
+            match head.get(name) {
+                Some(head_callback) => {
+                    let callback_diff =
+                        CallbackDiff::from_callbacks(callback, head_callback, resolver);
+
+                    if callback_diff.has_changes() {
+                        callbacks_changed.insert(name.clone(), callback_diff);
+                    }
+                }
+                None => callbacks_removed.push((name.clone(), callback.clone())),
+            }
+        }
+
+        for (name, callback) in head {
+            match base.get(name) {
+                Some(_) => {}
+                None => callbacks_added.push((name.clone(), callback.clone())),
+            }
+        }
+
+        Self {
+            added: callbacks_added,
+            removed: callbacks_removed,
+            changed: callbacks_changed,
+        }
+    }
+}
+
+/// Diffs the path items of a callback, matching them by their runtime
+/// expression, such as `{$request.body#/callbackUrl}`.
+#[derive(Debug, Serialize, Default)]
+pub struct CallbackDiff {
+    /// Set when either callback is a `$ref` that cannot be resolved, and
+    /// the references differ.
+    pub reference: Option<OptionalStringDiff>,
+    pub expressions_added: Vec<ExpressionPair>,
+    pub expressions_removed: Vec<ExpressionPair>,
+    pub expressions_changed: HashMap<String, PathItemDiff>,
+}
+
+impl CallbackDiff {
+    pub fn has_changes(&self) -> bool {
+        self.reference.is_some()
+            || !self.expressions_added.is_empty()
+            || !self.expressions_removed.is_empty()
+            || !self.expressions_changed.is_empty()
+    }
+
+    /// The API provider sends callback requests and receives their
+    /// responses, so path items are diffed with directions swapped.
+    pub fn from_callbacks(
+        base: &ReferenceOr<Callback>,
+        head: &ReferenceOr<Callback>,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut diff = Self::default();
+
+        let (base_callback, head_callback) =
+            match (resolver.base_callback(base), resolver.head_callback(head)) {
+                (Some(base_callback), Some(head_callback)) => (base_callback, head_callback),
+                _ => {
+                    diff.reference = OptionalStringDiff::from_strings(
+                        &Self::reference(base),
+                        &Self::reference(head),
+                    );
+                    return diff;
+                }
+            };
+
+        for (expression, path_item) in &base_callback {
+            match head_callback.get(expression) {
+                Some(head_path_item) => {
+                    let path_item_diff = PathItemDiff::from_items(
+                        path_item,
+                        head_path_item,
+                        Direction::Response,
+                        resolver,
+                    );
+
+                    if path_item_diff.has_change() {
+                        diff.expressions_changed
+                            .insert(expression.clone(), path_item_diff);
+                    }
+                }
+                None => diff
+                    .expressions_removed
+                    .push((expression.clone(), path_item.clone())),
+            }
+        }
+
+        for (expression, path_item) in &head_callback {
+            if !base_callback.contains_key(expression) {
+                diff.expressions_added
+                    .push((expression.clone(), path_item.clone()));
+            }
+        }
+
+        diff
+    }
+
+    fn reference(callback: &ReferenceOr<Callback>) -> Option<String> {
+        match callback {
+            ReferenceOr::Reference { reference } => Some(reference.clone()),
+            ReferenceOr::Item(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openapi::Components;
+
+    fn callbacks(yaml: &str) -> BTreeMap<String, ReferenceOr<Callback>> {
+        serde_yaml::from_str(yaml).expect("Failed to parse callbacks")
+    }
+
+    #[test]
+    fn expression_added() {
+        let base = callbacks("onEvent:\n  '{$request.body#/url}': {}\n");
+        let head = callbacks(
+            "onEvent:\n  '{$request.body#/url}': {}\n  '{$request.body#/backupUrl}': {}\n",
+        );
+
+        let diff = CallbacksDiff::from_callbacks(&base, &head, &Resolver::default());
+        let callback_diff = diff.changed.get("onEvent").unwrap();
+
+        assert_eq!(
+            "{$request.body#/backupUrl}",
+            callback_diff.expressions_added[0].0
+        );
+    }
+
+    #[test]
+    fn directions_are_swapped() {
+        // Widening the payload sent to subscribers breaks them, even though
+        // it would be fine in a request to the API.
+        let base = callbacks(
+            "onEvent:\n  '{$request.body#/url}':\n    post:\n      requestBody:\n        content:\n          application/json:\n            schema:\n              type: integer\n              format: int32\n      responses: {}\n",
+        );
+        let head = callbacks(
+            "onEvent:\n  '{$request.body#/url}':\n    post:\n      requestBody:\n        content:\n          application/json:\n            schema:\n              type: integer\n              format: int64\n      responses: {}\n",
+        );
+
+        let diff = CallbacksDiff::from_callbacks(&base, &head, &Resolver::default());
+        let operation_diff = &diff.changed["onEvent"].expressions_changed["{$request.body#/url}"]
+            .operations_changed["post"];
+        let content_diff = operation_diff
+            .request_body
+            .as_ref()
+            .unwrap()
+            .content_changed
+            .as_ref()
+            .unwrap();
+        let format_diff = content_diff.changed["application/json"]
+            .schema_changed
+            .as_ref()
+            .unwrap()
+            .format_changed
+            .as_ref()
+            .unwrap();
+
+        assert!(format_diff.breaking);
+    }
+
+    #[test]
+    fn resolves_references() {
+        let components: Components =
+            serde_yaml::from_str("callbacks:\n  Event:\n    '{$request.body#/url}': {}\n")
+                .expect("Failed to parse components");
+        let base = callbacks("onEvent:\n  $ref: '#/components/callbacks/Event'\n");
+        let head = callbacks("onEvent:\n  '{$request.body#/url}': {}\n");
+
+        let diff =
+            CallbacksDiff::from_callbacks(&base, &head, &Resolver::new(Some(&components), None));
+
+        assert!(!diff.has_changes());
+    }
+}
@@ -1,6 +1,26 @@
 use serde::Serialize;
 use std::collections::HashSet;
 
+/// Whether a diffed object is sent by the client (request) or by the
+/// server (response). Some schema keywords, such as `readOnly` and
+/// `writeOnly`, only apply in one direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Request,
+    Response,
+}
+
+impl Direction {
+    /// In callbacks the API provider sends the requests and receives the
+    /// responses, so each part of an operation changes direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Direction::Request => Direction::Response,
+            Direction::Response => Direction::Request,
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct StringListDiff {
     pub added: Vec<String>,
@@ -8,7 +28,7 @@ pub struct StringListDiff {
 }
 
 impl StringListDiff {
-    pub fn from_lists(base: &Vec<String>, head: &Vec<String>) -> Self {
+    pub fn from_lists(base: &[String], head: &[String]) -> Self {
         let base_set: HashSet<_> = base.iter().collect();
         let added: Vec<_> = head
             .iter()
@@ -50,6 +70,30 @@ impl OptionalStringDiff {
     }
 }
 
+/// A change to an optional JSON value, such as a schema `default` or
+/// `example`. Values are kept as JSON so they serialize structurally.
+#[derive(Debug, Serialize)]
+pub struct OptionalValueDiff {
+    pub from: Option<serde_json::Value>,
+    pub to: Option<serde_json::Value>,
+}
+
+impl OptionalValueDiff {
+    pub fn from_values(
+        base: &Option<serde_json::Value>,
+        head: &Option<serde_json::Value>,
+    ) -> Option<Self> {
+        if base != head {
+            Some(Self {
+                from: base.clone(),
+                to: head.clone(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct StringDiff {
     pub from: String,
@@ -87,3 +131,26 @@ impl BooleanDiff {
         }
     }
 }
+
+/// Escapes a JSON Pointer reference token.
+pub fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Unescapes a JSON Pointer reference token.
+pub fn unescape(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pointer_tokens() {
+        assert_eq!("~1pets~1{id}", escape("/pets/{id}"));
+        assert_eq!("a~01", escape("a~1"));
+        assert_eq!("a~1", unescape("a~01"));
+        assert_eq!("/pets/{id}", unescape(&escape("/pets/{id}")));
+    }
+}
@@ -0,0 +1,217 @@
+use super::common::Direction;
+use serde::Serialize;
+
+/// How the set of values accepted by a schema changed between base and head.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Compatibility {
+    /// Both sides accept the same values, e.g. adding `format: password`.
+    Equivalent,
+    /// Head accepts every base value and more, e.g. `integer` to `number`.
+    Widened,
+    /// Base accepts every head value and more, e.g. adding `format: email`.
+    Narrowed,
+    /// Neither side accepts all of the other's values, e.g. `date` to `date-time`.
+    Incompatible,
+}
+
+impl Compatibility {
+    /// Whether this change breaks existing clients. Narrowing a request
+    /// rejects payloads clients already send, while widening a response
+    /// sends values clients may not be able to handle.
+    pub fn is_breaking(&self, direction: Direction) -> bool {
+        match self {
+            Compatibility::Equivalent => false,
+            Compatibility::Incompatible => true,
+            Compatibility::Widened => direction == Direction::Response,
+            Compatibility::Narrowed => direction == Direction::Request,
+        }
+    }
+
+    /// Compares schemas that may list several types, as OpenAPI 3.1 allows.
+    /// A value is accepted when it matches any of the types, and a schema
+    /// without types accepts any value.
+    pub fn from_type_lists(
+        base_types: &[&str],
+        base_format: Option<&str>,
+        head_types: &[&str],
+        head_format: Option<&str>,
+    ) -> Self {
+        let base = DataType::list(base_types, base_format);
+        let head = DataType::list(head_types, head_format);
+
+        match (
+            DataType::contains_all(&base, &head),
+            DataType::contains_all(&head, &base),
+        ) {
+            (true, true) => Compatibility::Equivalent,
+            (true, false) => Compatibility::Narrowed,
+            (false, true) => Compatibility::Widened,
+            (false, false) => Compatibility::Incompatible,
+        }
+    }
+}
+
+/// The set of values described by a schema's `type` and `format`, as defined
+/// by the OpenAPI data types.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/master/versions/3.0.3.md#data-types>.
+#[derive(Debug, PartialEq)]
+enum DataType {
+    /// No `type`: any value is accepted.
+    Any,
+    Integer(IntegerFormat),
+    Number(NumberFormat),
+    /// A string, optionally restricted by a format such as `date` or `uuid`.
+    String(Option<String>),
+    /// `boolean`, `object`, `array`, or a non standard type.
+    Other(String),
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+enum IntegerFormat {
+    Int32,
+    Int64,
+    Unbounded,
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+enum NumberFormat {
+    Float,
+    Double,
+    Unbounded,
+}
+
+impl DataType {
+    fn new(schema_type: Option<&str>, format: Option<&str>) -> Self {
+        match schema_type {
+            None => DataType::Any,
+            Some("integer") => DataType::Integer(match format {
+                Some("int32") => IntegerFormat::Int32,
+                Some("int64") => IntegerFormat::Int64,
+                _ => IntegerFormat::Unbounded,
+            }),
+            Some("number") => DataType::Number(match format {
+                Some("float") => NumberFormat::Float,
+                Some("double") => NumberFormat::Double,
+                _ => NumberFormat::Unbounded,
+            }),
+            // `password` only hints UIs to obscure the input.
+            Some("string") => DataType::String(match format {
+                None | Some("password") => None,
+                Some(format) => Some(format.to_string()),
+            }),
+            Some(other) => DataType::Other(other.to_string()),
+        }
+    }
+
+    fn list(schema_types: &[&str], format: Option<&str>) -> Vec<Self> {
+        if schema_types.is_empty() {
+            return vec![DataType::Any];
+        }
+
+        schema_types
+            .iter()
+            .map(|schema_type| DataType::new(Some(schema_type), format))
+            .collect()
+    }
+
+    /// Whether every value of a type in `other` is a value of a type in `list`.
+    fn contains_all(list: &[DataType], other: &[DataType]) -> bool {
+        other
+            .iter()
+            .all(|other| list.iter().any(|data_type| data_type.contains(other)))
+    }
+
+    /// Whether every value of `other` is also a value of `self`.
+    fn contains(&self, other: &DataType) -> bool {
+        match (self, other) {
+            (DataType::Any, _) => true,
+            (DataType::Integer(a), DataType::Integer(b)) => a >= b,
+            (DataType::Number(NumberFormat::Unbounded), DataType::Integer(_)) => true,
+            // Every int32 is exactly representable as a double.
+            (DataType::Number(NumberFormat::Double), DataType::Integer(IntegerFormat::Int32)) => {
+                true
+            }
+            (DataType::Number(a), DataType::Number(b)) => a >= b,
+            (DataType::String(None), DataType::String(_)) => true,
+            (DataType::String(a), DataType::String(b)) => a == b,
+            (DataType::Other(a), DataType::Other(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_to_number_widens() {
+        let compatibility = Compatibility::from_type_lists(&["integer"], None, &["number"], None);
+
+        assert_eq!(Compatibility::Widened, compatibility);
+        assert!(!compatibility.is_breaking(Direction::Request));
+        assert!(compatibility.is_breaking(Direction::Response));
+    }
+
+    #[test]
+    fn int32_to_int64_widens() {
+        let compatibility = Compatibility::from_type_lists(
+            &["integer"],
+            Some("int32"),
+            &["integer"],
+            Some("int64"),
+        );
+
+        assert_eq!(Compatibility::Widened, compatibility);
+    }
+
+    #[test]
+    fn date_to_date_time_is_incompatible() {
+        let compatibility = Compatibility::from_type_lists(
+            &["string"],
+            Some("date"),
+            &["string"],
+            Some("date-time"),
+        );
+
+        assert_eq!(Compatibility::Incompatible, compatibility);
+        assert!(compatibility.is_breaking(Direction::Request));
+        assert!(compatibility.is_breaking(Direction::Response));
+    }
+
+    #[test]
+    fn adding_string_format_narrows() {
+        let compatibility =
+            Compatibility::from_type_lists(&["string"], None, &["string"], Some("email"));
+
+        assert_eq!(Compatibility::Narrowed, compatibility);
+        assert!(compatibility.is_breaking(Direction::Request));
+        assert!(!compatibility.is_breaking(Direction::Response));
+    }
+
+    #[test]
+    fn password_format_is_equivalent() {
+        let compatibility =
+            Compatibility::from_type_lists(&["string"], None, &["string"], Some("password"));
+
+        assert_eq!(Compatibility::Equivalent, compatibility);
+    }
+
+    #[test]
+    fn adding_a_type_widens() {
+        let compatibility =
+            Compatibility::from_type_lists(&["string"], None, &["string", "integer"], None);
+
+        assert_eq!(Compatibility::Widened, compatibility);
+    }
+
+    #[test]
+    fn unrelated_types_are_incompatible() {
+        assert_eq!(
+            Compatibility::Incompatible,
+            Compatibility::from_type_lists(&["string"], None, &["integer"], None)
+        );
+    }
+}
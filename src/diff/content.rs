@@ -1,7 +1,11 @@
+use super::common::Direction;
+use super::compatibility::Compatibility;
+use super::media_range::{self, MediaRange, MediaRangeDiff};
 use super::media_type::MediaTypeDiff;
+use super::resolver::Resolver;
 use crate::openapi::MediaType;
-use std::collections::BTreeMap;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 pub type MediaTypePair = (String, MediaType);
@@ -21,16 +25,26 @@ impl ContentDiff {
     pub fn from_content(
         base: &BTreeMap<String, MediaType>,
         head: &BTreeMap<String, MediaType>,
+        direction: Direction,
+        resolver: &Resolver,
     ) -> Self {
         let mut media_types_added = vec![];
         let mut media_types_removed = vec![];
         let mut media_types_changed: HashMap<String, MediaTypeDiff> = HashMap::new();
 
+        let matches = Self::match_media_types(base, head);
+
         for (media_type, media_type_definition) in base {
-            match head.get(media_type) {
-                Some(head_media_type) => {
-                    let media_type_diff =
-                        MediaTypeDiff::from_media_types(&media_type_definition, &head_media_type);
+            match matches.get(media_type) {
+                Some(head_key) => {
+                    let mut media_type_diff = MediaTypeDiff::from_media_types(
+                        media_type_definition,
+                        &head[*head_key],
+                        direction,
+                        resolver,
+                    );
+                    media_type_diff.range_changed =
+                        MediaRangeDiff::from_keys(media_type, head_key, direction);
 
                     if media_type_diff.has_changes() {
                         media_types_changed.insert(media_type.clone(), media_type_diff);
@@ -43,9 +57,8 @@ impl ContentDiff {
         }
 
         for (media_type, media_type_definition) in head {
-            match base.get(media_type) {
-                Some(_) => {}
-                None => media_types_added.push((media_type.clone(), media_type_definition.clone())),
+            if !matches.values().any(|head_key| *head_key == media_type) {
+                media_types_added.push((media_type.clone(), media_type_definition.clone()));
             }
         }
 
@@ -55,4 +68,133 @@ impl ContentDiff {
             changed: media_types_changed,
         }
     }
+
+    /// Pairs each base media type with a head media type. Identical and
+    /// equivalent keys are matched first, then what is left is matched
+    /// with a wider or narrower range, such as `application/*`, and last
+    /// JSON with a JSON-structured vendor type, such as
+    /// `application/vnd.github.v3+json`, since both carry the same schema.
+    fn match_media_types<'a>(
+        base: &'a BTreeMap<String, MediaType>,
+        head: &'a BTreeMap<String, MediaType>,
+    ) -> HashMap<&'a String, &'a String> {
+        let mut matches: HashMap<&String, &String> = HashMap::new();
+
+        let passes: [fn(&str, &str) -> bool; 3] = [
+            |base, head| media_range::compatibility(base, head) == Some(Compatibility::Equivalent),
+            |base, head| {
+                matches!(
+                    media_range::compatibility(base, head),
+                    Some(compatibility) if compatibility != Compatibility::Incompatible
+                )
+            },
+            |base, head| match (MediaRange::parse(base), MediaRange::parse(head)) {
+                (Some(base), Some(head)) => {
+                    base.type_ == head.type_ && base.is_json() && head.is_json()
+                }
+                _ => false,
+            },
+        ];
+
+        for is_match in passes.iter() {
+            for base_key in base.keys() {
+                if matches.contains_key(base_key) {
+                    continue;
+                }
+
+                let head_key = head.keys().find(|head_key| {
+                    !matches.values().any(|matched| matched == head_key)
+                        && is_match(base_key, head_key)
+                });
+
+                if let Some(head_key) = head_key {
+                    matches.insert(base_key, head_key);
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content(media_types: &[&str]) -> BTreeMap<String, MediaType> {
+        media_types
+            .iter()
+            .map(|media_type| (media_type.to_string(), MediaType::default()))
+            .collect()
+    }
+
+    #[test]
+    fn equivalent_media_types_match() {
+        let diff = ContentDiff::from_content(
+            &content(&["application/json"]),
+            &content(&["application/json; charset=utf-8"]),
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn exact_match_is_preferred() {
+        let diff = ContentDiff::from_content(
+            &content(&["application/json", "application/xml"]),
+            &content(&["application/*", "application/json"]),
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        let range_diff = diff.changed["application/xml"]
+            .range_changed
+            .as_ref()
+            .unwrap();
+
+        assert_eq!("application/*", range_diff.to);
+        assert_eq!(Compatibility::Widened, range_diff.compatibility);
+        assert!(!range_diff.breaking);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+    }
+
+    #[test]
+    fn narrowed_range() {
+        let diff = ContentDiff::from_content(
+            &content(&["image/*"]),
+            &content(&["image/png"]),
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        let range_diff = diff.changed["image/*"].range_changed.as_ref().unwrap();
+
+        assert_eq!(Compatibility::Narrowed, range_diff.compatibility);
+        assert!(range_diff.breaking);
+    }
+
+    #[test]
+    fn vendor_json_matches_json() {
+        let diff = ContentDiff::from_content(
+            &content(&["application/json", "application/xml"]),
+            &content(&["application/vnd.github.v3+json", "application/xml"]),
+            Direction::Response,
+            &Resolver::default(),
+        );
+
+        let range_diff = diff.changed["application/json"]
+            .range_changed
+            .as_ref()
+            .unwrap();
+
+        assert_eq!("application/vnd.github.v3+json", range_diff.to);
+        assert_eq!(Compatibility::Incompatible, range_diff.compatibility);
+        assert!(range_diff.breaking);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(1, diff.changed.len());
+    }
 }
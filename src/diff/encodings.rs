@@ -0,0 +1,195 @@
+use super::common::{BooleanDiff, Direction, OptionalStringDiff, StringDiff};
+use super::extensions::ExtensionsDiff;
+use super::headers::HeadersDiff;
+use super::parameter::ParameterDiff;
+use super::resolver::Resolver;
+use crate::openapi::{Encoding, QueryStyle};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+pub type EncodingPair = (String, Encoding);
+
+/// Diffs the `encoding` map of a multipart or form media type, matching
+/// encodings by the schema property they apply to.
+#[derive(Debug, Serialize)]
+pub struct EncodingsDiff {
+    pub added: Vec<EncodingPair>,
+    pub removed: Vec<EncodingPair>,
+    pub changed: HashMap<String, EncodingDiff>,
+}
+
+impl EncodingsDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    pub fn from_encodings(
+        base: &BTreeMap<String, Encoding>,
+        head: &BTreeMap<String, Encoding>,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut encodings_added = vec![];
+        let mut encodings_removed = vec![];
+        let mut encodings_changed: HashMap<String, EncodingDiff> = HashMap::new();
+
+        for (property, encoding) in base {
+            match head.get(property) {
+                Some(head_encoding) => {
+                    let encoding_diff =
+                        EncodingDiff::from_encodings(encoding, head_encoding, direction, resolver);
+
+                    if encoding_diff.has_changes() {
+                        encodings_changed.insert(property.clone(), encoding_diff);
+                    }
+                }
+                None => encodings_removed.push((property.clone(), encoding.clone())),
+            }
+        }
+
+        for (property, encoding) in head {
+            match base.get(property) {
+                Some(_) => {}
+                None => encodings_added.push((property.clone(), encoding.clone())),
+            }
+        }
+
+        Self {
+            added: encodings_added,
+            removed: encodings_removed,
+            changed: encodings_changed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct EncodingDiff {
+    pub content_type: Option<OptionalStringDiff>,
+    pub headers: Option<HeadersDiff>,
+    pub style: Option<StringDiff>,
+    pub explode: Option<BooleanDiff>,
+    pub allow_reserved: Option<BooleanDiff>,
+    pub extensions: Option<ExtensionsDiff>,
+}
+
+impl EncodingDiff {
+    pub fn has_changes(&self) -> bool {
+        self.content_type.is_some()
+            || self.headers.is_some()
+            || self.style.is_some()
+            || self.explode.is_some()
+            || self.allow_reserved.is_some()
+            || self.extensions.is_some()
+    }
+
+    /// `style`, `explode` and `allowReserved` follow the same defaults
+    /// as query parameters.
+    pub fn from_encodings(
+        base: &Encoding,
+        head: &Encoding,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut diff = Self {
+            content_type: OptionalStringDiff::from_strings(&base.content_type, &head.content_type),
+            ..Self::default()
+        };
+
+        let headers_diff =
+            HeadersDiff::from_headers(&base.headers, &head.headers, direction, resolver);
+        if headers_diff.has_changes() {
+            diff.headers = Some(headers_diff);
+        }
+
+        diff.style = StringDiff::from_strings(Self::style(base), Self::style(head));
+        diff.explode = BooleanDiff::from_bools(Self::explode(base), Self::explode(head));
+        diff.allow_reserved = BooleanDiff::from_bools(
+            base.allow_reserved.unwrap_or(false),
+            head.allow_reserved.unwrap_or(false),
+        );
+
+        let extensions_diff = ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
+        if extensions_diff.has_changes() {
+            diff.extensions = Some(extensions_diff);
+        }
+
+        diff
+    }
+
+    fn style(encoding: &Encoding) -> String {
+        let style = encoding.style.clone().unwrap_or_default();
+        String::from(ParameterDiff::query_style(&style))
+    }
+
+    fn explode(encoding: &Encoding) -> bool {
+        match encoding.explode {
+            Some(explode) => explode,
+            None => encoding.style.clone().unwrap_or_default() == QueryStyle::Form,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_type_changed() {
+        let base: BTreeMap<String, Encoding> =
+            serde_yaml::from_str("file:\n  contentType: image/png\n")
+                .expect("Failed to parse encoding");
+        let head: BTreeMap<String, Encoding> =
+            serde_yaml::from_str("file:\n  contentType: image/png, image/jpeg\n")
+                .expect("Failed to parse encoding");
+
+        let diff =
+            EncodingsDiff::from_encodings(&base, &head, Direction::Request, &Resolver::default());
+        let encoding_diff = diff.changed.get("file").unwrap();
+
+        assert_eq!(
+            Some(String::from("image/png, image/jpeg")),
+            encoding_diff.content_type.as_ref().unwrap().to
+        );
+    }
+
+    #[test]
+    fn explicit_defaults_are_not_changes() {
+        let base: BTreeMap<String, Encoding> =
+            serde_yaml::from_str("tags:\n  contentType: text/plain\n")
+                .expect("Failed to parse encoding");
+        let head: BTreeMap<String, Encoding> = serde_yaml::from_str(
+            "tags:\n  contentType: text/plain\n  style: form\n  explode: true\n",
+        )
+        .expect("Failed to parse encoding");
+
+        let diff =
+            EncodingsDiff::from_encodings(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn header_names_are_case_insensitive() {
+        let base: BTreeMap<String, Encoding> = serde_yaml::from_str(
+            "file:\n  headers:\n    X-Rate-Limit:\n      schema:\n        type: integer\n",
+        )
+        .expect("Failed to parse encoding");
+        let head: BTreeMap<String, Encoding> = serde_yaml::from_str(
+            "file:\n  headers:\n    x-rate-limit:\n      required: true\n      schema:\n        type: integer\n",
+        )
+        .expect("Failed to parse encoding");
+
+        let diff =
+            EncodingsDiff::from_encodings(&base, &head, Direction::Request, &Resolver::default());
+        let headers_diff = diff.changed.get("file").unwrap().headers.as_ref().unwrap();
+
+        assert!(headers_diff.added.is_empty());
+        assert!(headers_diff.removed.is_empty());
+        assert!(headers_diff
+            .changed
+            .get("X-Rate-Limit")
+            .unwrap()
+            .required
+            .is_some());
+    }
+}
@@ -0,0 +1,213 @@
+use super::paths::PathsDiff;
+use super::resolver::Resolver;
+use crate::openapi::{OpenAPI, Operation, PathItem, Server};
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// A concrete endpoint: an HTTP method and the full URL template of a
+/// path on one of the servers that serve it.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Endpoint {
+    pub method: String,
+    pub url: String,
+    /// The path the URL was built from.
+    pub path: String,
+}
+
+/// The endpoints that exist only in the head or only in the base document,
+/// after server overrides are applied.
+#[derive(Debug, Serialize)]
+pub struct EndpointsDiff {
+    pub added: Vec<Endpoint>,
+    pub removed: Vec<Endpoint>,
+}
+
+impl EndpointsDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty()
+    }
+
+    /// Endpoints of the operations that `paths` reports as added or
+    /// removed, and of operations in both documents whose servers changed.
+    pub fn from_paths(
+        paths: &PathsDiff,
+        base: &OpenAPI,
+        head: &OpenAPI,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut added = BTreeSet::new();
+        let mut removed = BTreeSet::new();
+
+        for (path, path_item) in &paths.added {
+            if let Some(path_item) = resolver.head_path_item(path_item) {
+                for (method, operation) in path_item.operations() {
+                    added.extend(endpoints(head, path, path_item, method, operation));
+                }
+            }
+        }
+
+        for (path, path_item) in &paths.removed {
+            if let Some(path_item) = resolver.base_path_item(path_item) {
+                for (method, operation) in path_item.operations() {
+                    removed.extend(endpoints(base, path, path_item, method, operation));
+                }
+            }
+        }
+
+        for (path, base_path_item) in &base.paths {
+            let path_items = (
+                resolver.base_path_item(base_path_item),
+                head.paths
+                    .get(path)
+                    .and_then(|path_item| resolver.head_path_item(path_item)),
+            );
+            let (base_path_item, head_path_item) = match path_items {
+                (Some(base_path_item), Some(head_path_item)) => (base_path_item, head_path_item),
+                _ => continue,
+            };
+
+            if let Some(path_item_diff) = paths.changed.get(path) {
+                for (method, operation) in &path_item_diff.operations_added {
+                    added.extend(endpoints(head, path, head_path_item, method, operation));
+                }
+
+                for (method, operation) in &path_item_diff.operations_removed {
+                    removed.extend(endpoints(base, path, base_path_item, method, operation));
+                }
+            }
+
+            for (method, base_operation) in base_path_item.operations() {
+                let head_operation = match head_path_item.operation(method) {
+                    Some(head_operation) => head_operation,
+                    None => continue,
+                };
+
+                let base_endpoints = endpoints(base, path, base_path_item, method, base_operation);
+                let head_endpoints = endpoints(head, path, head_path_item, method, head_operation);
+
+                added.extend(head_endpoints.difference(&base_endpoints).cloned());
+                removed.extend(base_endpoints.difference(&head_endpoints).cloned());
+            }
+        }
+
+        Self {
+            added: added.into_iter().collect(),
+            removed: removed.into_iter().collect(),
+        }
+    }
+}
+
+/// Operation servers override path item servers, which override the
+/// document's servers.
+fn endpoints(
+    document: &OpenAPI,
+    path: &str,
+    path_item: &PathItem,
+    method: &str,
+    operation: &Operation,
+) -> BTreeSet<Endpoint> {
+    [&operation.servers, &path_item.servers, &document.servers]
+        .iter()
+        .find(|servers| !servers.is_empty())
+        .map(|servers| server_urls(servers))
+        // Without servers, paths are relative to the document.
+        .unwrap_or_else(|| vec![String::new()])
+        .into_iter()
+        .map(|server_url| Endpoint {
+            method: method.to_uppercase(),
+            url: format!("{}{}", server_url.trim_end_matches('/'), path),
+            path: path.to_string(),
+        })
+        .collect()
+}
+
+fn server_urls(servers: &[Server]) -> Vec<String> {
+    servers.iter().flat_map(Server::urls).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::common::Direction;
+
+    fn document(yaml: &str) -> OpenAPI {
+        serde_yaml::from_str(&format!(
+            "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\n{}",
+            yaml
+        ))
+        .expect("Failed to parse document")
+    }
+
+    #[test]
+    fn server_overrides() {
+        let base = document(
+            "servers:\n  - url: https://{region}.api.example.com/v2\n    variables:\n      region:\n        default: eu\n        enum: [eu, us]\npaths:\n  /pets:\n    get:\n      responses: {}\n    post:\n      servers:\n        - url: https://write.example.com/\n      responses: {}\n",
+        );
+        let head = document(
+            "servers:\n  - url: https://{region}.api.example.com/v2\n    variables:\n      region:\n        default: eu\n        enum: [eu]\npaths:\n  /pets:\n    servers:\n      - url: https://pets.example.com\n    get:\n      responses: {}\n    post:\n      servers:\n        - url: https://write.example.com/\n      responses: {}\n",
+        );
+
+        let resolver = Resolver::default();
+        let paths = PathsDiff::from_paths(&base.paths, &head.paths, Direction::Request, &resolver)
+            .expect("Failed to diff paths");
+        let diff = EndpointsDiff::from_paths(&paths, &base, &head, &resolver);
+
+        assert_eq!(
+            vec![Endpoint {
+                method: "GET".into(),
+                url: "https://pets.example.com/pets".into(),
+                path: "/pets".into(),
+            }],
+            diff.added
+        );
+        assert_eq!(
+            vec![
+                Endpoint {
+                    method: "GET".into(),
+                    url: "https://eu.api.example.com/v2/pets".into(),
+                    path: "/pets".into(),
+                },
+                Endpoint {
+                    method: "GET".into(),
+                    url: "https://us.api.example.com/v2/pets".into(),
+                    path: "/pets".into(),
+                },
+            ],
+            diff.removed
+        );
+    }
+
+    #[test]
+    fn operations_added_and_removed() {
+        let base = document(
+            "servers:\n  - url: https://api.example.com\npaths:\n  /pets:\n    delete:\n      responses: {}\n  /cats:\n    get:\n      responses: {}\n",
+        );
+        let head = document(
+            "servers:\n  - url: https://api.example.com\npaths:\n  /pets:\n    trace:\n      responses: {}\n",
+        );
+
+        let resolver = Resolver::default();
+        let paths = PathsDiff::from_paths(&base.paths, &head.paths, Direction::Request, &resolver)
+            .expect("Failed to diff paths");
+        let diff = EndpointsDiff::from_paths(&paths, &base, &head, &resolver);
+
+        let urls = |endpoints: &[Endpoint]| -> Vec<String> {
+            endpoints
+                .iter()
+                .map(|endpoint| format!("{} {}", endpoint.method, endpoint.url))
+                .collect()
+        };
+
+        assert_eq!(
+            vec!["TRACE https://api.example.com/pets"],
+            urls(&diff.added)
+        );
+        assert_eq!(
+            vec![
+                "DELETE https://api.example.com/pets",
+                "GET https://api.example.com/cats",
+            ],
+            urls(&diff.removed)
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use super::common::{Direction, OptionalStringDiff, OptionalValueDiff};
+use super::extensions::ExtensionsDiff;
+use super::resolver::Resolver;
+use super::validation::{ValidationError, Validator};
+use crate::openapi::{Example, ParameterSchemaOrContent, ReferenceOr, Schema};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+pub type ExamplePair = (String, ReferenceOr<Example>);
+
+/// Diffs named examples, matching them by key.
+#[derive(Debug, Serialize)]
+pub struct ExamplesDiff {
+    pub added: Vec<ExamplePair>,
+    pub removed: Vec<ExamplePair>,
+    pub changed: HashMap<String, ExampleDiff>,
+}
+
+impl ExamplesDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    pub fn from_examples(
+        base: &BTreeMap<String, ReferenceOr<Example>>,
+        head: &BTreeMap<String, ReferenceOr<Example>>,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut examples_added = vec![];
+        let mut examples_removed = vec![];
+        let mut examples_changed: HashMap<String, ExampleDiff> = HashMap::new();
+
+        for (name, example) in base {
+            match head.get(name) {
+                Some(head_example) => {
+                    let example_diff = ExampleDiff::from_examples(example, head_example, resolver);
+
+                    if example_diff.has_changes() {
+                        examples_changed.insert(name.clone(), example_diff);
+                    }
+                }
+                None => examples_removed.push((name.clone(), example.clone())),
+            }
+        }
+
+        for (name, example) in head {
+            match base.get(name) {
+                Some(_) => {}
+                None => examples_added.push((name.clone(), example.clone())),
+            }
+        }
+
+        Self {
+            added: examples_added,
+            removed: examples_removed,
+            changed: examples_changed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct ExampleDiff {
+    /// Set when either example is a `$ref` that cannot be resolved, and
+    /// the references differ.
+    pub reference: Option<OptionalStringDiff>,
+    pub summary: Option<OptionalStringDiff>,
+    pub description: Option<OptionalStringDiff>,
+    pub value: Option<OptionalValueDiff>,
+    pub external_value: Option<OptionalStringDiff>,
+    pub extensions: Option<ExtensionsDiff>,
+}
+
+impl ExampleDiff {
+    pub fn has_changes(&self) -> bool {
+        self.reference.is_some()
+            || self.summary.is_some()
+            || self.description.is_some()
+            || self.value.is_some()
+            || self.external_value.is_some()
+            || self.extensions.is_some()
+    }
+
+    pub fn from_examples(
+        base: &ReferenceOr<Example>,
+        head: &ReferenceOr<Example>,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut diff = Self::default();
+
+        match (resolver.base_example(base), resolver.head_example(head)) {
+            (Some(base), Some(head)) => {
+                diff.summary = OptionalStringDiff::from_strings(&base.summary, &head.summary);
+                diff.description =
+                    OptionalStringDiff::from_strings(&base.description, &head.description);
+                diff.value = OptionalValueDiff::from_values(&base.value, &head.value);
+                diff.external_value =
+                    OptionalStringDiff::from_strings(&base.external_value, &head.external_value);
+
+                let extensions_diff =
+                    ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
+                if extensions_diff.has_changes() {
+                    diff.extensions = Some(extensions_diff);
+                }
+            }
+            _ => {
+                diff.reference = OptionalStringDiff::from_strings(
+                    &Self::reference(base),
+                    &Self::reference(head),
+                );
+            }
+        }
+
+        diff
+    }
+
+    fn reference(example: &ReferenceOr<Example>) -> Option<String> {
+        match example {
+            ReferenceOr::Reference { reference } => Some(reference.clone()),
+            ReferenceOr::Item(_) => None,
+        }
+    }
+}
+
+/// The examples of a media type, parameter or header, along with the
+/// schema they should validate against.
+pub struct ExampleSet<'a> {
+    pub schema: Option<&'a ReferenceOr<Schema>>,
+    pub example: Option<&'a Value>,
+    pub examples: &'a BTreeMap<String, ReferenceOr<Example>>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExampleSource {
+    Base,
+    Head,
+}
+
+impl<'a> ExampleSet<'a> {
+    /// The examples of a parameter or header, which apply to its schema or,
+    /// when it uses `content`, to the schema of its only media type.
+    pub fn from_format(
+        format: &'a ParameterSchemaOrContent,
+        example: &'a Option<Value>,
+        examples: &'a BTreeMap<String, ReferenceOr<Example>>,
+    ) -> Self {
+        let schema = match format {
+            ParameterSchemaOrContent::Schema(schema) => Some(schema),
+            ParameterSchemaOrContent::Content(content) => content
+                .values()
+                .next()
+                .and_then(|media_type| media_type.schema.as_ref()),
+        };
+
+        Self {
+            schema,
+            example: example.as_ref(),
+            examples,
+        }
+    }
+}
+
+/// An example that does not validate against the head schema.
+#[derive(Debug, Serialize)]
+pub struct InvalidExample {
+    /// `example`, the key of a named example, or `schema` followed by the
+    /// JSON Pointer of the schema whose own `example` is invalid.
+    pub name: String,
+    pub source: ExampleSource,
+    pub errors: Vec<ValidationError>,
+}
+
+impl InvalidExample {
+    /// Reports head examples that fail against the head schema, unless they
+    /// already failed against the base schema, and base examples that were
+    /// valid against the base schema but would fail against the head schema.
+    pub fn from_example_sets(
+        base: &ExampleSet,
+        head: &ExampleSet,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Vec<Self> {
+        let mut invalid_examples = vec![];
+
+        let base_validator = Validator::new(resolver.base, direction);
+        let head_validator = Validator::new(resolver.head, direction);
+
+        let base_examples = Self::candidates(base, &base_validator, |example| {
+            resolver.base_example(example)
+        });
+        let head_examples = Self::candidates(head, &head_validator, |example| {
+            resolver.head_example(example)
+        });
+
+        let base_errors = |pointer: &str, value: &Value| {
+            Self::errors(base.schema, &base_validator, pointer, value)
+        };
+        let head_errors = |pointer: &str, value: &Value| {
+            Self::errors(head.schema, &head_validator, pointer, value)
+        };
+
+        for (name, pointer, value) in &head_examples {
+            let errors = match head_errors(pointer, value) {
+                Some(errors) if !errors.is_empty() => errors,
+                _ => continue,
+            };
+
+            let already_invalid =
+                base_examples
+                    .iter()
+                    .any(|(base_name, base_pointer, base_value)| {
+                        base_name == name
+                            && base_value == value
+                            && base_errors(base_pointer, base_value)
+                                .is_some_and(|errors| !errors.is_empty())
+                    });
+
+            if !already_invalid {
+                invalid_examples.push(Self {
+                    name: name.clone(),
+                    source: ExampleSource::Head,
+                    errors,
+                });
+            }
+        }
+
+        for (name, pointer, value) in &base_examples {
+            // Examples kept as they are in head were checked above.
+            if head_examples
+                .iter()
+                .any(|(head_name, _, head_value)| head_name == name && head_value == value)
+            {
+                continue;
+            }
+
+            if base_errors(pointer, value).map_or(true, |errors| !errors.is_empty()) {
+                continue;
+            }
+
+            if let Some(errors) = head_errors(pointer, value) {
+                if !errors.is_empty() {
+                    invalid_examples.push(Self {
+                        name: name.clone(),
+                        source: ExampleSource::Base,
+                        errors,
+                    });
+                }
+            }
+        }
+
+        invalid_examples
+    }
+
+    /// Lists examples as their name, the JSON Pointer of the schema they
+    /// belong to, relative to the top level schema, and their value.
+    fn candidates(
+        set: &ExampleSet,
+        validator: &Validator,
+        resolve: impl Fn(&ReferenceOr<Example>) -> Option<Example>,
+    ) -> Vec<(String, String, Value)> {
+        let mut candidates = vec![];
+
+        if let Some(example) = set.example {
+            candidates.push((String::from("example"), String::new(), example.clone()));
+        }
+
+        for (name, example) in set.examples {
+            if let Some(value) = resolve(example).and_then(|example| example.value) {
+                candidates.push((name.clone(), String::new(), value));
+            }
+        }
+
+        if let Some(schema) = set.schema {
+            for (pointer, value) in validator.schema_examples(schema) {
+                candidates.push((format!("schema{}", pointer), pointer, value));
+            }
+        }
+
+        candidates
+    }
+
+    /// Returns `None` when there is no schema to validate against.
+    fn errors(
+        schema: Option<&ReferenceOr<Schema>>,
+        validator: &Validator,
+        pointer: &str,
+        value: &Value,
+    ) -> Option<Vec<ValidationError>> {
+        validator.validate_at(schema?, pointer, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openapi::Components;
+    use serde_json::json;
+
+    fn schema(yaml: &str) -> ReferenceOr<Schema> {
+        serde_yaml::from_str(yaml).expect("Failed to parse schema")
+    }
+
+    #[test]
+    fn head_example_no_longer_validates() {
+        let examples = BTreeMap::new();
+        let example = json!("42");
+        let base_schema = schema("type: string");
+        let head_schema = schema("type: integer");
+
+        let invalid_examples = InvalidExample::from_example_sets(
+            &ExampleSet {
+                schema: Some(&base_schema),
+                example: Some(&example),
+                examples: &examples,
+            },
+            &ExampleSet {
+                schema: Some(&head_schema),
+                example: Some(&example),
+                examples: &examples,
+            },
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        assert_eq!(1, invalid_examples.len());
+        assert_eq!("example", invalid_examples[0].name);
+        assert_eq!(ExampleSource::Head, invalid_examples[0].source);
+    }
+
+    #[test]
+    fn removed_base_example_would_fail() {
+        let base_examples: BTreeMap<String, ReferenceOr<Example>> =
+            serde_yaml::from_str("short:\n  value: abc\n").expect("Failed to parse examples");
+        let head_examples = BTreeMap::new();
+        let base_schema = schema("type: string");
+        let head_schema = schema("type: string\nminLength: 5\nexample: abcdef\n");
+
+        let invalid_examples = InvalidExample::from_example_sets(
+            &ExampleSet {
+                schema: Some(&base_schema),
+                example: None,
+                examples: &base_examples,
+            },
+            &ExampleSet {
+                schema: Some(&head_schema),
+                example: None,
+                examples: &head_examples,
+            },
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        assert_eq!(1, invalid_examples.len());
+        assert_eq!("short", invalid_examples[0].name);
+        assert_eq!(ExampleSource::Base, invalid_examples[0].source);
+    }
+
+    #[test]
+    fn already_invalid_examples_are_not_reported() {
+        let examples = BTreeMap::new();
+        let same_schema =
+            schema("type: object\nproperties:\n  id:\n    type: integer\n    example: abc\n");
+
+        let invalid_examples = InvalidExample::from_example_sets(
+            &ExampleSet {
+                schema: Some(&same_schema),
+                example: None,
+                examples: &examples,
+            },
+            &ExampleSet {
+                schema: Some(&same_schema),
+                example: None,
+                examples: &examples,
+            },
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        assert!(invalid_examples.is_empty());
+    }
+
+    #[test]
+    fn referenced_example_changed() {
+        let base: Components = serde_yaml::from_str("examples:\n  Pet:\n    value: Rex\n")
+            .expect("Failed to parse components");
+        let head: Components = serde_yaml::from_str("examples:\n  Pet:\n    value: Fido\n")
+            .expect("Failed to parse components");
+        let examples: BTreeMap<String, ReferenceOr<Example>> =
+            serde_yaml::from_str("pet:\n  $ref: '#/components/examples/Pet'\n")
+                .expect("Failed to parse examples");
+
+        let diff = ExamplesDiff::from_examples(
+            &examples,
+            &examples,
+            &Resolver::new(Some(&base), Some(&head)),
+        );
+        let example_diff = &diff.changed["pet"];
+
+        assert!(example_diff.reference.is_none());
+        assert_eq!(Some(json!("Fido")), example_diff.value.as_ref().unwrap().to);
+    }
+}
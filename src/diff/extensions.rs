@@ -1,4 +1,6 @@
+use super::common::escape;
 use serde::Serialize;
+use serde_json::Value;
 use std::collections::{BTreeMap, HashMap};
 
 pub type ExtensionKeyValue = (String, serde_json::Value);
@@ -15,6 +17,8 @@ impl ExtensionsDiff {
         !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
     }
 
+    /// Only `x-` keys are extensions. Other keys collected by a flattened
+    /// map, such as an unsupported schema keyword, are ignored.
     pub fn from_extensions(
         base: &BTreeMap<String, serde_json::Value>,
         head: &BTreeMap<String, serde_json::Value>,
@@ -23,11 +27,11 @@ impl ExtensionsDiff {
         let mut extensions_removed = vec![];
         let mut extensions_changed: HashMap<String, ExtensionDiff> = HashMap::new();
 
-        for (extension_key, extension_value) in base {
+        for (extension_key, extension_value) in base.iter().filter(|(k, _)| is_extension(k)) {
             match head.get(extension_key) {
                 Some(head_extension_value) => {
                     let extension_diff =
-                        ExtensionDiff::from_values(&extension_value, &head_extension_value);
+                        ExtensionDiff::from_values(extension_value, head_extension_value);
 
                     if extension_diff.has_changes() {
                         extensions_changed.insert(extension_key.clone(), extension_diff);
@@ -37,7 +41,7 @@ impl ExtensionsDiff {
             }
         }
 
-        for (extension_key, extension_value) in head {
+        for (extension_key, extension_value) in head.iter().filter(|(k, _)| is_extension(k)) {
             match base.get(extension_key) {
                 Some(_) => {}
                 None => extensions_added.push((extension_key.clone(), extension_value.clone())),
@@ -52,21 +56,157 @@ impl ExtensionsDiff {
     }
 }
 
+fn is_extension(key: &str) -> bool {
+    key.starts_with("x-")
+}
+
+/// The changes within the value of a single extension.
 #[derive(Debug, Serialize)]
 pub struct ExtensionDiff {
-    from: serde_json::Value,
-    to: serde_json::Value,
+    pub changes: Vec<ValueChange>,
 }
 
 impl ExtensionDiff {
     pub fn has_changes(&self) -> bool {
-        self.from != self.to
+        !self.changes.is_empty()
     }
 
     pub fn from_values(base: &serde_json::Value, head: &serde_json::Value) -> Self {
+        let mut changes = vec![];
+        ValueChange::collect(String::new(), base, head, &mut changes);
+
+        Self { changes }
+    }
+}
+
+/// A change at a JSON Pointer within a value. The pointer is empty when
+/// the whole value changed, `from` is `None` for added members and `to` is
+/// `None` for removed ones.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ValueChange {
+    pub pointer: String,
+    pub from: Option<Value>,
+    pub to: Option<Value>,
+}
+
+impl ValueChange {
+    /// Objects are compared by key and arrays by index, anything else is
+    /// reported as a replacement at `pointer`.
+    fn collect(pointer: String, base: &Value, head: &Value, changes: &mut Vec<ValueChange>) {
+        match (base, head) {
+            (Value::Object(base_map), Value::Object(head_map)) => {
+                for (key, base_value) in base_map {
+                    let child = format!("{}/{}", pointer, escape(key));
+
+                    match head_map.get(key) {
+                        Some(head_value) => Self::collect(child, base_value, head_value, changes),
+                        None => changes.push(Self::removed(child, base_value)),
+                    }
+                }
+
+                for (key, head_value) in head_map {
+                    if !base_map.contains_key(key) {
+                        let child = format!("{}/{}", pointer, escape(key));
+                        changes.push(Self::added(child, head_value));
+                    }
+                }
+            }
+            (Value::Array(base_items), Value::Array(head_items)) => {
+                for (index, base_value) in base_items.iter().enumerate() {
+                    let child = format!("{}/{}", pointer, index);
+
+                    match head_items.get(index) {
+                        Some(head_value) => Self::collect(child, base_value, head_value, changes),
+                        None => changes.push(Self::removed(child, base_value)),
+                    }
+                }
+
+                for (index, head_value) in head_items.iter().enumerate().skip(base_items.len()) {
+                    changes.push(Self::added(format!("{}/{}", pointer, index), head_value));
+                }
+            }
+            _ => {
+                if base != head {
+                    changes.push(Self {
+                        pointer,
+                        from: Some(base.clone()),
+                        to: Some(head.clone()),
+                    });
+                }
+            }
+        }
+    }
+
+    fn added(pointer: String, value: &Value) -> Self {
         Self {
-            from: base.clone(),
-            to: head.clone(),
+            pointer,
+            from: None,
+            to: Some(value.clone()),
         }
     }
+
+    fn removed(pointer: String, value: &Value) -> Self {
+        Self {
+            pointer,
+            from: Some(value.clone()),
+            to: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn extensions(value: Value) -> BTreeMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn nested_changes_by_pointer() {
+        let base = json!({ "limit": 10, "tiers": ["free", "pro"], "a/b": { "c": true } });
+        let head = json!({ "limit": 20, "tiers": ["free"], "a/b": {}, "burst": 5 });
+
+        let diff = ExtensionDiff::from_values(&base, &head);
+
+        assert_eq!(
+            vec![
+                ValueChange {
+                    pointer: "/a~1b/c".into(),
+                    from: Some(json!(true)),
+                    to: None,
+                },
+                ValueChange {
+                    pointer: "/limit".into(),
+                    from: Some(json!(10)),
+                    to: Some(json!(20)),
+                },
+                ValueChange {
+                    pointer: "/tiers/1".into(),
+                    from: Some(json!("pro")),
+                    to: None,
+                },
+                ValueChange {
+                    pointer: "/burst".into(),
+                    from: None,
+                    to: Some(json!(5)),
+                },
+            ],
+            diff.changes
+        );
+    }
+
+    #[test]
+    fn only_x_keys_are_extensions() {
+        let base = extensions(json!({ "x-internal": false, "$ref": "#/a" }));
+        let head = extensions(json!({ "x-internal": true, "$ref": "#/b", "const": 1 }));
+
+        let diff = ExtensionsDiff::from_extensions(&base, &head);
+
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(vec!["x-internal"], diff.changed.keys().collect::<Vec<_>>());
+        assert_eq!("", diff.changed["x-internal"].changes[0].pointer);
+    }
 }
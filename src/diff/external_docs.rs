@@ -0,0 +1,58 @@
+use super::common::{OptionalStringDiff, StringDiff};
+use super::extensions::ExtensionsDiff;
+use crate::openapi::ExternalDocumentation;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Default)]
+pub struct ExternalDocsDiff {
+    pub added: Option<ExternalDocumentation>,
+    pub removed: Option<ExternalDocumentation>,
+    pub url: Option<StringDiff>,
+    pub description: Option<OptionalStringDiff>,
+    pub extensions: Option<ExtensionsDiff>,
+}
+
+impl ExternalDocsDiff {
+    pub fn has_changes(&self) -> bool {
+        self.added.is_some()
+            || self.removed.is_some()
+            || self.url.is_some()
+            || self.description.is_some()
+            || self.extensions.is_some()
+    }
+
+    pub fn from_docs(
+        base: &Option<ExternalDocumentation>,
+        head: &Option<ExternalDocumentation>,
+    ) -> Self {
+        match (base, head) {
+            (Some(base), Some(head)) => {
+                let mut diff = Self {
+                    url: StringDiff::from_strings(base.url.clone(), head.url.clone()),
+                    description: OptionalStringDiff::from_strings(
+                        &base.description,
+                        &head.description,
+                    ),
+                    ..Self::default()
+                };
+
+                let extensions_diff =
+                    ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
+                if extensions_diff.has_changes() {
+                    diff.extensions = Some(extensions_diff);
+                }
+
+                diff
+            }
+            (Some(base), None) => Self {
+                removed: Some(base.clone()),
+                ..Default::default()
+            },
+            (None, Some(head)) => Self {
+                added: Some(head.clone()),
+                ..Default::default()
+            },
+            (None, None) => Self::default(),
+        }
+    }
+}
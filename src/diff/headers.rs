@@ -0,0 +1,244 @@
+use super::common::{BooleanDiff, Direction, OptionalStringDiff, OptionalValueDiff, StringDiff};
+use super::content::ContentDiff;
+use super::examples::{ExampleSet, ExamplesDiff, InvalidExample};
+use super::extensions::ExtensionsDiff;
+use super::resolver::Resolver;
+use super::schema::SchemaDiff;
+use crate::openapi::{Header, ParameterSchemaOrContent, ReferenceOr};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+pub type HeaderPair = (String, ReferenceOr<Header>);
+
+/// Diffs a map of headers. Header names are case insensitive, so
+/// `X-Rate-Limit` and `x-rate-limit` are the same header.
+#[derive(Debug, Serialize)]
+pub struct HeadersDiff {
+    pub added: Vec<HeaderPair>,
+    pub removed: Vec<HeaderPair>,
+    pub changed: HashMap<String, HeaderDiff>,
+}
+
+impl HeadersDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    /// `Content-Type` headers are left out, since the specification says
+    /// they are described separately and SHALL be ignored.
+    pub fn from_headers(
+        base: &BTreeMap<String, ReferenceOr<Header>>,
+        head: &BTreeMap<String, ReferenceOr<Header>>,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut headers_added = vec![];
+        let mut headers_removed = vec![];
+        let mut headers_changed: HashMap<String, HeaderDiff> = HashMap::new();
+
+        let base = Self::normalize(base);
+        let head = Self::normalize(head);
+
+        for (name, (original_name, header)) in &base {
+            match head.get(name) {
+                Some((_, head_header)) => {
+                    let header_diff =
+                        HeaderDiff::from_headers(header, head_header, direction, resolver);
+
+                    if header_diff.has_changes() {
+                        headers_changed.insert(original_name.clone(), header_diff);
+                    }
+                }
+                None => headers_removed.push((original_name.clone(), (*header).clone())),
+            }
+        }
+
+        for (name, (original_name, header)) in &head {
+            match base.get(name) {
+                Some(_) => {}
+                None => headers_added.push((original_name.clone(), (*header).clone())),
+            }
+        }
+
+        Self {
+            added: headers_added,
+            removed: headers_removed,
+            changed: headers_changed,
+        }
+    }
+
+    fn normalize(
+        headers: &BTreeMap<String, ReferenceOr<Header>>,
+    ) -> BTreeMap<String, (String, &ReferenceOr<Header>)> {
+        headers
+            .iter()
+            .filter(|(name, _)| !name.eq_ignore_ascii_case("content-type"))
+            .map(|(name, header)| (name.to_lowercase(), (name.clone(), header)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct HeaderDiff {
+    /// Set when either header is a `$ref` that cannot be resolved, and
+    /// the references differ.
+    pub reference: Option<OptionalStringDiff>,
+    pub required: Option<BooleanDiff>,
+    pub deprecated: Option<BooleanDiff>,
+    pub description: Option<OptionalStringDiff>,
+    pub schema: Option<SchemaDiff>,
+    pub content: Option<ContentDiff>,
+    /// Set when the header switches between the `schema` and `content` forms.
+    pub serialization_changed: Option<StringDiff>,
+    pub example: Option<OptionalValueDiff>,
+    pub examples: Option<ExamplesDiff>,
+    pub extensions: Option<ExtensionsDiff>,
+    pub invalid_examples: Vec<InvalidExample>,
+}
+
+impl HeaderDiff {
+    pub fn has_changes(&self) -> bool {
+        self.reference.is_some()
+            || self.required.is_some()
+            || self.deprecated.is_some()
+            || self.description.is_some()
+            || self.schema.is_some()
+            || self.content.is_some()
+            || self.serialization_changed.is_some()
+            || self.example.is_some()
+            || self.examples.is_some()
+            || self.extensions.is_some()
+            || !self.invalid_examples.is_empty()
+    }
+
+    pub fn from_headers(
+        base: &ReferenceOr<Header>,
+        head: &ReferenceOr<Header>,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut diff = Self::default();
+
+        let (base, head) = match (resolver.base_header(base), resolver.head_header(head)) {
+            (Some(base_header), Some(head_header)) => (base_header, head_header),
+            _ => {
+                diff.reference = OptionalStringDiff::from_strings(
+                    &Self::reference(base),
+                    &Self::reference(head),
+                );
+                return diff;
+            }
+        };
+
+        diff.required = BooleanDiff::from_bools(
+            base.required.unwrap_or(false),
+            head.required.unwrap_or(false),
+        );
+        diff.deprecated = BooleanDiff::from_bools(
+            base.deprecated.unwrap_or(false),
+            head.deprecated.unwrap_or(false),
+        );
+        diff.description = OptionalStringDiff::from_strings(&base.description, &head.description);
+        diff.example = OptionalValueDiff::from_values(&base.example, &head.example);
+
+        match (&base.format, &head.format) {
+            (
+                ParameterSchemaOrContent::Schema(base_schema),
+                ParameterSchemaOrContent::Schema(head_schema),
+            ) => {
+                let schema_diff =
+                    SchemaDiff::from_schemas(base_schema, head_schema, direction, resolver);
+                if schema_diff.has_changes() {
+                    diff.schema = Some(schema_diff);
+                }
+            }
+            (
+                ParameterSchemaOrContent::Content(base_content),
+                ParameterSchemaOrContent::Content(head_content),
+            ) => {
+                let content_diff =
+                    ContentDiff::from_content(base_content, head_content, direction, resolver);
+                if content_diff.has_changes() {
+                    diff.content = Some(content_diff);
+                }
+            }
+            (ParameterSchemaOrContent::Schema(_), ParameterSchemaOrContent::Content(_)) => {
+                diff.serialization_changed =
+                    StringDiff::from_strings(String::from("schema"), String::from("content"));
+            }
+            (ParameterSchemaOrContent::Content(_), ParameterSchemaOrContent::Schema(_)) => {
+                diff.serialization_changed =
+                    StringDiff::from_strings(String::from("content"), String::from("schema"));
+            }
+        }
+
+        let examples_diff = ExamplesDiff::from_examples(&base.examples, &head.examples, resolver);
+        if examples_diff.has_changes() {
+            diff.examples = Some(examples_diff);
+        }
+
+        let extensions_diff = ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
+        if extensions_diff.has_changes() {
+            diff.extensions = Some(extensions_diff);
+        }
+
+        diff.invalid_examples = InvalidExample::from_example_sets(
+            &ExampleSet::from_format(&base.format, &base.example, &base.examples),
+            &ExampleSet::from_format(&head.format, &head.example, &head.examples),
+            direction,
+            resolver,
+        );
+
+        diff
+    }
+
+    fn reference(header: &ReferenceOr<Header>) -> Option<String> {
+        match header {
+            ReferenceOr::Reference { reference } => Some(reference.clone()),
+            ReferenceOr::Item(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openapi::Components;
+
+    #[test]
+    fn referenced_header_changed() {
+        let base: Components =
+            serde_yaml::from_str("headers:\n  RateLimit:\n    schema:\n      type: integer\n")
+                .expect("Failed to parse components");
+        let head: Components = serde_yaml::from_str(
+            "headers:\n  RateLimit:\n    required: true\n    schema:\n      type: integer\n",
+        )
+        .expect("Failed to parse components");
+        let reference = ReferenceOr::ref_("#/components/headers/RateLimit");
+
+        let diff = HeaderDiff::from_headers(
+            &reference,
+            &reference,
+            Direction::Response,
+            &Resolver::new(Some(&base), Some(&head)),
+        );
+
+        assert!(diff.reference.is_none());
+        assert!(diff.required.unwrap().to);
+    }
+
+    #[test]
+    fn unresolved_reference_changed() {
+        let diff = HeaderDiff::from_headers(
+            &ReferenceOr::ref_("#/components/headers/RateLimit"),
+            &ReferenceOr::ref_("#/components/headers/Limit"),
+            Direction::Response,
+            &Resolver::default(),
+        );
+
+        assert_eq!(
+            Some("#/components/headers/Limit".into()),
+            diff.reference.unwrap().to
+        );
+    }
+}
@@ -26,12 +26,15 @@ impl InfoDiff {
     }
 
     pub fn from_info(base: &Info, head: &Info) -> Self {
-        let mut diff = Self::default();
-
-        diff.title = StringDiff::from_strings(base.title.clone(), head.title.clone());
-        diff.description = OptionalStringDiff::from_strings(&base.description, &head.description);
-        diff.terms_of_service =
-            OptionalStringDiff::from_strings(&base.terms_of_service, &head.terms_of_service);
+        let mut diff = Self {
+            title: StringDiff::from_strings(base.title.clone(), head.title.clone()),
+            description: OptionalStringDiff::from_strings(&base.description, &head.description),
+            terms_of_service: OptionalStringDiff::from_strings(
+                &base.terms_of_service,
+                &head.terms_of_service,
+            ),
+            ..Self::default()
+        };
 
         let contact_diff = ContactDiff::from_contacts(&base.contact, &head.contact);
         if contact_diff.has_changes() {
@@ -76,11 +79,12 @@ impl ContactDiff {
         match base {
             Some(base) => match head {
                 Some(head) => {
-                    let mut diff = Self::default();
-
-                    diff.name = OptionalStringDiff::from_strings(&base.name, &head.name);
-                    diff.url = OptionalStringDiff::from_strings(&base.url, &head.url);
-                    diff.email = OptionalStringDiff::from_strings(&base.email, &head.email);
+                    let mut diff = Self {
+                        name: OptionalStringDiff::from_strings(&base.name, &head.name),
+                        url: OptionalStringDiff::from_strings(&base.url, &head.url),
+                        email: OptionalStringDiff::from_strings(&base.email, &head.email),
+                        ..Self::default()
+                    };
 
                     let extensions_diff =
                         ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
@@ -124,10 +128,11 @@ impl LicenseDiff {
         match base {
             Some(base) => match head {
                 Some(head) => {
-                    let mut diff = Self::default();
-
-                    diff.name = StringDiff::from_strings(base.name.clone(), head.name.clone());
-                    diff.url = OptionalStringDiff::from_strings(&base.url, &head.url);
+                    let mut diff = Self {
+                        name: StringDiff::from_strings(base.name.clone(), head.name.clone()),
+                        url: OptionalStringDiff::from_strings(&base.url, &head.url),
+                        ..Self::default()
+                    };
 
                     let extensions_diff =
                         ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
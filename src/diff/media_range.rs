@@ -0,0 +1,173 @@
+use super::common::Direction;
+use super::compatibility::Compatibility;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// A parsed media type or media type range, such as `application/json`,
+/// `image/*` or `text/plain; charset=utf-8`.
+///
+/// See <https://tools.ietf.org/html/rfc7231#section-3.1.1.1>.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaRange {
+    pub type_: String,
+    pub subtype: String,
+    pub parameters: BTreeMap<String, String>,
+}
+
+impl MediaRange {
+    /// Parses a media type, lowercasing the type, subtype and parameter
+    /// names, and unquoting parameter values. Returns `None` when the
+    /// value is not of the form `type/subtype`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.split(';');
+        let essence = parts.next()?.trim().to_lowercase();
+        let (type_, subtype) = essence.split_once('/')?;
+
+        if type_.is_empty() || subtype.is_empty() || subtype.contains('/') {
+            return None;
+        }
+
+        let mut parameters = BTreeMap::new();
+        for parameter in parts {
+            let (name, value) = match parameter.split_once('=') {
+                Some((name, value)) => (name, value),
+                None => continue,
+            };
+            let name = name.trim().to_lowercase();
+            let mut value = value.trim().trim_matches('"').to_string();
+
+            // Only charset values are case insensitive.
+            if name == "charset" {
+                value = value.to_lowercase();
+            }
+
+            parameters.insert(name, value);
+        }
+
+        let mut range = Self {
+            type_: type_.to_string(),
+            subtype: subtype.to_string(),
+            parameters,
+        };
+
+        // JSON text is always UTF-8, so the parameter does not change anything.
+        if range.is_json() && range.parameters.get("charset").map(String::as_str) == Some("utf-8") {
+            range.parameters.remove("charset");
+        }
+
+        Some(range)
+    }
+
+    /// Whether this is JSON, or a JSON-structured vendor type such as
+    /// `application/vnd.github.v3+json`.
+    pub fn is_json(&self) -> bool {
+        self.subtype == "json" || self.subtype.ends_with("+json")
+    }
+
+    /// Whether every media type matched by `other` is also matched by `self`.
+    pub fn contains(&self, other: &MediaRange) -> bool {
+        let type_matches = self.type_ == "*" || self.type_ == other.type_;
+        let subtype_matches = self.subtype == "*" || self.subtype == other.subtype;
+        let parameters_match = self
+            .parameters
+            .iter()
+            .all(|(name, value)| other.parameters.get(name) == Some(value));
+
+        type_matches && subtype_matches && parameters_match
+    }
+}
+
+/// Compares two media type keys of a content map, which may match
+/// each other even when they are not written the same way.
+pub fn compatibility(base: &str, head: &str) -> Option<Compatibility> {
+    if base == head {
+        return Some(Compatibility::Equivalent);
+    }
+
+    let base = MediaRange::parse(base)?;
+    let head = MediaRange::parse(head)?;
+
+    match (base.contains(&head), head.contains(&base)) {
+        (true, true) => Some(Compatibility::Equivalent),
+        (true, false) => Some(Compatibility::Narrowed),
+        (false, true) => Some(Compatibility::Widened),
+        (false, false) => None,
+    }
+}
+
+/// A media type key that was replaced by a wider or narrower range, e.g.
+/// `application/json` by `application/*`, or by another JSON-structured
+/// type, e.g. `application/vnd.github.v3+json`.
+#[derive(Debug, Serialize)]
+pub struct MediaRangeDiff {
+    pub from: String,
+    pub to: String,
+    pub compatibility: Compatibility,
+    pub breaking: bool,
+}
+
+impl MediaRangeDiff {
+    /// Equivalent keys, such as `application/json` and
+    /// `application/json; charset=utf-8`, are not reported. Keys where
+    /// neither range contains the other are incompatible: clients must
+    /// send or accept a different `Content-Type`.
+    pub fn from_keys(base: &str, head: &str, direction: Direction) -> Option<Self> {
+        let compatibility = compatibility(base, head).unwrap_or(Compatibility::Incompatible);
+
+        if compatibility == Compatibility::Equivalent {
+            return None;
+        }
+
+        Some(Self {
+            from: base.to_string(),
+            to: head.to_string(),
+            compatibility,
+            breaking: compatibility.is_breaking(direction),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parameters_and_case_are_normalized() {
+        let base = MediaRange::parse("Text/Plain; Charset=\"UTF-8\"").unwrap();
+        let head = MediaRange::parse("text/plain;charset=utf-8").unwrap();
+
+        assert_eq!(base, head);
+    }
+
+    #[test]
+    fn json_charset_is_equivalent() {
+        assert_eq!(
+            Some(Compatibility::Equivalent),
+            compatibility("application/json", "application/json; charset=utf-8")
+        );
+    }
+
+    #[test]
+    fn vendor_json() {
+        let range = MediaRange::parse("application/vnd.github.v3+json").unwrap();
+
+        assert!(range.is_json());
+        assert!(!MediaRange::parse("application/xml").unwrap().is_json());
+    }
+
+    #[test]
+    fn wildcard_widens() {
+        let diff =
+            MediaRangeDiff::from_keys("application/json", "application/*", Direction::Response)
+                .unwrap();
+
+        assert_eq!(Compatibility::Widened, diff.compatibility);
+        assert!(diff.breaking);
+    }
+
+    #[test]
+    fn unrelated_types_do_not_match() {
+        assert_eq!(None, compatibility("application/json", "application/xml"));
+        assert_eq!(None, compatibility("application/json", "not a media type"));
+    }
+}
@@ -1,32 +1,64 @@
+use super::common::{Direction, OptionalValueDiff};
+use super::encodings::EncodingsDiff;
+use super::examples::{ExampleSet, ExamplesDiff, InvalidExample};
+use super::extensions::ExtensionsDiff;
+use super::media_range::MediaRangeDiff;
+use super::resolver::Resolver;
 use super::schema::SchemaDiff;
 use crate::openapi::{MediaType, ReferenceOr, Schema};
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
 pub struct MediaTypeDiff {
+    /// Set by `ContentDiff` when the media type was matched with a wider
+    /// or narrower range.
+    pub range_changed: Option<MediaRangeDiff>,
     pub schema_changed: Option<SchemaDiff>,
     pub schema_added: Option<ReferenceOr<Schema>>,
     pub schema_removed: Option<ReferenceOr<Schema>>,
+    pub example_changed: Option<OptionalValueDiff>,
+    pub examples_changed: Option<ExamplesDiff>,
+    pub encoding_changed: Option<EncodingsDiff>,
+    pub extensions_changed: Option<ExtensionsDiff>,
+    pub invalid_examples: Vec<InvalidExample>,
 }
 
 impl MediaTypeDiff {
     pub fn has_changes(&self) -> bool {
-        self.schema_changed.is_some()
+        self.range_changed.is_some()
+            || self.schema_changed.is_some()
             || self.schema_added.is_some()
             || self.schema_removed.is_some()
+            || self.example_changed.is_some()
+            || self.examples_changed.is_some()
+            || self.encoding_changed.is_some()
+            || self.extensions_changed.is_some()
+            || !self.invalid_examples.is_empty()
     }
 
-    pub fn from_media_types(base: &MediaType, head: &MediaType) -> Self {
+    pub fn from_media_types(
+        base: &MediaType,
+        head: &MediaType,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
         let mut diff = Self {
+            range_changed: None,
             schema_changed: None,
             schema_removed: None,
             schema_added: None,
+            example_changed: None,
+            examples_changed: None,
+            encoding_changed: None,
+            extensions_changed: None,
+            invalid_examples: vec![],
         };
 
         match &base.schema {
             Some(base_schema) => match &head.schema {
                 Some(head_schema) => {
-                    let schema_diff = SchemaDiff::from_schemas(&base_schema, &head_schema);
+                    let schema_diff =
+                        SchemaDiff::from_schemas(base_schema, head_schema, direction, resolver);
 
                     if schema_diff.has_changes() {
                         diff.schema_changed = Some(schema_diff);
@@ -36,14 +68,98 @@ impl MediaTypeDiff {
                     diff.schema_removed = Some(base_schema.clone());
                 }
             },
-            None => match &head.schema {
-                Some(head_schema) => {
+            None => {
+                if let Some(head_schema) = &head.schema {
                     diff.schema_added = Some(head_schema.clone());
                 }
-                None => {}
-            },
+            }
+        }
+
+        diff.example_changed = OptionalValueDiff::from_values(&base.example, &head.example);
+
+        let examples_diff = ExamplesDiff::from_examples(&base.examples, &head.examples, resolver);
+        if examples_diff.has_changes() {
+            diff.examples_changed = Some(examples_diff);
+        }
+
+        let encoding_diff =
+            EncodingsDiff::from_encodings(&base.encoding, &head.encoding, direction, resolver);
+        if encoding_diff.has_changes() {
+            diff.encoding_changed = Some(encoding_diff);
         }
 
+        let extensions_diff = ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
+        if extensions_diff.has_changes() {
+            diff.extensions_changed = Some(extensions_diff);
+        }
+
+        diff.invalid_examples = InvalidExample::from_example_sets(
+            &ExampleSet {
+                schema: base.schema.as_ref(),
+                example: base.example.as_ref(),
+                examples: &base.examples,
+            },
+            &ExampleSet {
+                schema: head.schema.as_ref(),
+                example: head.example.as_ref(),
+                examples: &head.examples,
+            },
+            direction,
+            resolver,
+        );
+
         diff
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_removed() {
+        let base: MediaType =
+            serde_yaml::from_str("x-codegen-type: Pet\n").expect("Failed to parse media type");
+
+        let diff = MediaTypeDiff::from_media_types(
+            &base,
+            &MediaType::default(),
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        assert_eq!(
+            "x-codegen-type",
+            diff.extensions_changed.unwrap().removed[0].0
+        );
+    }
+
+    #[test]
+    fn named_example_changed() {
+        let base: MediaType =
+            serde_yaml::from_str("examples:\n  cat:\n    value:\n      name: Tom\n")
+                .expect("Failed to parse media type");
+        let head: MediaType =
+            serde_yaml::from_str("examples:\n  cat:\n    value:\n      name: Garfield\n")
+                .expect("Failed to parse media type");
+
+        let diff =
+            MediaTypeDiff::from_media_types(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(diff.examples_changed.unwrap().changed.contains_key("cat"));
+        assert!(diff.encoding_changed.is_none());
+    }
+
+    #[test]
+    fn encoding_added() {
+        let base = MediaType::default();
+        let head: MediaType =
+            serde_yaml::from_str("encoding:\n  file:\n    contentType: image/png\n")
+                .expect("Failed to parse media type");
+
+        let diff =
+            MediaTypeDiff::from_media_types(&base, &head, Direction::Request, &Resolver::default());
+
+        assert_eq!("file", diff.encoding_changed.unwrap().added[0].0);
+    }
+}
@@ -1,7 +1,15 @@
+pub(crate) mod callbacks;
 pub(crate) mod common;
+pub(crate) mod compatibility;
 pub(crate) mod content;
+pub(crate) mod encodings;
+pub(crate) mod endpoints;
+pub(crate) mod examples;
 pub(crate) mod extensions;
+pub(crate) mod external_docs;
+pub(crate) mod headers;
 pub(crate) mod info;
+pub(crate) mod media_range;
 pub(crate) mod media_type;
 pub(crate) mod operations;
 pub(crate) mod parameter;
@@ -9,18 +17,28 @@ pub(crate) mod parameters;
 pub(crate) mod path_items;
 pub(crate) mod paths;
 pub(crate) mod request_body;
+pub(crate) mod resolver;
 pub(crate) mod response;
 pub(crate) mod responses;
 pub(crate) mod schema;
+pub(crate) mod security;
 pub(crate) mod servers;
+pub(crate) mod tags;
+pub(crate) mod validation;
 
 use crate::openapi::OpenAPI;
-use common::StringDiff;
+use crate::swagger::Swagger;
+use common::{Direction, StringDiff};
+use endpoints::EndpointsDiff;
+use external_docs::ExternalDocsDiff;
 use info::InfoDiff;
 use paths::PathsDiff;
-use serde::Serialize;
+use resolver::Resolver;
+use security::SecurityDiff;
+use serde::{Deserialize, Serialize};
 use servers::ServersDiff;
 use std::path::PathBuf;
+use tags::TagsDiff;
 use thiserror::Error;
 
 /// DiffError enumerates all possible errors returned by this library.
@@ -44,7 +62,16 @@ pub struct Diff {
     pub version: Option<StringDiff>,
     pub servers: Option<ServersDiff>,
     pub paths: Option<PathsDiff>,
+    /// The endpoints that were added or removed, including those that
+    /// moved to other servers.
+    pub endpoints: Option<EndpointsDiff>,
+    pub webhooks: Option<PathsDiff>,
     pub info: Option<InfoDiff>,
+    pub tags: Option<TagsDiff>,
+    pub external_docs: Option<ExternalDocsDiff>,
+    /// The security requirements applied to operations that do not
+    /// declare their own.
+    pub security: Option<SecurityDiff>,
 }
 
 impl Diff {
@@ -52,28 +79,69 @@ impl Diff {
         self.version.is_some()
             || self.servers.is_some()
             || self.paths.is_some()
+            || self.endpoints.is_some()
+            || self.webhooks.is_some()
             || self.info.is_some()
+            || self.tags.is_some()
+            || self.external_docs.is_some()
+            || self.security.is_some()
     }
 }
 
 pub fn diff_files(base: PathBuf, head: PathBuf) -> Result<Diff, DiffError> {
     let base_contents = std::fs::read_to_string(base)?;
     let head_contents = std::fs::read_to_string(head)?;
-    let base_openapi: OpenAPI = serde_yaml::from_str(&base_contents)?;
-    let head_openapi: OpenAPI = serde_yaml::from_str(&head_contents)?;
-    diff(base_openapi, head_openapi)
+    diff(parse(&base_contents)?, parse(&head_contents)?)
+}
+
+/// Parses an OpenAPI 3 document, or a Swagger 2.0 document converted to
+/// OpenAPI 3.
+pub fn parse(contents: &str) -> Result<OpenAPI, DiffError> {
+    #[derive(Deserialize)]
+    struct Version {
+        swagger: Option<String>,
+    }
+
+    let version: Version = serde_yaml::from_str(contents)?;
+
+    if version.swagger.is_some() {
+        let swagger: Swagger = serde_yaml::from_str(contents)?;
+        Ok(OpenAPI::from(swagger))
+    } else {
+        Ok(serde_yaml::from_str(contents)?)
+    }
 }
 
 pub fn diff(base: OpenAPI, head: OpenAPI) -> Result<Diff, DiffError> {
-    let mut diff = Diff::default();
+    let mut diff = Diff {
+        version: StringDiff::from_strings(base.openapi.clone(), head.openapi.clone()),
+        ..Diff::default()
+    };
 
-    diff.version = StringDiff::from_strings(base.openapi, head.openapi);
+    let resolver = Resolver::new(base.components.as_ref(), head.components.as_ref());
+
+    let paths_diff =
+        PathsDiff::from_paths(&base.paths, &head.paths, Direction::Request, &resolver)?;
+
+    let endpoints_diff = EndpointsDiff::from_paths(&paths_diff, &base, &head, &resolver);
+    if endpoints_diff.has_changes() {
+        diff.endpoints = Some(endpoints_diff);
+    }
 
-    let paths_diff = PathsDiff::from_paths(&base.paths, &head.paths)?;
     if paths_diff.has_changes() {
         diff.paths = Some(paths_diff);
     }
 
+    let webhooks_diff = PathsDiff::from_paths(
+        &base.webhooks,
+        &head.webhooks,
+        Direction::Response,
+        &resolver,
+    )?;
+    if webhooks_diff.has_changes() {
+        diff.webhooks = Some(webhooks_diff);
+    }
+
     let info_diff = InfoDiff::from_info(&base.info, &head.info);
     if info_diff.has_changes() {
         diff.info = Some(info_diff);
@@ -84,12 +152,31 @@ pub fn diff(base: OpenAPI, head: OpenAPI) -> Result<Diff, DiffError> {
         diff.servers = Some(servers_diff);
     }
 
+    let tags_diff = TagsDiff::from_tags(&base.tags, &head.tags);
+    if tags_diff.has_changes() {
+        diff.tags = Some(tags_diff);
+    }
+
+    let external_docs_diff = ExternalDocsDiff::from_docs(&base.external_docs, &head.external_docs);
+    if external_docs_diff.has_changes() {
+        diff.external_docs = Some(external_docs_diff);
+    }
+
+    let security_diff = SecurityDiff::from_requirements(
+        base.security.as_deref().unwrap_or_default(),
+        head.security.as_deref().unwrap_or_default(),
+    );
+    if security_diff.has_changes() {
+        diff.security = Some(security_diff);
+    }
+
     Ok(diff)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::openapi::StatusCode;
 
     #[test]
     fn from_json_files() {
@@ -115,7 +202,7 @@ mod tests {
         )
         .expect("Failed to diff JSON");
 
-        assert_eq!(false, diff.has_changes());
+        assert!(!diff.has_changes());
     }
 
     #[test]
@@ -132,12 +219,74 @@ mod tests {
         assert_eq!("3.1.0", version_change.to);
     }
 
+    #[test]
+    fn swagger_migration() {
+        let diff = diff_files(
+            PathBuf::from("fixtures/pet-store.swagger.yaml"),
+            PathBuf::from("fixtures/pet-store.yaml"),
+        )
+        .expect("Failed to diff Swagger");
+
+        let version_change = diff.version.unwrap();
+
+        assert_eq!("2.0", version_change.from);
+        assert_eq!("3.0.0", version_change.to);
+        assert!(diff.servers.is_none());
+        assert!(diff.paths.is_none());
+    }
+
+    #[test]
+    fn swagger_shared_responses() {
+        let swagger = |schema_type: &str| {
+            format!(
+                "swagger: '2.0'\ninfo:\n  title: Pets\n  version: '1'\nproduces: [application/json]\nresponses:\n  NotFound:\n    description: Not found\n    schema:\n      type: {}\npaths:\n  /pets:\n    get:\n      responses:\n        '404':\n          $ref: '#/responses/NotFound'\n",
+                schema_type
+            )
+        };
+        let base = parse(&swagger("string")).expect("Failed to parse Swagger");
+        let head = parse(&swagger("integer")).expect("Failed to parse Swagger");
+
+        assert!(!diff(base.clone(), base.clone())
+            .expect("Failed to diff")
+            .has_changes());
+
+        let diff = diff(base, head).expect("Failed to diff");
+        let operation_diff = &diff.paths.unwrap().changed["/pets"].operations_changed["get"];
+        let response_diff = &operation_diff.responses.changed[&StatusCode::Code(404)];
+
+        assert!(response_diff.reference.is_none());
+        assert!(response_diff.content.is_some());
+    }
+
+    #[test]
+    fn webhooks_changed() {
+        let base: OpenAPI = serde_yaml::from_str(
+            "openapi: 3.1.0\ninfo:\n  title: Pets\n  version: '1'\nwebhooks:\n  newPet:\n    post:\n      responses: {}\n",
+        )
+        .expect("Failed to parse document");
+        let head: OpenAPI = serde_yaml::from_str(
+            "openapi: 3.1.0\ninfo:\n  title: Pets\n  version: '1'\nwebhooks:\n  newPet:\n    $ref: '#/components/pathItems/NewPet'\ncomponents:\n  pathItems:\n    NewPet:\n      post:\n        responses: {}\n      put:\n        responses: {}\n",
+        )
+        .expect("Failed to parse document");
+
+        let diff = diff(base, head).expect("Failed to diff");
+        let webhook_diff = &diff.webhooks.unwrap().changed["newPet"];
+
+        assert!(diff.paths.is_none());
+        assert_eq!("put", webhook_diff.operations_added[0].0);
+        assert!(webhook_diff.operations_changed.is_empty());
+    }
+
     #[test]
     fn openapi_version_change() {
-        let mut base = OpenAPI::default();
-        base.openapi = String::from("3.0.0");
-        let mut head = OpenAPI::default();
-        head.openapi = String::from("4.0.0");
+        let base = OpenAPI {
+            openapi: String::from("3.0.0"),
+            ..OpenAPI::default()
+        };
+        let head = OpenAPI {
+            openapi: String::from("4.0.0"),
+            ..OpenAPI::default()
+        };
 
         let result = diff(base, head);
         let diff = result.expect("Failed to diff");
@@ -147,4 +296,22 @@ mod tests {
         assert_eq!("3.0.0", version_change.from);
         assert_eq!("4.0.0", version_change.to);
     }
+
+    #[test]
+    fn document_security_and_tags_changed() {
+        let base: OpenAPI = serde_yaml::from_str(
+            "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths: {}\nsecurity:\n  - api_key: []\ntags:\n  - name: pets\n",
+        )
+        .expect("Failed to parse document");
+        let head: OpenAPI = serde_yaml::from_str(
+            "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths: {}\ntags:\n  - name: pets\n    description: Everything about pets\nexternalDocs:\n  url: https://example.com\n",
+        )
+        .expect("Failed to parse document");
+
+        let diff = diff(base, head).expect("Failed to diff");
+
+        assert_eq!(1, diff.security.unwrap().removed.len());
+        assert!(diff.tags.unwrap().changed.contains_key("pets"));
+        assert!(diff.external_docs.unwrap().added.is_some());
+    }
 }
@@ -1,7 +1,12 @@
-use super::common::{OptionalStringDiff, StringListDiff};
+use super::callbacks::CallbacksDiff;
+use super::common::{BooleanDiff, Direction, OptionalStringDiff, StringListDiff};
+use super::extensions::ExtensionsDiff;
+use super::external_docs::ExternalDocsDiff;
 use super::parameters::ParametersDiff;
 use super::request_body::RequestBodyDiff;
+use super::resolver::Resolver;
 use super::responses::ResponsesDiff;
+use super::servers::ServersDiff;
 use crate::openapi::Operation;
 use serde::Serialize;
 
@@ -11,9 +16,14 @@ pub struct OperationDiff {
     pub summary: Option<OptionalStringDiff>,
     pub description: Option<OptionalStringDiff>,
     pub operation_id: Option<OptionalStringDiff>,
+    pub external_docs: Option<ExternalDocsDiff>,
+    pub deprecated: Option<BooleanDiff>,
+    pub servers: Option<ServersDiff>,
     pub parameters: ParametersDiff,
     pub request_body: Option<RequestBodyDiff>,
     pub responses: ResponsesDiff,
+    pub callbacks: Option<CallbacksDiff>,
+    pub extensions: Option<ExtensionsDiff>,
 }
 
 impl OperationDiff {
@@ -22,12 +32,24 @@ impl OperationDiff {
             || self.summary.is_some()
             || self.description.is_some()
             || self.operation_id.is_some()
+            || self.external_docs.is_some()
+            || self.deprecated.is_some()
+            || self.servers.is_some()
             || self.parameters.has_changes()
             || self.request_body.is_some()
             || self.responses.has_changes()
+            || self.callbacks.is_some()
+            || self.extensions.is_some()
     }
 
-    pub fn from_operations(base: &Operation, head: &Operation) -> Self {
+    /// `direction` is the direction of the operation's request, which is
+    /// `Direction::Response` for callbacks.
+    pub fn from_operations(
+        base: &Operation,
+        head: &Operation,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
         let tags_diff = StringListDiff::from_lists(&base.tags, &head.tags);
 
         let summary_diff = OptionalStringDiff::from_strings(&base.summary, &head.summary);
@@ -38,10 +60,37 @@ impl OperationDiff {
         let operation_id_diff =
             OptionalStringDiff::from_strings(&base.operation_id, &head.operation_id);
 
-        let parameters = ParametersDiff::from_params(&base.parameters, &head.parameters);
+        let external_docs_diff =
+            ExternalDocsDiff::from_docs(&base.external_docs, &head.external_docs);
 
-        let request_body_diff =
-            RequestBodyDiff::from_request_bodies(&base.request_body, &head.request_body);
+        let external_docs = if external_docs_diff.has_changes() {
+            Some(external_docs_diff)
+        } else {
+            None
+        };
+
+        let deprecated_diff = BooleanDiff::from_bools(
+            base.deprecated.unwrap_or(false),
+            head.deprecated.unwrap_or(false),
+        );
+
+        let servers_diff = ServersDiff::from_servers(&base.servers, &head.servers);
+
+        let servers = if servers_diff.has_changes() {
+            Some(servers_diff)
+        } else {
+            None
+        };
+
+        let parameters =
+            ParametersDiff::from_params(&base.parameters, &head.parameters, direction, resolver);
+
+        let request_body_diff = RequestBodyDiff::from_request_bodies(
+            &base.request_body,
+            &head.request_body,
+            direction,
+            resolver,
+        );
 
         let request_body = if request_body_diff.has_changes() {
             Some(request_body_diff)
@@ -49,16 +98,43 @@ impl OperationDiff {
             None
         };
 
-        let responses = ResponsesDiff::from_responses(&base.responses, &head.responses);
+        let responses = ResponsesDiff::from_responses(
+            &base.responses,
+            &head.responses,
+            direction.reversed(),
+            resolver,
+        );
+
+        let callbacks_diff =
+            CallbacksDiff::from_callbacks(&base.callbacks, &head.callbacks, resolver);
+
+        let callbacks = if callbacks_diff.has_changes() {
+            Some(callbacks_diff)
+        } else {
+            None
+        };
+
+        let extensions_diff = ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
+
+        let extensions = if extensions_diff.has_changes() {
+            Some(extensions_diff)
+        } else {
+            None
+        };
 
         Self {
             tags: tags_diff,
             summary: summary_diff,
             description: description_diff,
             operation_id: operation_id_diff,
+            external_docs,
+            deprecated: deprecated_diff,
+            servers,
             parameters,
             request_body,
             responses,
+            callbacks,
+            extensions,
         }
     }
 }
@@ -70,10 +146,17 @@ mod tests {
     #[test]
     fn operation_id_changed_from_none_to_some() {
         let base_operation = Operation::default();
-        let mut head_operation = Operation::default();
-        head_operation.operation_id = Some("cats/create".into());
+        let head_operation = Operation {
+            operation_id: Some("cats/create".into()),
+            ..Operation::default()
+        };
 
-        let diff = OperationDiff::from_operations(&base_operation, &head_operation);
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
         let op_id_diff = diff.operation_id.unwrap();
 
         assert_eq!(op_id_diff.from, None);
@@ -82,12 +165,21 @@ mod tests {
 
     #[test]
     fn operation_id_changed_from_some_to_some() {
-        let mut base_operation = Operation::default();
-        base_operation.operation_id = Some("cats-create".into());
-        let mut head_operation = Operation::default();
-        head_operation.operation_id = Some("cats/create".into());
+        let base_operation = Operation {
+            operation_id: Some("cats-create".into()),
+            ..Operation::default()
+        };
+        let head_operation = Operation {
+            operation_id: Some("cats/create".into()),
+            ..Operation::default()
+        };
 
-        let diff = OperationDiff::from_operations(&base_operation, &head_operation);
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
         let op_id_diff = diff.operation_id.unwrap();
 
         assert_eq!(op_id_diff.from, Some("cats-create".into()));
@@ -97,10 +189,17 @@ mod tests {
     #[test]
     fn summary_changed_from_none_to_some() {
         let base_operation = Operation::default();
-        let mut head_operation = Operation::default();
-        head_operation.summary = Some("Creates a feline.".into());
+        let head_operation = Operation {
+            summary: Some("Creates a feline.".into()),
+            ..Operation::default()
+        };
 
-        let diff = OperationDiff::from_operations(&base_operation, &head_operation);
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
         let summary_diff = diff.summary.unwrap();
 
         assert_eq!(summary_diff.from, None);
@@ -109,12 +208,21 @@ mod tests {
 
     #[test]
     fn summary_changed_from_some_to_some() {
-        let mut base_operation = Operation::default();
-        base_operation.summary = Some("Creates a cat.".into());
-        let mut head_operation = Operation::default();
-        head_operation.summary = Some("Creates a feline.".into());
+        let base_operation = Operation {
+            summary: Some("Creates a cat.".into()),
+            ..Operation::default()
+        };
+        let head_operation = Operation {
+            summary: Some("Creates a feline.".into()),
+            ..Operation::default()
+        };
 
-        let diff = OperationDiff::from_operations(&base_operation, &head_operation);
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
         let summary_diff = diff.summary.unwrap();
 
         assert_eq!(summary_diff.from, Some("Creates a cat.".into()));
@@ -124,10 +232,17 @@ mod tests {
     #[test]
     fn description_changed_from_none_to_some() {
         let base_operation = Operation::default();
-        let mut head_operation = Operation::default();
-        head_operation.description = Some("Creates a feline.".into());
+        let head_operation = Operation {
+            description: Some("Creates a feline.".into()),
+            ..Operation::default()
+        };
 
-        let diff = OperationDiff::from_operations(&base_operation, &head_operation);
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
         let description_diff = diff.description.unwrap();
 
         assert_eq!(description_diff.from, None);
@@ -136,12 +251,21 @@ mod tests {
 
     #[test]
     fn description_changed_from_some_to_some() {
-        let mut base_operation = Operation::default();
-        base_operation.description = Some("Creates a cat.".into());
-        let mut head_operation = Operation::default();
-        head_operation.description = Some("Creates a feline.".into());
+        let base_operation = Operation {
+            description: Some("Creates a cat.".into()),
+            ..Operation::default()
+        };
+        let head_operation = Operation {
+            description: Some("Creates a feline.".into()),
+            ..Operation::default()
+        };
 
-        let diff = OperationDiff::from_operations(&base_operation, &head_operation);
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
         let description_diff = diff.description.unwrap();
 
         assert_eq!(description_diff.from, Some("Creates a cat.".into()));
@@ -158,9 +282,62 @@ mod tests {
         head_operation.tags.push("Cats".into());
         head_operation.tags.push("Fish".into());
 
-        let diff = OperationDiff::from_operations(&base_operation, &head_operation);
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
 
         assert_eq!(vec!["Fish"], diff.tags.added);
         assert_eq!(vec!["Dogs"], diff.tags.removed);
     }
+
+    #[test]
+    fn extension_changed() {
+        let mut base_operation = Operation::default();
+        base_operation
+            .extensions
+            .insert("x-rate-limit".into(), serde_json::json!({ "rpm": 60 }));
+        let mut head_operation = Operation::default();
+        head_operation
+            .extensions
+            .insert("x-rate-limit".into(), serde_json::json!({ "rpm": 30 }));
+
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
+        let change = &diff.extensions.unwrap().changed["x-rate-limit"].changes[0];
+
+        assert_eq!("/rpm", change.pointer);
+        assert_eq!(Some(serde_json::json!(30)), change.to);
+    }
+
+    #[test]
+    fn deprecated_and_servers_changed() {
+        let base_operation: Operation =
+            serde_yaml::from_str("responses: {}\nservers:\n  - url: https://api.example.com\n")
+                .expect("Failed to parse operation");
+        let head_operation: Operation = serde_yaml::from_str(
+            "responses: {}\ndeprecated: true\nexternalDocs:\n  url: https://example.com/migration\n",
+        )
+        .expect("Failed to parse operation");
+
+        let diff = OperationDiff::from_operations(
+            &base_operation,
+            &head_operation,
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        assert!(diff.deprecated.unwrap().to);
+        assert!(diff.external_docs.unwrap().added.is_some());
+        assert_eq!(
+            "https://api.example.com",
+            diff.servers.unwrap().removed[0].url
+        );
+    }
 }
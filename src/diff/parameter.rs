@@ -1,52 +1,260 @@
-use super::common::{BooleanDiff, StringDiff};
+use super::common::{BooleanDiff, Direction, OptionalStringDiff, OptionalValueDiff, StringDiff};
+use super::compatibility::Compatibility;
+use super::content::ContentDiff;
+use super::examples::{ExampleSet, ExamplesDiff, InvalidExample};
+use super::extensions::ExtensionsDiff;
+use super::resolver::Resolver;
 use super::schema::SchemaDiff;
-use crate::openapi::{Parameter, ParameterData, ParameterSchemaOrContent};
+use crate::openapi::{
+    CookieStyle, HeaderStyle, Parameter, ParameterData, ParameterSchemaOrContent, PathStyle,
+    QueryStyle, ReferenceOr,
+};
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
 pub struct ParameterDiff {
-    required: Option<BooleanDiff>,
+    pub required: Option<BooleanDiff>,
     #[serde(rename = "in")]
-    in_change: Option<StringDiff>,
-    schema: Option<SchemaDiff>,
+    pub in_change: Option<StringDiff>,
+    pub schema: Option<SchemaDiff>,
+    pub content: Option<ContentDiff>,
+    /// Set when a parameter switches between the `schema` and `content`
+    /// forms, which changes how its value is serialized.
+    pub serialization_changed: Option<StringDiff>,
+    pub style: Option<StringDiff>,
+    pub explode: Option<BooleanDiff>,
+    pub allow_reserved: Option<BooleanDiff>,
+    pub allow_empty_value: Option<AllowEmptyValueDiff>,
+    /// Whether clients must send this parameter differently, as opposed
+    /// to changes that only affect the documentation or validation.
+    pub wire_format_changed: bool,
+    pub deprecated: Option<BooleanDiff>,
+    pub description: Option<OptionalStringDiff>,
+    pub example: Option<OptionalValueDiff>,
+    pub examples: Option<ExamplesDiff>,
+    pub extensions: Option<ExtensionsDiff>,
+    pub invalid_examples: Vec<InvalidExample>,
 }
 
 impl ParameterDiff {
     pub fn has_changes(&self) -> bool {
-        self.required.is_some() || self.in_change.is_some() || self.schema.is_some()
+        self.required.is_some()
+            || self.in_change.is_some()
+            || self.schema.is_some()
+            || self.content.is_some()
+            || self.serialization_changed.is_some()
+            || self.style.is_some()
+            || self.explode.is_some()
+            || self.allow_reserved.is_some()
+            || self.allow_empty_value.is_some()
+            || self.deprecated.is_some()
+            || self.description.is_some()
+            || self.example.is_some()
+            || self.examples.is_some()
+            || self.extensions.is_some()
+            || !self.invalid_examples.is_empty()
     }
 
-    pub fn from_params(base: &Parameter, head: &Parameter) -> Self {
+    pub fn from_params(
+        base: &Parameter,
+        head: &Parameter,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
         let base_parameter_data = Self::parameter_data(base);
         let head_parameter_data = Self::parameter_data(head);
 
-        let schema_diff =
-            if let ParameterSchemaOrContent::Schema(base_schema) = &base_parameter_data.format {
-                if let ParameterSchemaOrContent::Schema(head_schema) = &head_parameter_data.format {
-                    let diff = SchemaDiff::from_schemas(&base_schema, &head_schema);
-
-                    if diff.has_changes() {
-                        Some(diff)
-                    } else {
-                        None
-                    }
-                } else {
-                    panic!("Parameter content is not supported yet")
+        let mut schema_diff = None;
+        let mut content_diff = None;
+        let mut serialization_diff = None;
+
+        match (&base_parameter_data.format, &head_parameter_data.format) {
+            (
+                ParameterSchemaOrContent::Schema(base_schema),
+                ParameterSchemaOrContent::Schema(head_schema),
+            ) => {
+                let diff = SchemaDiff::from_schemas(base_schema, head_schema, direction, resolver);
+
+                if diff.has_changes() {
+                    schema_diff = Some(diff);
                 }
-            } else {
-                panic!("Parameter content is not supported yet")
-            };
+            }
+            (
+                ParameterSchemaOrContent::Content(base_content),
+                ParameterSchemaOrContent::Content(head_content),
+            ) => {
+                let diff =
+                    ContentDiff::from_content(base_content, head_content, direction, resolver);
+
+                if diff.has_changes() {
+                    content_diff = Some(diff);
+                }
+            }
+            (base_format, head_format) => {
+                serialization_diff = StringDiff::from_strings(
+                    Self::serialization(base_format),
+                    Self::serialization(head_format),
+                );
+            }
+        }
+
+        let in_diff =
+            StringDiff::from_strings(Self::parameter_type(base), Self::parameter_type(head));
+        let style_diff = StringDiff::from_strings(Self::style(base), Self::style(head));
+        let allow_reserved_diff =
+            BooleanDiff::from_bools(Self::allow_reserved(base), Self::allow_reserved(head));
+
+        // `explode` has no effect on primitive values, so toggling it only
+        // changes the wire format of arrays and objects.
+        let explode_diff = BooleanDiff::from_bools(Self::explode(base), Self::explode(head));
+        let explode_matters =
+            !Self::is_primitive(base_parameter_data) || !Self::is_primitive(head_parameter_data);
+
+        let wire_format_changed = in_diff.is_some()
+            || serialization_diff.is_some()
+            || style_diff.is_some()
+            || (explode_diff.is_some() && explode_matters)
+            || allow_reserved_diff.is_some();
+
+        let examples_diff = ExamplesDiff::from_examples(
+            &base_parameter_data.examples,
+            &head_parameter_data.examples,
+            resolver,
+        );
+        let extensions_diff = ExtensionsDiff::from_extensions(
+            &base_parameter_data.extensions,
+            &head_parameter_data.extensions,
+        );
+        let invalid_examples = InvalidExample::from_example_sets(
+            &ExampleSet::from_format(
+                &base_parameter_data.format,
+                &base_parameter_data.example,
+                &base_parameter_data.examples,
+            ),
+            &ExampleSet::from_format(
+                &head_parameter_data.format,
+                &head_parameter_data.example,
+                &head_parameter_data.examples,
+            ),
+            direction,
+            resolver,
+        );
 
         Self {
             required: BooleanDiff::from_bools(
                 base_parameter_data.required,
                 head_parameter_data.required,
             ),
-            in_change: StringDiff::from_strings(
-                Self::parameter_type(base),
-                Self::parameter_type(head),
-            ),
+            in_change: in_diff,
             schema: schema_diff,
+            content: content_diff,
+            serialization_changed: serialization_diff,
+            style: style_diff,
+            explode: explode_diff,
+            allow_reserved: allow_reserved_diff,
+            allow_empty_value: AllowEmptyValueDiff::from_params(base, head, direction),
+            wire_format_changed,
+            deprecated: BooleanDiff::from_bools(
+                base_parameter_data.deprecated.unwrap_or(false),
+                head_parameter_data.deprecated.unwrap_or(false),
+            ),
+            description: OptionalStringDiff::from_strings(
+                &base_parameter_data.description,
+                &head_parameter_data.description,
+            ),
+            example: OptionalValueDiff::from_values(
+                &base_parameter_data.example,
+                &head_parameter_data.example,
+            ),
+            examples: if examples_diff.has_changes() {
+                Some(examples_diff)
+            } else {
+                None
+            },
+            extensions: if extensions_diff.has_changes() {
+                Some(extensions_diff)
+            } else {
+                None
+            },
+            invalid_examples,
+        }
+    }
+
+    /// The serialization style, applying the default for the parameter's location.
+    pub fn style(param: &Parameter) -> String {
+        let style = match param {
+            Parameter::Query { style, .. } => Self::query_style(style),
+            Parameter::Header { style, .. } => match style {
+                HeaderStyle::Simple => "simple",
+            },
+            Parameter::Path { style, .. } => match style {
+                PathStyle::Matrix => "matrix",
+                PathStyle::Label => "label",
+                PathStyle::Simple => "simple",
+            },
+            Parameter::Cookie { style, .. } => match style {
+                CookieStyle::Form => "form",
+            },
+        };
+
+        String::from(style)
+    }
+
+    /// The name of a query style, which is also used by form encodings.
+    pub fn query_style(style: &QueryStyle) -> &'static str {
+        match style {
+            QueryStyle::Form => "form",
+            QueryStyle::SpaceDelimited => "spaceDelimited",
+            QueryStyle::PipeDelimited => "pipeDelimited",
+            QueryStyle::DeepObject => "deepObject",
+        }
+    }
+
+    /// When `explode` is missing, it defaults to true for the `form`
+    /// style and to false for every other style.
+    pub fn explode(param: &Parameter) -> bool {
+        match Self::parameter_data(param).explode {
+            Some(explode) => explode,
+            None => Self::style(param) == "form",
+        }
+    }
+
+    /// Only applies to query parameters, defaults to false.
+    pub fn allow_reserved(param: &Parameter) -> bool {
+        match param {
+            Parameter::Query { allow_reserved, .. } => allow_reserved.unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// Only applies to query parameters, defaults to false.
+    pub fn allow_empty_value(param: &Parameter) -> bool {
+        match param {
+            Parameter::Query {
+                allow_empty_value, ..
+            } => allow_empty_value.unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    fn is_primitive(parameter_data: &ParameterData) -> bool {
+        match &parameter_data.format {
+            ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) => {
+                let types = schema.types();
+                !types.is_empty()
+                    && !types.contains(&"array")
+                    && !types.contains(&"object")
+                    && schema.properties.is_none()
+                    && schema.items.is_none()
+            }
+            _ => false,
+        }
+    }
+
+    fn serialization(format: &ParameterSchemaOrContent) -> String {
+        match format {
+            ParameterSchemaOrContent::Schema(_) => String::from("schema"),
+            ParameterSchemaOrContent::Content(_) => String::from("content"),
         }
     }
 
@@ -96,3 +304,230 @@ impl ParameterDiff {
         }
     }
 }
+
+/// A change to `allowEmptyValue`, which only affects whether an empty
+/// value is accepted, not how the parameter is serialized.
+#[derive(Debug, Serialize)]
+pub struct AllowEmptyValueDiff {
+    pub from: bool,
+    pub to: bool,
+    pub compatibility: Compatibility,
+    pub breaking: bool,
+}
+
+impl AllowEmptyValueDiff {
+    pub fn from_params(base: &Parameter, head: &Parameter, direction: Direction) -> Option<Self> {
+        let from = ParameterDiff::allow_empty_value(base);
+        let to = ParameterDiff::allow_empty_value(head);
+
+        if from == to {
+            return None;
+        }
+
+        let compatibility = if to {
+            Compatibility::Widened
+        } else {
+            Compatibility::Narrowed
+        };
+
+        Some(Self {
+            from,
+            to,
+            compatibility,
+            breaking: compatibility.is_breaking(direction),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openapi::{Content, MediaType, Schema};
+    use std::collections::BTreeMap;
+
+    fn query_param(format: ParameterSchemaOrContent) -> Parameter {
+        query_param_with_style(format, QueryStyle::Form, None)
+    }
+
+    fn query_param_with_style(
+        format: ParameterSchemaOrContent,
+        style: QueryStyle,
+        explode: Option<bool>,
+    ) -> Parameter {
+        Parameter::Query {
+            parameter_data: ParameterData {
+                name: String::from("filter"),
+                description: None,
+                deprecated: None,
+                example: None,
+                examples: BTreeMap::default(),
+                extensions: BTreeMap::default(),
+                format,
+                required: false,
+                explode,
+            },
+            allow_empty_value: None,
+            allow_reserved: None,
+            style,
+        }
+    }
+
+    fn array_schema() -> ParameterSchemaOrContent {
+        let schema = Schema {
+            schema_type: Some("array".into()),
+            ..Schema::default()
+        };
+        ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema))
+    }
+
+    fn string_schema() -> ParameterSchemaOrContent {
+        let schema = Schema {
+            schema_type: Some("string".into()),
+            ..Schema::default()
+        };
+        ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema))
+    }
+
+    fn json_content(schema_type: &str) -> ParameterSchemaOrContent {
+        let schema = Schema {
+            schema_type: Some(schema_type.into()),
+            ..Schema::default()
+        };
+        let mut content = Content::new();
+        content.insert(
+            String::from("application/json"),
+            MediaType {
+                schema: Some(ReferenceOr::Item(schema)),
+                ..MediaType::default()
+            },
+        );
+        ParameterSchemaOrContent::Content(content)
+    }
+
+    #[test]
+    fn content_changed() {
+        let base = query_param(json_content("object"));
+        let head = query_param(json_content("array"));
+
+        let diff =
+            ParameterDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(diff.has_changes());
+        assert!(diff.schema.is_none());
+        assert_eq!(1, diff.content.unwrap().changed.len());
+    }
+
+    #[test]
+    fn schema_to_content() {
+        let base = query_param(ParameterSchemaOrContent::Schema(ReferenceOr::Item(
+            Schema::default(),
+        )));
+        let head = query_param(json_content("object"));
+
+        let diff =
+            ParameterDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+        let serialization_diff = diff.serialization_changed.unwrap();
+
+        assert_eq!("schema", serialization_diff.from);
+        assert_eq!("content", serialization_diff.to);
+    }
+
+    #[test]
+    fn explicit_defaults_are_not_changes() {
+        let base = query_param_with_style(array_schema(), QueryStyle::Form, None);
+        let head = query_param_with_style(array_schema(), QueryStyle::Form, Some(true));
+
+        let diff =
+            ParameterDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn style_changed() {
+        let base = query_param_with_style(array_schema(), QueryStyle::Form, None);
+        let head = query_param_with_style(array_schema(), QueryStyle::PipeDelimited, None);
+
+        let diff =
+            ParameterDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+        let style_diff = diff.style.as_ref().unwrap();
+
+        assert_eq!("form", style_diff.from);
+        assert_eq!("pipeDelimited", style_diff.to);
+        // The default explode value follows the style.
+        assert!(diff.explode.is_some());
+        assert!(diff.wire_format_changed);
+    }
+
+    #[test]
+    fn explode_on_primitive_does_not_change_wire_format() {
+        let base = query_param_with_style(string_schema(), QueryStyle::Form, None);
+        let head = query_param_with_style(string_schema(), QueryStyle::Form, Some(false));
+
+        let diff =
+            ParameterDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(diff.explode.is_some());
+        assert!(!diff.wire_format_changed);
+    }
+
+    #[test]
+    fn parameter_deprecated() {
+        let base = query_param(string_schema());
+        let mut head = query_param(string_schema());
+        if let Parameter::Query { parameter_data, .. } = &mut head {
+            parameter_data.deprecated = Some(true);
+            parameter_data.description = Some(String::from("Use `search` instead."));
+        }
+
+        let diff =
+            ParameterDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(diff.deprecated.unwrap().to);
+        assert!(diff.description.is_some());
+        assert!(!diff.wire_format_changed);
+    }
+
+    #[test]
+    fn allow_empty_value_changed() {
+        let allow_empty_value = |allow_empty_value| match query_param(string_schema()) {
+            Parameter::Query {
+                parameter_data,
+                allow_reserved,
+                style,
+                ..
+            } => Parameter::Query {
+                parameter_data,
+                allow_empty_value: Some(allow_empty_value),
+                allow_reserved,
+                style,
+            },
+            _ => unreachable!(),
+        };
+
+        let diff = ParameterDiff::from_params(
+            &allow_empty_value(true),
+            &allow_empty_value(false),
+            Direction::Request,
+            &Resolver::default(),
+        );
+        let allow_empty_value_diff = diff.allow_empty_value.as_ref().unwrap();
+
+        assert_eq!(
+            Compatibility::Narrowed,
+            allow_empty_value_diff.compatibility
+        );
+        assert!(allow_empty_value_diff.breaking);
+        assert!(!diff.wire_format_changed);
+
+        let diff = ParameterDiff::from_params(
+            &allow_empty_value(false),
+            &allow_empty_value(true),
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        assert!(!diff.allow_empty_value.unwrap().breaking);
+        assert!(!diff.wire_format_changed);
+    }
+}
@@ -1,4 +1,6 @@
+use super::common::Direction;
 use super::parameter::ParameterDiff;
+use super::resolver::Resolver;
 use crate::openapi::{Parameter, ReferenceOr};
 use serde::Serialize;
 use std::collections::HashMap;
@@ -38,75 +40,67 @@ impl ParametersDiff {
         }
     }
 
+    /// A unique parameter is defined by a combination of a name and location,
+    /// written as `{in}.{name}` like in Link Objects. Header names are case
+    /// insensitive, so they are lowercased.
+    pub fn param_key(param: &Parameter) -> String {
+        let location = ParameterDiff::parameter_type(param);
+        let name = Self::param_name(param);
+
+        match param {
+            Parameter::Header { .. } => format!("{}.{}", location, name.to_lowercase()),
+            _ => format!("{}.{}", location, name),
+        }
+    }
+
+    /// Header parameters named `Accept`, `Content-Type` or `Authorization`
+    /// are ignored by the specification.
+    pub fn is_ignored(param: &Parameter) -> bool {
+        match param {
+            Parameter::Header { parameter_data, .. } => {
+                let name = parameter_data.name.to_lowercase();
+                name == "accept" || name == "content-type" || name == "authorization"
+            }
+            _ => false,
+        }
+    }
+
+    /// Parameters are matched by their resolved location and name, so
+    /// inlining a parameter or extracting it to a component is not a change
+    /// in itself. References that cannot be resolved are matched by their
+    /// `$ref` instead.
     pub fn from_params(
-        base: &Vec<ReferenceOr<Parameter>>,
-        head: &Vec<ReferenceOr<Parameter>>,
+        base: &[ReferenceOr<Parameter>],
+        head: &[ReferenceOr<Parameter>],
+        direction: Direction,
+        resolver: &Resolver,
     ) -> Self {
         let mut added = vec![];
         let mut removed = vec![];
         let mut changed: HashMap<String, ParameterDiff> = HashMap::default();
 
-        for ref_or_param in base {
-            match ref_or_param {
-                ReferenceOr::Reference { reference } => {
-                    let ref_match = head.iter().find(|p| match p {
-                        ReferenceOr::Reference { reference: r } => r == reference,
-                        ReferenceOr::Item(_) => false,
-                    });
-
-                    match ref_match {
-                        Some(_param) => {
-                            panic!("Comparing changed parameter refs is not implemented yet");
-                        }
-                        None => removed.push(ref_or_param.clone()),
-                    }
-                }
-                ReferenceOr::Item(param) => {
-                    let param_match = head.iter().find(|p| match p {
-                        ReferenceOr::Reference { reference: _ } => false,
-                        ReferenceOr::Item(p) => Self::param_name(p) == Self::param_name(param),
-                    });
-
-                    match param_match {
-                        Some(head_param) => {
-                            if let ReferenceOr::Item(head_param) = head_param {
-                                let diff = ParameterDiff::from_params(param, head_param);
-
-                                if diff.has_changes() {
-                                    changed.insert(Self::param_name(param), diff);
-                                }
-                            }
+        let base = Self::keyed_params(base, |p| resolver.base_parameter(p));
+        let head = Self::keyed_params(head, |p| resolver.head_parameter(p));
+
+        for (key, ref_or_param, param) in &base {
+            match head.iter().find(|(head_key, _, _)| head_key == key) {
+                Some((_, _, head_param)) => {
+                    if let (Some(param), Some(head_param)) = (param, head_param) {
+                        let diff =
+                            ParameterDiff::from_params(param, head_param, direction, resolver);
+
+                        if diff.has_changes() {
+                            changed.insert(key.clone(), diff);
                         }
-                        None => removed.push(ref_or_param.clone()),
                     }
                 }
+                None => removed.push((*ref_or_param).clone()),
             }
         }
 
-        for ref_or_param in head {
-            match ref_or_param {
-                ReferenceOr::Reference { reference } => {
-                    let ref_match = base.iter().find(|p| match p {
-                        ReferenceOr::Reference { reference: r } => r == reference,
-                        ReferenceOr::Item(_) => false,
-                    });
-
-                    match ref_match {
-                        Some(_param) => {}
-                        None => added.push(ref_or_param.clone()),
-                    }
-                }
-                ReferenceOr::Item(param) => {
-                    let param_match = base.iter().find(|p| match p {
-                        ReferenceOr::Reference { reference: _ } => false,
-                        ReferenceOr::Item(p) => Self::param_name(p) == Self::param_name(param),
-                    });
-
-                    match param_match {
-                        Some(_param) => {}
-                        None => added.push(ref_or_param.clone()),
-                    }
-                }
+        for (key, ref_or_param, _) in &head {
+            if !base.iter().any(|(base_key, _, _)| base_key == key) {
+                added.push((*ref_or_param).clone());
             }
         }
 
@@ -116,13 +110,36 @@ impl ParametersDiff {
             changed,
         }
     }
+
+    /// Pairs each parameter with its resolved definition and the key it is
+    /// matched by, leaving out ignored parameters.
+    fn keyed_params(
+        params: &[ReferenceOr<Parameter>],
+        resolve: impl Fn(&ReferenceOr<Parameter>) -> Option<Parameter>,
+    ) -> Vec<(String, &ReferenceOr<Parameter>, Option<Parameter>)> {
+        params
+            .iter()
+            .map(|ref_or_param| (ref_or_param, resolve(ref_or_param)))
+            .filter(|(_, param)| !matches!(param, Some(p) if Self::is_ignored(p)))
+            .map(|(ref_or_param, param)| {
+                let key = match (&param, ref_or_param) {
+                    (Some(param), _) => Self::param_key(param),
+                    (None, ReferenceOr::Reference { reference }) => reference.clone(),
+                    (None, ReferenceOr::Item(param)) => Self::param_key(param),
+                };
+                (key, ref_or_param, param)
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::openapi::{Operation, ParameterData, ParameterSchemaOrContent, QueryStyle, Schema};
+    use crate::openapi::{
+        Components, Operation, ParameterData, ParameterSchemaOrContent, QueryStyle, Schema,
+    };
     use std::collections::BTreeMap;
 
     #[test]
@@ -148,26 +165,25 @@ mod tests {
 
         head_operation.parameters.push(ReferenceOr::Item(my_param));
 
-        let diff =
-            ParametersDiff::from_params(&base_operation.parameters, &head_operation.parameters);
+        let diff = ParametersDiff::from_params(
+            &base_operation.parameters,
+            &head_operation.parameters,
+            Direction::Request,
+            &Resolver::default(),
+        );
 
         assert_eq!(diff.added.len(), 1);
         assert_eq!(diff.removed.len(), 0);
 
         match diff.added.first().unwrap() {
-            ReferenceOr::Item(p) => match p {
-                Parameter::Query {
-                    parameter_data,
-                    allow_reserved: _,
-                    style: _,
-                    allow_empty_value: _,
-                } => {
-                    assert_eq!(parameter_data.name, "myParam")
-                }
-                _ => {
-                    panic!("Unexpected parameter type")
-                }
-            },
+            ReferenceOr::Item(Parameter::Query {
+                parameter_data,
+                allow_reserved: _,
+                style: _,
+                allow_empty_value: _,
+            }) => {
+                assert_eq!(parameter_data.name, "myParam")
+            }
             _ => {
                 panic!("Unexpected parameter type")
             }
@@ -198,29 +214,114 @@ mod tests {
 
         base_operation.parameters.push(ReferenceOr::Item(my_param));
 
-        let diff =
-            ParametersDiff::from_params(&base_operation.parameters, &head_operation.parameters);
+        let diff = ParametersDiff::from_params(
+            &base_operation.parameters,
+            &head_operation.parameters,
+            Direction::Request,
+            &Resolver::default(),
+        );
 
         assert_eq!(diff.added.len(), 0);
         assert_eq!(diff.removed.len(), 1);
 
         match diff.removed.first().unwrap() {
-            ReferenceOr::Item(p) => match p {
-                Parameter::Query {
-                    parameter_data,
-                    allow_reserved: _,
-                    style: _,
-                    allow_empty_value: _,
-                } => {
-                    assert_eq!(parameter_data.name, "myParam")
-                }
-                _ => {
-                    panic!("Unexpected parameter type")
-                }
-            },
+            ReferenceOr::Item(Parameter::Query {
+                parameter_data,
+                allow_reserved: _,
+                style: _,
+                allow_empty_value: _,
+            }) => {
+                assert_eq!(parameter_data.name, "myParam")
+            }
             _ => {
                 panic!("Unexpected parameter type")
             }
         }
     }
+
+    fn param(location: &str, name: &str) -> ReferenceOr<Parameter> {
+        let parameter: Parameter = serde_yaml::from_str(&format!(
+            "name: {}\nin: {}\nrequired: true\nschema:\n  type: string\n",
+            name, location
+        ))
+        .expect("Failed to parse parameter");
+        ReferenceOr::Item(parameter)
+    }
+
+    #[test]
+    fn same_name_different_location() {
+        let base = vec![param("query", "id"), param("path", "id")];
+        let head = vec![param("path", "id")];
+
+        let diff =
+            ParametersDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+
+        assert_eq!(diff.added.len(), 0);
+        assert_eq!(diff.removed.len(), 1);
+        assert!(diff.changed.is_empty());
+        assert_eq!(
+            "query.id",
+            ParametersDiff::param_key(match &diff.removed[0] {
+                ReferenceOr::Item(p) => p,
+                _ => panic!("Unexpected parameter type"),
+            })
+        );
+    }
+
+    #[test]
+    fn header_names_are_case_insensitive() {
+        let base = vec![param("header", "X-Request-Id")];
+        let head = vec![param("header", "x-request-id")];
+
+        let diff =
+            ParametersDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn ignored_headers() {
+        let base = vec![];
+        let head = vec![param("header", "Authorization"), param("header", "accept")];
+
+        let diff =
+            ParametersDiff::from_params(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn inlined_referenced_parameter() {
+        let components: Components = serde_yaml::from_str(
+            "parameters:\n  RequestId:\n    name: X-Request-Id\n    in: header\n    required: true\n    schema:\n      type: string\n",
+        )
+        .expect("Failed to parse components");
+        let resolver = Resolver::new(Some(&components), None);
+
+        let base = vec![ReferenceOr::ref_("#/components/parameters/RequestId")];
+        let head = vec![param("header", "x-request-id")];
+
+        let diff = ParametersDiff::from_params(&base, &head, Direction::Request, &resolver);
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn changed_referenced_parameter() {
+        let base_components: Components = serde_yaml::from_str(
+            "parameters:\n  Page:\n    name: page\n    in: query\n    schema:\n      type: integer\n",
+        )
+        .expect("Failed to parse components");
+        let head_components: Components = serde_yaml::from_str(
+            "parameters:\n  Page:\n    name: page\n    in: query\n    required: true\n    schema:\n      type: integer\n",
+        )
+        .expect("Failed to parse components");
+        let resolver = Resolver::new(Some(&base_components), Some(&head_components));
+
+        let params = vec![ReferenceOr::ref_("#/components/parameters/Page")];
+
+        let diff = ParametersDiff::from_params(&params, &params, Direction::Request, &resolver);
+
+        assert!(diff.changed.contains_key("query.page"));
+    }
 }
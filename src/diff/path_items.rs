@@ -1,4 +1,7 @@
+use super::common::Direction;
+use super::extensions::ExtensionsDiff;
 use super::operations::OperationDiff;
+use super::resolver::Resolver;
 use super::DiffError;
 use crate::openapi::{Operation, PathItem, ReferenceOr};
 use serde::Serialize;
@@ -13,6 +16,7 @@ pub struct PathItemDiff {
     pub operations_added: Vec<OperationMethod>,
     pub operations_removed: Vec<OperationMethod>,
     pub operations_changed: HashMap<String, OperationDiff>,
+    pub extensions: Option<ExtensionsDiff>,
 }
 
 impl PathItemDiff {
@@ -20,139 +24,93 @@ impl PathItemDiff {
         !self.operations_removed.is_empty()
             || !self.operations_added.is_empty()
             || !self.operations_changed.is_empty()
+            || self.extensions.is_some()
     }
 
     /// Diffs two sets of OpenAPI paths
     pub fn from_path_items(
         base: &ReferenceOr<PathItem>,
         head: &ReferenceOr<PathItem>,
+        direction: Direction,
+        resolver: &Resolver,
     ) -> Result<Self, DiffError> {
-        let mut operations_added = vec![];
-        let mut operations_removed = vec![];
-        let mut operations_changed: HashMap<String, OperationDiff> = HashMap::default();
-
-        let base_path_item = match &base {
-            ReferenceOr::Item(pi) => pi,
-            ReferenceOr::Reference { reference } => {
-                return Err(DiffError::UnsupportedFeature(format!(
-                    "Cannot diff reference {} path references is not implemented yet",
-                    reference
-                )));
-            }
-        };
+        let base_path_item = resolver
+            .base_path_item(base)
+            .ok_or_else(|| Self::unsupported_reference(base))?;
+        let head_path_item = resolver
+            .head_path_item(head)
+            .ok_or_else(|| Self::unsupported_reference(head))?;
+
+        Ok(Self::from_items(
+            base_path_item,
+            head_path_item,
+            direction,
+            resolver,
+        ))
+    }
 
-        let head_path_item = match &head {
-            ReferenceOr::Item(pi) => pi,
-            ReferenceOr::Reference { reference } => {
-                return Err(DiffError::UnsupportedFeature(format!(
-                    "Cannot diff reference {} path references is not implemented yet",
-                    reference
-                )));
-            }
+    fn unsupported_reference(path_item: &ReferenceOr<PathItem>) -> DiffError {
+        let reference = match path_item {
+            ReferenceOr::Reference { reference } => reference.as_str(),
+            ReferenceOr::Item(_) => "",
         };
 
-        match &base_path_item.get {
-            Some(op) => match &head_path_item.get {
-                Some(head_op) => {
-                    let diff = OperationDiff::from_operations(op, head_op);
-
-                    if diff.has_changes() {
-                        operations_changed.insert(String::from("get"), diff);
-                    }
-                }
-                None => {
-                    // Removed
-                    operations_removed.push((String::from("get"), op.clone()));
-                }
-            },
-            None => match &head_path_item.get {
-                Some(head_op) => {
-                    // Added
-                    operations_added.push((String::from("get"), head_op.clone()));
-                }
-                None => {}
-            },
-        }
-
-        match &base_path_item.post {
-            Some(op) => match &head_path_item.post {
-                Some(head_op) => {
-                    let diff = OperationDiff::from_operations(op, head_op);
+        DiffError::UnsupportedFeature(format!(
+            "Cannot diff reference {} path references is not implemented yet",
+            reference
+        ))
+    }
 
-                    if diff.has_changes() {
-                        operations_changed.insert(String::from("post"), diff);
-                    }
-                }
-                None => {
-                    // Removed
-                    operations_removed.push((String::from("post"), op.clone()));
-                }
-            },
-            None => match &head_path_item.post {
-                Some(head_op) => {
-                    // Added
-                    operations_added.push((String::from("post"), head_op.clone()));
-                }
-                None => {}
-            },
-        }
+    /// Diffs the operations of two path items. `direction` is the direction
+    /// of their requests, which is `Direction::Response` for callbacks and
+    /// webhooks.
+    pub fn from_items(
+        base_path_item: &PathItem,
+        head_path_item: &PathItem,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut operations_added = vec![];
+        let mut operations_removed = vec![];
+        let mut operations_changed: HashMap<String, OperationDiff> = HashMap::default();
 
-        match &base_path_item.put {
-            Some(op) => match &head_path_item.put {
-                Some(head_op) => {
-                    let diff = OperationDiff::from_operations(op, head_op);
+        for (method, operation) in base_path_item.operations() {
+            match head_path_item.operation(method) {
+                Some(head_operation) => {
+                    let diff = OperationDiff::from_operations(
+                        operation,
+                        head_operation,
+                        direction,
+                        resolver,
+                    );
 
                     if diff.has_changes() {
-                        operations_changed.insert(String::from("put"), diff);
+                        operations_changed.insert(method.to_string(), diff);
                     }
                 }
-                None => {
-                    // Removed
-                    operations_removed.push((String::from("put"), op.clone()));
-                }
-            },
-            None => match &head_path_item.put {
-                Some(head_op) => {
-                    // Added
-                    operations_added.push((String::from("put"), head_op.clone()));
-                }
-                None => {}
-            },
+                None => operations_removed.push((method.to_string(), operation.clone())),
+            }
         }
 
-        match &base_path_item.patch {
-            Some(op) => match &head_path_item.patch {
-                Some(head_op) => {
-                    let diff = OperationDiff::from_operations(op, head_op);
-
-                    if diff.has_changes() {
-                        operations_changed.insert(String::from("patch"), diff);
-                    }
-                }
-                None => {
-                    // Removed
-                    operations_removed.push((String::from("patch"), op.clone()));
-                }
-            },
-            None => match &head_path_item.patch {
-                Some(head_op) => {
-                    // Added
-                    operations_added.push((String::from("patch"), head_op.clone()));
-                }
-                None => {}
-            },
+        for (method, operation) in head_path_item.operations() {
+            if base_path_item.operation(method).is_none() {
+                operations_added.push((method.to_string(), operation.clone()));
+            }
         }
 
+        let extensions_diff =
+            ExtensionsDiff::from_extensions(&base_path_item.extensions, &head_path_item.extensions);
 
-
-
-
-
-        Ok(Self {
+        Self {
             operations_added,
             operations_removed,
             operations_changed,
-        })
+            extensions: if extensions_diff.has_changes() {
+                Some(extensions_diff)
+            } else {
+                None
+            },
+        }
     }
 }
 
@@ -163,15 +121,19 @@ mod tests {
     #[test]
     fn operations_removed() {
         let mut path_item = PathItem::default();
-        let mut operation = Operation::default();
-        operation.operation_id = Some("cats/get".into());
+        let operation = Operation {
+            operation_id: Some("cats/get".into()),
+            ..Operation::default()
+        };
         path_item.get = Some(operation);
 
         let base = ReferenceOr::Item(path_item);
 
         let head = ReferenceOr::Item(PathItem::default());
 
-        let diff = PathItemDiff::from_path_items(&base, &head).expect("Failed to diff paths");
+        let diff =
+            PathItemDiff::from_path_items(&base, &head, Direction::Request, &Resolver::default())
+                .expect("Failed to diff paths");
 
         assert_eq!(diff.operations_removed.len(), 1);
         let removed_op = diff.operations_removed.first().unwrap();
@@ -183,14 +145,18 @@ mod tests {
     #[test]
     fn operations_added() {
         let mut path_item = PathItem::default();
-        let mut operation = Operation::default();
-        operation.operation_id = Some("cats/get".into());
+        let operation = Operation {
+            operation_id: Some("cats/get".into()),
+            ..Operation::default()
+        };
         path_item.get = Some(operation);
 
         let base = ReferenceOr::Item(PathItem::default());
         let head = ReferenceOr::Item(path_item);
 
-        let diff = PathItemDiff::from_path_items(&base, &head).expect("Failed to diff paths");
+        let diff =
+            PathItemDiff::from_path_items(&base, &head, Direction::Request, &Resolver::default())
+                .expect("Failed to diff paths");
 
         assert_eq!(diff.operations_removed.len(), 0);
         assert_eq!(diff.operations_added.len(), 1);
@@ -199,4 +165,31 @@ mod tests {
         assert_eq!(added_op.0, "get");
         assert_eq!(added_op.1.operation_id, Some("cats/get".into()));
     }
+
+    #[test]
+    fn all_methods_are_diffed() {
+        let operation = Operation {
+            operation_id: Some("cats/delete".into()),
+            ..Operation::default()
+        };
+        let base = PathItem {
+            delete: Some(operation.clone()),
+            trace: Some(Operation::default()),
+            ..PathItem::default()
+        };
+        let head = PathItem {
+            delete: Some(Operation {
+                deprecated: Some(true),
+                ..operation
+            }),
+            options: Some(Operation::default()),
+            ..PathItem::default()
+        };
+
+        let diff = PathItemDiff::from_items(&base, &head, Direction::Request, &Resolver::default());
+
+        assert!(diff.operations_changed.contains_key("delete"));
+        assert_eq!(diff.operations_removed.first().unwrap().0, "trace");
+        assert_eq!(diff.operations_added.first().unwrap().0, "options");
+    }
 }
@@ -1,4 +1,6 @@
+use super::common::Direction;
 use super::path_items::{PathItemDiff, PathItemPair};
+use super::resolver::Resolver;
 use super::DiffError;
 use crate::openapi::Paths;
 use serde::Serialize;
@@ -16,8 +18,14 @@ impl PathsDiff {
         !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
     }
 
-    /// Diffs two sets of OpenAPI paths
-    pub(crate) fn from_paths(base: &Paths, head: &Paths) -> Result<Self, DiffError> {
+    /// Diffs two sets of OpenAPI paths. `direction` is the direction of
+    /// their requests, which is `Direction::Response` for webhooks.
+    pub(crate) fn from_paths(
+        base: &Paths,
+        head: &Paths,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Result<Self, DiffError> {
         let mut paths_added = vec![];
         let mut paths_removed = vec![];
         let mut paths_changed: HashMap<String, PathItemDiff> = HashMap::new();
@@ -25,7 +33,12 @@ impl PathsDiff {
         for (path, path_item) in base {
             match head.get(path) {
                 Some(head_path_item) => {
-                    let path_item_diff = PathItemDiff::from_path_items(path_item, head_path_item)?;
+                    let path_item_diff = PathItemDiff::from_path_items(
+                        path_item,
+                        head_path_item,
+                        direction,
+                        resolver,
+                    )?;
 
                     if path_item_diff.has_change() {
                         paths_changed.insert(path.clone(), path_item_diff);
@@ -61,7 +74,8 @@ mod tests {
         let mut head = Paths::default();
         head.insert("/cats".into(), ReferenceOr::Item(PathItem::default()));
 
-        let diff = PathsDiff::from_paths(&base, &head).expect("Failed to diff paths");
+        let diff = PathsDiff::from_paths(&base, &head, Direction::Request, &Resolver::default())
+            .expect("Failed to diff paths");
 
         assert_eq!(diff.added.len(), 1);
         assert_eq!(diff.added.first().unwrap().0, "/cats");
@@ -73,7 +87,8 @@ mod tests {
         base.insert("/cats".into(), ReferenceOr::Item(PathItem::default()));
         let head = Paths::default();
 
-        let diff = PathsDiff::from_paths(&base, &head).expect("Failed to diff paths");
+        let diff = PathsDiff::from_paths(&base, &head, Direction::Request, &Resolver::default())
+            .expect("Failed to diff paths");
 
         assert_eq!(diff.added.len(), 0);
         assert_eq!(diff.removed.len(), 1);
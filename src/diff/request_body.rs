@@ -1,92 +1,220 @@
+use super::common::{BooleanDiff, Direction, OptionalStringDiff};
 use super::content::ContentDiff;
+use super::extensions::ExtensionsDiff;
+use super::resolver::Resolver;
 use crate::openapi::{ReferenceOr, RequestBody};
 use serde::Serialize;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Default)]
 pub struct RequestBodyDiff {
+    /// Set when either request body is a `$ref` that cannot be resolved,
+    /// and the references differ.
+    pub reference: Option<OptionalStringDiff>,
     pub added: Option<ReferenceOr<RequestBody>>,
     pub removed: Option<ReferenceOr<RequestBody>>,
     pub content_changed: Option<ContentDiff>,
+    pub required_changed: Option<BooleanDiff>,
+    pub description_changed: Option<OptionalStringDiff>,
+    pub extensions_changed: Option<ExtensionsDiff>,
 }
 
 impl RequestBodyDiff {
     pub fn has_changes(&self) -> bool {
-        self.added.is_some() || self.removed.is_some() || self.content_changed.is_some()
+        self.reference.is_some()
+            || self.added.is_some()
+            || self.removed.is_some()
+            || self.content_changed.is_some()
+            || self.required_changed.is_some()
+            || self.description_changed.is_some()
+            || self.extensions_changed.is_some()
     }
 
     pub fn from_request_bodies(
         base: &Option<ReferenceOr<RequestBody>>,
         head: &Option<ReferenceOr<RequestBody>>,
+        direction: Direction,
+        resolver: &Resolver,
     ) -> Self {
         match base {
             Some(base) => match head {
                 Some(head) => {
-                    let derefed_base = match &base {
-                        ReferenceOr::Item(bc) => bc,
-                        ReferenceOr::Reference { reference: _ } => {
-                            panic!("$ref not supported yet");
+                    let (derefed_base, derefed_head) = match (
+                        resolver.base_request_body(base),
+                        resolver.head_request_body(head),
+                    ) {
+                        (Some(base), Some(head)) => (base, head),
+                        _ => {
+                            return Self {
+                                reference: OptionalStringDiff::from_strings(
+                                    &Self::reference(base),
+                                    &Self::reference(head),
+                                ),
+                                ..Self::default()
+                            }
                         }
                     };
 
-                    let derefed_head = match &head {
-                        ReferenceOr::Item(pi) => pi,
-                        ReferenceOr::Reference { reference: _ } => {
-                            panic!("$ref not supported yet");
-                        }
-                    };
+                    let mut diff = Self::default();
 
-                    let mut diff = Self {
-                        added: None,
-                        removed: None,
-                        content_changed: None,
-                    };
-
-                    let content_diff =
-                        ContentDiff::from_content(&derefed_base.content, &derefed_head.content);
+                    let content_diff = ContentDiff::from_content(
+                        &derefed_base.content,
+                        &derefed_head.content,
+                        direction,
+                        resolver,
+                    );
 
                     if content_diff.has_changes() {
                         diff.content_changed = Some(content_diff);
                     }
 
+                    // Request bodies are optional unless stated otherwise.
+                    diff.required_changed = BooleanDiff::from_bools(
+                        derefed_base.required.unwrap_or(false),
+                        derefed_head.required.unwrap_or(false),
+                    );
+
+                    diff.description_changed = OptionalStringDiff::from_strings(
+                        &derefed_base.description,
+                        &derefed_head.description,
+                    );
+
+                    let extensions_diff = ExtensionsDiff::from_extensions(
+                        &derefed_base.extensions,
+                        &derefed_head.extensions,
+                    );
+
+                    if extensions_diff.has_changes() {
+                        diff.extensions_changed = Some(extensions_diff);
+                    }
+
                     diff
                 }
                 None => Self {
-                    added: None,
                     removed: Some(base.clone()),
-                    content_changed: None,
+                    ..Default::default()
                 },
             },
             None => match head {
                 Some(head) => Self {
                     added: Some(head.clone()),
-                    removed: None,
-                    content_changed: None,
-                },
-                None => Self {
-                    added: None,
-                    removed: None,
-                    content_changed: None,
+                    ..Default::default()
                 },
+                None => Self::default(),
             },
         }
     }
+
+    fn reference(request_body: &ReferenceOr<RequestBody>) -> Option<String> {
+        match request_body {
+            ReferenceOr::Reference { reference } => Some(reference.clone()),
+            ReferenceOr::Item(_) => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::openapi::Components;
     use std::path::PathBuf;
 
     #[test]
     fn property_removed() {
-        let base_body_string = std::fs::read_to_string(PathBuf::from("fixtures/request_body.yaml")).expect("Failed to parse request body");
-        let head_body_string = std::fs::read_to_string(PathBuf::from("fixtures/request_body_property_removed.yaml")).expect("Failed to parse request body");
-        let base_body: RequestBody = serde_yaml::from_str(&base_body_string).expect("Failed to parse request body");
-        let head_body: RequestBody = serde_yaml::from_str(&head_body_string).expect("Failed to parse request body");
+        let base_body_string = std::fs::read_to_string(PathBuf::from("fixtures/request_body.yaml"))
+            .expect("Failed to parse request body");
+        let head_body_string =
+            std::fs::read_to_string(PathBuf::from("fixtures/request_body_property_removed.yaml"))
+                .expect("Failed to parse request body");
+        let base_body: RequestBody =
+            serde_yaml::from_str(&base_body_string).expect("Failed to parse request body");
+        let head_body: RequestBody =
+            serde_yaml::from_str(&head_body_string).expect("Failed to parse request body");
 
-        let diff = RequestBodyDiff::from_request_bodies(&Some(ReferenceOr::Item(base_body)), &Some(ReferenceOr::Item(head_body)));
+        let diff = RequestBodyDiff::from_request_bodies(
+            &Some(ReferenceOr::Item(base_body)),
+            &Some(ReferenceOr::Item(head_body)),
+            Direction::Request,
+            &Resolver::default(),
+        );
 
         assert!(diff.has_changes());
         assert!(!diff.content_changed.unwrap().changed.is_empty());
     }
+
+    #[test]
+    fn required_changed() {
+        let base_body = RequestBody::default();
+        let head_body = RequestBody {
+            required: Some(true),
+            ..RequestBody::default()
+        };
+
+        let diff = RequestBodyDiff::from_request_bodies(
+            &Some(ReferenceOr::Item(base_body)),
+            &Some(ReferenceOr::Item(head_body)),
+            Direction::Request,
+            &Resolver::default(),
+        );
+        let required_diff = diff.required_changed.unwrap();
+
+        assert!(!required_diff.from);
+        assert!(required_diff.to);
+    }
+
+    #[test]
+    fn explicit_default_required() {
+        let base_body = RequestBody::default();
+        let head_body = RequestBody {
+            required: Some(false),
+            ..RequestBody::default()
+        };
+
+        let diff = RequestBodyDiff::from_request_bodies(
+            &Some(ReferenceOr::Item(base_body)),
+            &Some(ReferenceOr::Item(head_body)),
+            Direction::Request,
+            &Resolver::default(),
+        );
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn references_are_resolved() {
+        let base: Components = serde_yaml::from_str(
+            "requestBodies:\n  Pet:\n    content:\n      application/json:\n        schema:\n          type: object\n",
+        )
+        .expect("Failed to parse components");
+        let head: Components = serde_yaml::from_str(
+            "requestBodies:\n  Pet:\n    required: true\n    content:\n      application/json:\n        schema:\n          type: object\n",
+        )
+        .expect("Failed to parse components");
+        let reference = Some(ReferenceOr::ref_("#/components/requestBodies/Pet"));
+
+        let diff = RequestBodyDiff::from_request_bodies(
+            &reference,
+            &reference,
+            Direction::Request,
+            &Resolver::new(Some(&base), Some(&head)),
+        );
+
+        assert!(diff.reference.is_none());
+        assert!(diff.required_changed.unwrap().to);
+    }
+
+    #[test]
+    fn unresolved_reference_changed() {
+        let diff = RequestBodyDiff::from_request_bodies(
+            &Some(ReferenceOr::ref_("#/components/requestBodies/Pet")),
+            &Some(ReferenceOr::ref_("#/components/requestBodies/Animal")),
+            Direction::Request,
+            &Resolver::default(),
+        );
+        let reference_diff = diff.reference.unwrap();
+
+        assert_eq!(
+            Some("#/components/requestBodies/Animal".into()),
+            reference_diff.to
+        );
+    }
 }
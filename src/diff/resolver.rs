@@ -0,0 +1,236 @@
+use super::common::unescape;
+use crate::openapi::{
+    Callback, Components, Example, Header, Parameter, PathItem, ReferenceOr, RequestBody, Response,
+    Schema,
+};
+
+/// Resolves `$ref`s against the components of the base and head documents.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Resolver<'a> {
+    pub base: Option<&'a Components>,
+    pub head: Option<&'a Components>,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(base: Option<&'a Components>, head: Option<&'a Components>) -> Self {
+        Self { base, head }
+    }
+
+    pub fn base_parameter(&self, param: &ReferenceOr<Parameter>) -> Option<Parameter> {
+        resolve(param, "#/components/parameters/", |name| {
+            self.base?.parameters.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn head_parameter(&self, param: &ReferenceOr<Parameter>) -> Option<Parameter> {
+        resolve(param, "#/components/parameters/", |name| {
+            self.head?.parameters.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn base_example(&self, example: &ReferenceOr<Example>) -> Option<Example> {
+        resolve(example, "#/components/examples/", |name| {
+            self.base?.examples.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn base_header(&self, header: &ReferenceOr<Header>) -> Option<Header> {
+        resolve(header, "#/components/headers/", |name| {
+            self.base?.headers.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn head_header(&self, header: &ReferenceOr<Header>) -> Option<Header> {
+        resolve(header, "#/components/headers/", |name| {
+            self.head?.headers.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn base_callback(&self, callback: &ReferenceOr<Callback>) -> Option<Callback> {
+        resolve(callback, "#/components/callbacks/", |name| {
+            self.base?.callbacks.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn head_callback(&self, callback: &ReferenceOr<Callback>) -> Option<Callback> {
+        resolve(callback, "#/components/callbacks/", |name| {
+            self.head?.callbacks.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn base_request_body(
+        &self,
+        request_body: &ReferenceOr<RequestBody>,
+    ) -> Option<RequestBody> {
+        resolve(request_body, "#/components/requestBodies/", |name| {
+            self.base?.request_bodies.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn head_request_body(
+        &self,
+        request_body: &ReferenceOr<RequestBody>,
+    ) -> Option<RequestBody> {
+        resolve(request_body, "#/components/requestBodies/", |name| {
+            self.head?.request_bodies.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn base_response(&self, response: &ReferenceOr<Response>) -> Option<Response> {
+        resolve(response, "#/components/responses/", |name| {
+            self.base?.responses.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn head_response(&self, response: &ReferenceOr<Response>) -> Option<Response> {
+        resolve(response, "#/components/responses/", |name| {
+            self.head?.responses.get(name)
+        })
+        .cloned()
+    }
+
+    pub fn base_path_item<'b>(
+        &'b self,
+        path_item: &'b ReferenceOr<PathItem>,
+    ) -> Option<&'b PathItem> {
+        resolve(path_item, "#/components/pathItems/", |name| {
+            self.base?.path_items.get(name)
+        })
+    }
+
+    pub fn head_path_item<'b>(
+        &'b self,
+        path_item: &'b ReferenceOr<PathItem>,
+    ) -> Option<&'b PathItem> {
+        resolve(path_item, "#/components/pathItems/", |name| {
+            self.head?.path_items.get(name)
+        })
+    }
+
+    pub fn head_example(&self, example: &ReferenceOr<Example>) -> Option<Example> {
+        resolve(example, "#/components/examples/", |name| {
+            self.head?.examples.get(name)
+        })
+        .cloned()
+    }
+}
+
+/// Resolves a schema against a single document's components.
+pub fn schema<'a>(
+    components: Option<&'a Components>,
+    schema: &'a ReferenceOr<Schema>,
+) -> Option<&'a Schema> {
+    resolve(schema, "#/components/schemas/", |name| {
+        components?.schemas.get(name)
+    })
+}
+
+/// The `$ref` of a schema in `items` or `properties`, which are not
+/// `ReferenceOr` in the model, so their `$ref`s are parsed as an extension.
+pub fn inline_reference(schema: &Schema) -> Option<&str> {
+    schema
+        .extensions
+        .get("$ref")
+        .and_then(|value| value.as_str())
+}
+
+/// Resolves a schema in `items` or `properties` against a single
+/// document's components.
+pub fn inline_schema<'a>(
+    components: Option<&'a Components>,
+    schema: &'a Schema,
+) -> Option<&'a Schema> {
+    match inline_reference(schema) {
+        Some(reference) => reference
+            .strip_prefix("#/components/schemas/")
+            .and_then(|name| components?.schemas.get(&unescape(name)))
+            .and_then(|schema| self::schema(components, schema)),
+        None => Some(schema),
+    }
+}
+
+/// Follows `{prefix}{name}` references, including components that are
+/// themselves references. Returns `None` for references that point
+/// elsewhere or cannot be found.
+fn resolve<'a, T>(
+    item: &'a ReferenceOr<T>,
+    prefix: &str,
+    lookup: impl Fn(&str) -> Option<&'a ReferenceOr<T>>,
+) -> Option<&'a T> {
+    let mut current = item;
+    let mut seen = vec![];
+
+    loop {
+        match current {
+            ReferenceOr::Item(item) => return Some(item),
+            ReferenceOr::Reference { reference } => {
+                if seen.contains(&reference) {
+                    return None;
+                }
+                seen.push(reference);
+
+                let name = reference.strip_prefix(prefix)?;
+                current = lookup(&unescape(name))?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_nested_references() {
+        let components: Components = serde_yaml::from_str(
+            "parameters:\n  Alias:\n    $ref: '#/components/parameters/Page'\n  Page:\n    name: page\n    in: query\n    schema:\n      type: integer\n",
+        )
+        .expect("Failed to parse components");
+        let resolver = Resolver::new(Some(&components), None);
+
+        let param = resolver
+            .base_parameter(&ReferenceOr::ref_("#/components/parameters/Alias"))
+            .expect("Failed to resolve parameter");
+
+        assert!(matches!(param, Parameter::Query { .. }));
+        assert!(resolver
+            .head_parameter(&ReferenceOr::ref_("#/components/parameters/Alias"))
+            .is_none());
+    }
+
+    #[test]
+    fn circular_references() {
+        let components: Components = serde_yaml::from_str(
+            "parameters:\n  A:\n    $ref: '#/components/parameters/B'\n  B:\n    $ref: '#/components/parameters/A'\n",
+        )
+        .expect("Failed to parse components");
+        let resolver = Resolver::new(Some(&components), None);
+
+        assert!(resolver
+            .base_parameter(&ReferenceOr::ref_("#/components/parameters/A"))
+            .is_none());
+    }
+
+    #[test]
+    fn resolves_schemas() {
+        let components: Components = serde_yaml::from_str("schemas:\n  Pet:\n    type: object\n")
+            .expect("Failed to parse components");
+
+        let reference = ReferenceOr::ref_("#/components/schemas/Pet");
+
+        let pet = schema(Some(&components), &reference).expect("Failed to resolve schema");
+
+        assert_eq!(vec!["object"], pet.types());
+        assert!(schema(None, &reference).is_none());
+    }
+}
@@ -1,41 +1,73 @@
+use super::common::{Direction, OptionalStringDiff};
 use super::content::ContentDiff;
+use super::extensions::ExtensionsDiff;
+use super::resolver::Resolver;
 use crate::openapi::{ReferenceOr, Response};
 use serde::Serialize;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Default)]
 pub struct ResponseDiff {
+    /// Set when either response is a `$ref` that cannot be resolved, and
+    /// the references differ.
+    pub reference: Option<OptionalStringDiff>,
     pub content: Option<ContentDiff>,
+    pub extensions: Option<ExtensionsDiff>,
 }
 
 impl ResponseDiff {
     pub fn has_changes(&self) -> bool {
-        self.content.is_some()
+        self.reference.is_some() || self.content.is_some() || self.extensions.is_some()
     }
 
-    pub fn from_responses(base: &ReferenceOr<Response>, head: &ReferenceOr<Response>) -> Self {
-        let base_response = match &base {
-            ReferenceOr::Item(i) => i,
-            ReferenceOr::Reference { reference: _ } => {
-                panic!("$ref not supported yet");
-            }
-        };
+    pub fn from_responses(
+        base: &ReferenceOr<Response>,
+        head: &ReferenceOr<Response>,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
+        let (base_response, head_response) =
+            match (resolver.base_response(base), resolver.head_response(head)) {
+                (Some(base), Some(head)) => (base, head),
+                _ => {
+                    return Self {
+                        reference: OptionalStringDiff::from_strings(
+                            &Self::reference(base),
+                            &Self::reference(head),
+                        ),
+                        ..Self::default()
+                    }
+                }
+            };
 
-        let head_response = match &head {
-            ReferenceOr::Item(i) => i,
-            ReferenceOr::Reference { reference: _ } => {
-                panic!("$ref not supported yet");
-            }
-        };
+        let content_diff = ContentDiff::from_content(
+            &base_response.content,
+            &head_response.content,
+            direction,
+            resolver,
+        );
 
-        let content_diff =
-            ContentDiff::from_content(&base_response.content, &head_response.content);
+        let extensions_diff =
+            ExtensionsDiff::from_extensions(&base_response.extensions, &head_response.extensions);
 
-        if content_diff.has_changes() {
-            Self {
-                content: Some(content_diff),
-            }
-        } else {
-            Self { content: None }
+        Self {
+            content: if content_diff.has_changes() {
+                Some(content_diff)
+            } else {
+                None
+            },
+            extensions: if extensions_diff.has_changes() {
+                Some(extensions_diff)
+            } else {
+                None
+            },
+            ..Self::default()
+        }
+    }
+
+    fn reference(response: &ReferenceOr<Response>) -> Option<String> {
+        match response {
+            ReferenceOr::Reference { reference } => Some(reference.clone()),
+            ReferenceOr::Item(_) => None,
         }
     }
 }
@@ -1,3 +1,5 @@
+use super::common::Direction;
+use super::resolver::Resolver;
 use super::response::ResponseDiff;
 use crate::openapi::{ReferenceOr, Response, Responses, StatusCode};
 use serde::Serialize;
@@ -17,7 +19,12 @@ impl ResponsesDiff {
         !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
     }
 
-    pub fn from_responses(base: &Responses, head: &Responses) -> Self {
+    pub fn from_responses(
+        base: &Responses,
+        head: &Responses,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
         let mut responses_added = vec![];
         let mut responses_removed = vec![];
         let mut responses_changed: HashMap<StatusCode, ResponseDiff> = HashMap::new();
@@ -25,7 +32,8 @@ impl ResponsesDiff {
         for (status_code, response) in &base.responses {
             match head.responses.get(status_code) {
                 Some(head_response) => {
-                    let response_diff = ResponseDiff::from_responses(&response, &head_response);
+                    let response_diff =
+                        ResponseDiff::from_responses(response, head_response, direction, resolver);
 
                     if response_diff.has_changes() {
                         responses_changed.insert(status_code.clone(), response_diff);
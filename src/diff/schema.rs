@@ -1,61 +1,145 @@
-use super::common::{BooleanDiff, OptionalStringDiff, StringListDiff};
-use crate::openapi::{ReferenceOr, Schema};
+use super::common::{
+    BooleanDiff, Direction, OptionalStringDiff, OptionalValueDiff, StringListDiff,
+};
+use super::compatibility::Compatibility;
+use super::extensions::ExtensionsDiff;
+use super::resolver::{self, Resolver};
+use crate::openapi::{AdditionalProperties, Components, ReferenceOr, Schema};
 use serde::Serialize;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Serialize, Default)]
 pub struct SchemaDiff {
-    pub type_changed: Option<OptionalStringDiff>,
+    /// Set when a `$ref` on either side cannot be resolved, and the
+    /// references differ.
+    pub reference_changed: Option<OptionalStringDiff>,
+    pub type_changed: Option<DataTypeDiff>,
     pub description_changed: Option<OptionalStringDiff>,
-    pub format_changed: Option<OptionalStringDiff>,
+    pub format_changed: Option<DataTypeDiff>,
     pub required_changed: Option<StringListDiff>,
     pub properties_changed: Option<PropertiesDiff>,
     pub enum_changed: Option<EnumDiff>,
     pub items_changed: Option<Box<SchemaDiff>>,
+    pub prefix_items_changed: Option<PrefixItemsDiff>,
     pub nullable_changed: Option<BooleanDiff>,
+    pub additional_properties_changed: Option<AdditionalPropertiesDiff>,
+    pub read_only_changed: Option<BooleanDiff>,
+    pub write_only_changed: Option<BooleanDiff>,
+    pub default_changed: Option<OptionalValueDiff>,
+    pub example_changed: Option<OptionalValueDiff>,
+    pub examples_changed: Option<OptionalValueDiff>,
+    pub defs_changed: Option<PropertiesDiff>,
+    pub title_changed: Option<OptionalStringDiff>,
+    pub deprecated_changed: Option<BooleanDiff>,
+    pub extensions_changed: Option<ExtensionsDiff>,
 }
 
 impl SchemaDiff {
     pub fn has_changes(&self) -> bool {
-        self.type_changed.is_some()
+        self.reference_changed.is_some()
+            || self.type_changed.is_some()
             || self.properties_changed.is_some()
             || self.description_changed.is_some()
             || self.format_changed.is_some()
             || self.required_changed.is_some()
             || self.enum_changed.is_some()
             || self.items_changed.is_some()
+            || self.prefix_items_changed.is_some()
             || self.nullable_changed.is_some()
+            || self.additional_properties_changed.is_some()
+            || self.read_only_changed.is_some()
+            || self.write_only_changed.is_some()
+            || self.default_changed.is_some()
+            || self.example_changed.is_some()
+            || self.examples_changed.is_some()
+            || self.defs_changed.is_some()
+            || self.title_changed.is_some()
+            || self.deprecated_changed.is_some()
+            || self.extensions_changed.is_some()
     }
 
-    pub fn from_schemas(base: &ReferenceOr<Schema>, head: &ReferenceOr<Schema>) -> Self {
-        let base_schema = if let ReferenceOr::Item(s) = base {
-            s
-        } else {
-            panic!("Refs are not handled yet.");
+    /// References are resolved against the components of each side, so
+    /// moving a schema to another component is not a change by itself.
+    pub fn from_schemas(
+        base: &ReferenceOr<Schema>,
+        head: &ReferenceOr<Schema>,
+        direction: Direction,
+        resolver: &Resolver,
+    ) -> Self {
+        let mut context = Context {
+            direction,
+            resolver,
+            references: vec![],
         };
 
-        let head_schema = if let ReferenceOr::Item(s) = head {
-            s
-        } else {
-            panic!("Refs are not handled yet.");
-        };
+        Self::from_resolved(
+            resolve(resolver.base, base),
+            resolve(resolver.head, head),
+            &mut context,
+        )
+    }
+
+    fn from_inline(base: &Schema, head: &Schema, context: &mut Context) -> Self {
+        Self::from_resolved(
+            resolve_inline(context.resolver.base, base),
+            resolve_inline(context.resolver.head, head),
+            context,
+        )
+    }
+
+    fn from_resolved(base: Resolved, head: Resolved, context: &mut Context) -> Self {
+        let (base_reference, base_schema) = base;
+        let (head_reference, head_schema) = head;
+
+        let references = (
+            base_reference.map(String::from),
+            head_reference.map(String::from),
+        );
+        if context.references.contains(&references) {
+            return Self::default();
+        }
+
+        match (base_schema, head_schema) {
+            (Some(base_schema), Some(head_schema)) => {
+                let is_reference = references != (None, None);
+                if is_reference {
+                    context.references.push(references);
+                }
+
+                let diff = Self::from_items(base_schema, head_schema, context);
+
+                if is_reference {
+                    context.references.pop();
+                }
+                diff
+            }
+            _ => Self {
+                reference_changed: OptionalStringDiff::from_strings(&references.0, &references.1),
+                ..Self::default()
+            },
+        }
+    }
 
+    fn from_items(base_schema: &Schema, head_schema: &Schema, context: &mut Context) -> Self {
+        let direction = context.direction;
         let mut diff = Self::default();
 
-        let enum_diff = EnumDiff::from_schemas(&base_schema, &head_schema);
+        let enum_diff = EnumDiff::from_schemas(base_schema, head_schema);
         if enum_diff.has_changes() {
             diff.enum_changed = Some(enum_diff);
         }
 
+        // `required` has no effect on properties that are not sent in this
+        // direction, e.g. a required `readOnly` property in a request.
         let required_diff = StringListDiff::from_lists(
-            &base_schema.required.clone().unwrap_or_else(|| vec![]),
-            &head_schema.required.clone().unwrap_or_else(|| vec![]),
+            &Self::required_properties(context.resolver.base, base_schema, direction),
+            &Self::required_properties(context.resolver.head, head_schema, direction),
         );
         if required_diff.has_changes() {
             diff.required_changed = Some(required_diff);
         }
 
-        let properties_diff = PropertiesDiff::from_schemas(&base_schema, &head_schema);
+        let properties_diff = PropertiesDiff::from_schemas(base_schema, head_schema, context);
         if properties_diff.has_changes() {
             diff.properties_changed = Some(properties_diff);
         }
@@ -65,40 +149,250 @@ impl SchemaDiff {
         // type was changed to array and that is enough of a diff.
         if let Some(base_items) = &base_schema.items {
             if let Some(head_items) = &head_schema.items {
-                let items_diff = SchemaDiff::from_schemas(
-                    &ReferenceOr::Item(*base_items.clone()),
-                    &ReferenceOr::Item(*head_items.clone()),
-                );
+                let items_diff = SchemaDiff::from_inline(base_items, head_items, context);
                 if items_diff.has_changes() {
                     diff.items_changed = Some(Box::new(items_diff))
                 }
             }
         }
 
-        let base_nullable = base_schema.nullable.unwrap_or(false);
-        let head_nullable = head_schema.nullable.unwrap_or(false);
+        let prefix_items_diff = PrefixItemsDiff::from_schemas(base_schema, head_schema, context);
+        if prefix_items_diff.has_changes() {
+            diff.prefix_items_changed = Some(prefix_items_diff);
+        }
+
+        // OpenAPI 3.0 `nullable: true` and a 3.1 `null` type are the same.
+        let base_nullable = base_schema.is_nullable();
+        let head_nullable = head_schema.is_nullable();
 
         if base_nullable != head_nullable {
             diff.nullable_changed = BooleanDiff::from_bools(base_nullable, head_nullable);
         }
 
-        diff.type_changed =
-            OptionalStringDiff::from_strings(&base_schema.schema_type, &head_schema.schema_type);
+        diff.read_only_changed = BooleanDiff::from_bools(
+            base_schema.read_only.unwrap_or(false),
+            head_schema.read_only.unwrap_or(false),
+        );
+        diff.write_only_changed = BooleanDiff::from_bools(
+            base_schema.write_only.unwrap_or(false),
+            head_schema.write_only.unwrap_or(false),
+        );
+
+        let additional_properties_diff = AdditionalPropertiesDiff::from_schemas(
+            &base_schema.additional_properties,
+            &head_schema.additional_properties,
+            context,
+        );
+        if additional_properties_diff.has_changes() {
+            diff.additional_properties_changed = Some(additional_properties_diff);
+        }
+
+        diff.type_changed = DataTypeDiff::from_types(base_schema, head_schema, direction);
         diff.description_changed =
             OptionalStringDiff::from_strings(&base_schema.description, &head_schema.description);
-        diff.format_changed =
-            OptionalStringDiff::from_strings(&base_schema.format, &head_schema.format);
+        diff.format_changed = DataTypeDiff::from_formats(base_schema, head_schema, direction);
+        diff.default_changed =
+            OptionalValueDiff::from_values(&base_schema.default, &head_schema.default);
+        diff.example_changed =
+            OptionalValueDiff::from_values(&base_schema.example, &head_schema.example);
+        diff.examples_changed = OptionalValueDiff::from_values(
+            &base_schema.examples.clone().map(serde_json::Value::Array),
+            &head_schema.examples.clone().map(serde_json::Value::Array),
+        );
+
+        // `$defs` are named definitions rather than payload properties, so
+        // `readOnly` and `writeOnly` do not hide them.
+        let defs_diff = PropertiesDiff::from_maps(
+            &base_schema.defs.clone().unwrap_or_default(),
+            &head_schema.defs.clone().unwrap_or_default(),
+            false,
+            context,
+        );
+        if defs_diff.has_changes() {
+            diff.defs_changed = Some(defs_diff);
+        }
+
+        diff.title_changed =
+            OptionalStringDiff::from_strings(&base_schema.title, &head_schema.title);
+        diff.deprecated_changed = BooleanDiff::from_bools(
+            base_schema.deprecated.unwrap_or(false),
+            head_schema.deprecated.unwrap_or(false),
+        );
+
+        let extensions_diff =
+            ExtensionsDiff::from_extensions(&base_schema.extensions, &head_schema.extensions);
+        if extensions_diff.has_changes() {
+            diff.extensions_changed = Some(extensions_diff);
+        }
 
         diff
     }
+
+    fn required_properties(
+        components: Option<&Components>,
+        schema: &Schema,
+        direction: Direction,
+    ) -> Vec<String> {
+        let properties = schema.properties.clone().unwrap_or_default();
+
+        schema
+            .required
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|name| match properties.get(name) {
+                Some(property) => is_property_visible(components, property, direction),
+                None => true,
+            })
+            .collect()
+    }
+}
+
+/// State shared by the nested diffs of a schema.
+struct Context<'a> {
+    direction: Direction,
+    resolver: &'a Resolver<'a>,
+    /// The `$ref`s being diffed, to stop on recursive schemas.
+    references: Vec<(Option<String>, Option<String>)>,
+}
+
+/// A schema's `$ref`, if any, and the schema it resolves to.
+type Resolved<'a> = (Option<&'a str>, Option<&'a Schema>);
+
+fn resolve<'a>(
+    components: Option<&'a Components>,
+    schema: &'a ReferenceOr<Schema>,
+) -> Resolved<'a> {
+    match schema {
+        ReferenceOr::Reference { reference } => {
+            (Some(reference), resolver::schema(components, schema))
+        }
+        ReferenceOr::Item(schema) => resolve_inline(components, schema),
+    }
+}
+
+fn resolve_inline<'a>(components: Option<&'a Components>, schema: &'a Schema) -> Resolved<'a> {
+    (
+        resolver::inline_reference(schema),
+        resolver::inline_schema(components, schema),
+    )
+}
+
+/// Whether a property is part of the payload in the given direction.
+/// `readOnly` properties are not sent in requests, and `writeOnly`
+/// properties are not returned in responses.
+pub fn is_visible(schema: &Schema, direction: Direction) -> bool {
+    match direction {
+        Direction::Request => !schema.read_only.unwrap_or(false),
+        Direction::Response => !schema.write_only.unwrap_or(false),
+    }
+}
+
+/// Whether a property is part of the payload once an inline `$ref` is
+/// resolved, so that a reference to a `readOnly` schema is `readOnly` too.
+/// Unresolved references are assumed to be part of the payload.
+fn is_property_visible(
+    components: Option<&Components>,
+    property: &Schema,
+    direction: Direction,
+) -> bool {
+    is_visible(property, direction)
+        && resolver::inline_schema(components, property)
+            .map_or(true, |property| is_visible(property, direction))
+}
+
+/// A change to a schema's `type` or `format`, along with how it affects
+/// the set of accepted values and whether that breaks clients.
+#[derive(Debug, Serialize)]
+pub struct DataTypeDiff {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub compatibility: Compatibility,
+    pub breaking: bool,
+}
+
+impl DataTypeDiff {
+    /// Types are compared as sets, and a `null` type is left to
+    /// `SchemaDiff::nullable_changed`. When the format changed as well, the
+    /// formats are part of this change and of its compatibility.
+    pub fn from_types(base: &Schema, head: &Schema, direction: Direction) -> Option<Self> {
+        let base_types = Self::value_types(base);
+        let head_types = Self::value_types(head);
+
+        if base_types == head_types {
+            return None;
+        }
+
+        let (base_format, head_format) = if base.format == head.format {
+            (None, None)
+        } else {
+            (base.format.as_deref(), head.format.as_deref())
+        };
+
+        let compatibility =
+            Compatibility::from_type_lists(&base_types, base_format, &head_types, head_format);
+
+        Some(Self {
+            from: Self::type_name(&base_types, base_format),
+            to: Self::type_name(&head_types, head_format),
+            compatibility,
+            breaking: compatibility.is_breaking(direction),
+        })
+    }
+
+    /// Formats are compared along with their types, since a format only
+    /// has meaning for the type it applies to. A format change along with
+    /// the type is reported by `from_types`.
+    pub fn from_formats(base: &Schema, head: &Schema, direction: Direction) -> Option<Self> {
+        let base_types = Self::value_types(base);
+        let head_types = Self::value_types(head);
+
+        if base.format == head.format || base_types != head_types {
+            return None;
+        }
+
+        let compatibility = Compatibility::from_type_lists(
+            &base_types,
+            base.format.as_deref(),
+            &head_types,
+            head.format.as_deref(),
+        );
+
+        Some(Self {
+            from: base.format.clone(),
+            to: head.format.clone(),
+            compatibility,
+            breaking: compatibility.is_breaking(direction),
+        })
+    }
+
+    /// The sorted types of a schema without `null`, unless `null` is the
+    /// only type.
+    fn value_types(schema: &Schema) -> Vec<&str> {
+        let mut types = schema.types();
+        if types.len() > 1 {
+            types.retain(|schema_type| *schema_type != "null");
+        }
+        types.sort_unstable();
+        types.dedup();
+        types
+    }
+
+    fn type_name(types: &[&str], format: Option<&str>) -> Option<String> {
+        match (types.is_empty(), format) {
+            (true, _) => None,
+            (false, Some(format)) => Some(format!("{} ({})", types.join(" | "), format)),
+            (false, None) => Some(types.join(" | ")),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Default)]
 pub struct EnumDiff {
     pub added: bool,
     pub removed: bool,
-    pub values_added: Vec<Option<String>>,
-    pub values_removed: Vec<Option<String>>,
+    pub values_added: Vec<serde_json::Value>,
+    pub values_removed: Vec<serde_json::Value>,
 }
 
 impl EnumDiff {
@@ -110,20 +404,18 @@ impl EnumDiff {
     }
 
     pub fn from_schemas(base: &Schema, head: &Schema) -> Self {
-        match &base.enum_values {
-            Some(base_enum) => match &head.enum_values {
+        match Self::allowed_values(base) {
+            Some(base_enum) => match Self::allowed_values(head) {
                 Some(head_enum) => {
-                    let base_set: HashSet<_> = base_enum.iter().collect();
                     let added: Vec<_> = head_enum
                         .iter()
-                        .filter(|item| !base_set.contains(item))
+                        .filter(|item| !base_enum.iter().any(|b| Self::values_equal(b, item)))
                         .cloned()
                         .collect();
 
-                    let head_set: HashSet<_> = head_enum.iter().collect();
                     let removed: Vec<_> = base_enum
                         .iter()
-                        .filter(|item| !head_set.contains(item))
+                        .filter(|item| !head_enum.iter().any(|h| Self::values_equal(h, item)))
                         .cloned()
                         .collect();
 
@@ -141,7 +433,7 @@ impl EnumDiff {
                     values_added: vec![],
                 },
             },
-            None => match head.enum_values {
+            None => match Self::allowed_values(head) {
                 Some(_) => EnumDiff {
                     added: true,
                     removed: false,
@@ -152,6 +444,144 @@ impl EnumDiff {
             },
         }
     }
+
+    /// The values a schema is restricted to, treating `const` as an enum
+    /// with a single value.
+    fn allowed_values(schema: &Schema) -> Option<Vec<serde_json::Value>> {
+        match &schema.enum_values {
+            Some(values) => Some(values.clone()),
+            None => schema.const_value.clone().map(|value| vec![value]),
+        }
+    }
+
+    /// Compares enum values the way JSON Schema does: values of different
+    /// JSON types are never equal, so `1` and `"1"` differ, but numbers are
+    /// compared by value, so `1` and `1.0` are the same.
+    pub(crate) fn values_equal(base: &serde_json::Value, head: &serde_json::Value) -> bool {
+        use serde_json::Value;
+
+        match (base, head) {
+            (Value::Number(b), Value::Number(h)) => match (b.as_f64(), h.as_f64()) {
+                (Some(b), Some(h)) => b == h,
+                _ => b == h,
+            },
+            (Value::Array(b), Value::Array(h)) => {
+                b.len() == h.len() && b.iter().zip(h).all(|(b, h)| Self::values_equal(b, h))
+            }
+            (Value::Object(b), Value::Object(h)) => {
+                b.len() == h.len()
+                    && b.iter().all(|(key, b)| match h.get(key) {
+                        Some(h) => Self::values_equal(b, h),
+                        None => false,
+                    })
+            }
+            _ => base == head,
+        }
+    }
+}
+
+/// Diff of the `additionalProperties` keyword. A missing keyword is
+/// equivalent to `additionalProperties: true`, and a schema allows
+/// additional properties as long as they match it.
+#[derive(Debug, Serialize, Default)]
+pub struct AdditionalPropertiesDiff {
+    pub allowed_changed: Option<BooleanDiff>,
+    pub schema_added: Option<ReferenceOr<Schema>>,
+    pub schema_removed: Option<ReferenceOr<Schema>>,
+    pub schema_changed: Option<Box<SchemaDiff>>,
+}
+
+impl AdditionalPropertiesDiff {
+    pub fn has_changes(&self) -> bool {
+        self.allowed_changed.is_some()
+            || self.schema_added.is_some()
+            || self.schema_removed.is_some()
+            || self.schema_changed.is_some()
+    }
+
+    fn from_schemas(
+        base: &Option<AdditionalProperties>,
+        head: &Option<AdditionalProperties>,
+        context: &mut Context,
+    ) -> Self {
+        let mut diff = Self {
+            allowed_changed: BooleanDiff::from_bools(Self::allowed(base), Self::allowed(head)),
+            ..Self::default()
+        };
+
+        match (base, head) {
+            (
+                Some(AdditionalProperties::Schema(base_schema)),
+                Some(AdditionalProperties::Schema(head_schema)),
+            ) => {
+                let schema_diff = SchemaDiff::from_resolved(
+                    resolve(context.resolver.base, base_schema),
+                    resolve(context.resolver.head, head_schema),
+                    context,
+                );
+                if schema_diff.has_changes() {
+                    diff.schema_changed = Some(Box::new(schema_diff));
+                }
+            }
+            (Some(AdditionalProperties::Schema(base_schema)), _) => {
+                diff.schema_removed = Some(*base_schema.clone());
+            }
+            (_, Some(AdditionalProperties::Schema(head_schema))) => {
+                diff.schema_added = Some(*head_schema.clone());
+            }
+            _ => {}
+        }
+
+        diff
+    }
+
+    fn allowed(additional_properties: &Option<AdditionalProperties>) -> bool {
+        match additional_properties {
+            Some(AdditionalProperties::Any(allowed)) => *allowed,
+            Some(AdditionalProperties::Schema(_)) | None => true,
+        }
+    }
+}
+
+/// Diff of the OpenAPI 3.1 `prefixItems` keyword, keyed by item position.
+#[derive(Debug, Serialize, Default)]
+pub struct PrefixItemsDiff {
+    pub added: Vec<(usize, ReferenceOr<Schema>)>,
+    pub removed: Vec<(usize, ReferenceOr<Schema>)>,
+    pub changed: BTreeMap<usize, SchemaDiff>,
+}
+
+impl PrefixItemsDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    fn from_schemas(base: &Schema, head: &Schema, context: &mut Context) -> Self {
+        let mut diff = Self::default();
+
+        let base_items = base.prefix_items.clone().unwrap_or_default();
+        let head_items = head.prefix_items.clone().unwrap_or_default();
+
+        for index in 0..base_items.len().max(head_items.len()) {
+            match (base_items.get(index), head_items.get(index)) {
+                (Some(base_item), Some(head_item)) => {
+                    let schema_diff = SchemaDiff::from_resolved(
+                        resolve(context.resolver.base, base_item),
+                        resolve(context.resolver.head, head_item),
+                        context,
+                    );
+                    if schema_diff.has_changes() {
+                        diff.changed.insert(index, schema_diff);
+                    }
+                }
+                (Some(base_item), None) => diff.removed.push((index, base_item.clone())),
+                (None, Some(head_item)) => diff.added.push((index, head_item.clone())),
+                (None, None) => {}
+            }
+        }
+
+        diff
+    }
 }
 
 type Property = (String, Schema);
@@ -168,39 +598,63 @@ impl PropertiesDiff {
         !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
     }
 
-    pub fn from_schemas(base: &Schema, head: &Schema) -> Self {
+    /// Properties that are not part of the payload in `direction` on either
+    /// side are skipped: adding a `readOnly` property to a request schema,
+    /// for example, does not change what clients send.
+    fn from_schemas(base: &Schema, head: &Schema, context: &mut Context) -> Self {
+        Self::from_maps(
+            &base.properties.clone().unwrap_or_default(),
+            &head.properties.clone().unwrap_or_default(),
+            true,
+            context,
+        )
+    }
+
+    /// Diffs two maps of named schemas, such as `properties` or `$defs`.
+    /// `by_direction` skips the schemas that are not part of the payload.
+    fn from_maps(
+        base_properties: &BTreeMap<String, Schema>,
+        head_properties: &BTreeMap<String, Schema>,
+        by_direction: bool,
+        context: &mut Context,
+    ) -> Self {
+        let (direction, resolver) = (context.direction, context.resolver);
+        let base_visible =
+            |property| !by_direction || is_property_visible(resolver.base, property, direction);
+        let head_visible =
+            |property| !by_direction || is_property_visible(resolver.head, property, direction);
         let mut added = vec![];
         let mut removed = vec![];
         let mut changed: HashMap<String, SchemaDiff> = HashMap::new();
 
-        let base_properties = base
-            .properties
-            .clone()
-            .unwrap_or_else(|| BTreeMap::default());
-        let head_properties = head
-            .properties
-            .clone()
-            .unwrap_or_else(|| BTreeMap::default());
-
-        for (property_name, schema) in &base_properties {
+        for (property_name, schema) in base_properties {
             match head_properties.get(property_name) {
                 Some(head_property) => {
-                    let schema_diff = SchemaDiff::from_schemas(
-                        &ReferenceOr::Item(schema.clone()),
-                        &ReferenceOr::Item(head_property.clone()),
-                    );
+                    if !base_visible(schema) && !head_visible(head_property) {
+                        continue;
+                    }
+
+                    let schema_diff = SchemaDiff::from_inline(schema, head_property, context);
                     if schema_diff.has_changes() {
                         changed.insert(property_name.clone(), schema_diff);
                     }
                 }
-                None => removed.push((property_name.clone(), schema.clone())),
+                None => {
+                    if base_visible(schema) {
+                        removed.push((property_name.clone(), schema.clone()))
+                    }
+                }
             }
         }
 
-        for (property_name, schema) in &head_properties {
+        for (property_name, schema) in head_properties {
             match base_properties.get(property_name) {
                 Some(_) => {}
-                None => added.push((property_name.clone(), schema.clone())),
+                None => {
+                    if head_visible(schema) {
+                        added.push((property_name.clone(), schema.clone()))
+                    }
+                }
             }
         }
 
@@ -211,3 +665,451 @@ impl PropertiesDiff {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff_schemas(base: Schema, head: Schema) -> SchemaDiff {
+        SchemaDiff::from_schemas(
+            &ReferenceOr::Item(base),
+            &ReferenceOr::Item(head),
+            Direction::Request,
+            &Resolver::default(),
+        )
+    }
+
+    fn object_with_property(name: &str, property: Schema, required: bool) -> Schema {
+        let mut schema = Schema {
+            schema_type: Some("object".into()),
+            ..Schema::default()
+        };
+        let mut properties = std::collections::BTreeMap::new();
+        properties.insert(name.to_string(), property);
+        schema.properties = Some(properties);
+        if required {
+            schema.required = Some(vec![name.to_string()]);
+        }
+        schema
+    }
+
+    #[test]
+    fn additional_properties_disallowed() {
+        let base = Schema::default();
+        let head = Schema {
+            additional_properties: Some(AdditionalProperties::Any(false)),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+        let additional_properties_diff = diff.additional_properties_changed.unwrap();
+        let allowed_diff = additional_properties_diff.allowed_changed.unwrap();
+
+        assert!(allowed_diff.from);
+        assert!(!allowed_diff.to);
+    }
+
+    #[test]
+    fn additional_properties_true_is_default() {
+        let base = Schema::default();
+        let head = Schema {
+            additional_properties: Some(AdditionalProperties::Any(true)),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+
+        assert!(diff.additional_properties_changed.is_none());
+    }
+
+    #[test]
+    fn additional_properties_bool_to_schema() {
+        let base = Schema {
+            additional_properties: Some(AdditionalProperties::Any(false)),
+            ..Schema::default()
+        };
+        let head = Schema {
+            additional_properties: Some(AdditionalProperties::Schema(Box::new(ReferenceOr::Item(
+                Schema::default(),
+            )))),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+        let additional_properties_diff = diff.additional_properties_changed.unwrap();
+
+        assert!(additional_properties_diff.allowed_changed.unwrap().to);
+        assert!(additional_properties_diff.schema_added.is_some());
+        assert!(additional_properties_diff.schema_removed.is_none());
+    }
+
+    #[test]
+    fn additional_properties_schema_changed() {
+        let base_value = Schema {
+            schema_type: Some("string".into()),
+            ..Schema::default()
+        };
+        let base = Schema {
+            additional_properties: Some(AdditionalProperties::Schema(Box::new(ReferenceOr::Item(
+                base_value,
+            )))),
+            ..Schema::default()
+        };
+
+        let head_value = Schema {
+            schema_type: Some("integer".into()),
+            ..Schema::default()
+        };
+        let head = Schema {
+            additional_properties: Some(AdditionalProperties::Schema(Box::new(ReferenceOr::Item(
+                head_value,
+            )))),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+        let additional_properties_diff = diff.additional_properties_changed.unwrap();
+        let value_diff = additional_properties_diff.schema_changed.unwrap();
+
+        assert!(additional_properties_diff.allowed_changed.is_none());
+        assert_eq!(Some("integer".into()), value_diff.type_changed.unwrap().to);
+    }
+
+    #[test]
+    fn required_read_only_property_added_to_request() {
+        let base = Schema {
+            schema_type: Some("object".into()),
+            ..Schema::default()
+        };
+        let id = Schema {
+            read_only: Some(true),
+            ..Schema::default()
+        };
+        let head = object_with_property("id", id, true);
+
+        let diff = diff_schemas(base, head);
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn required_read_only_property_added_to_response() {
+        let base = Schema {
+            schema_type: Some("object".into()),
+            ..Schema::default()
+        };
+        let id = Schema {
+            read_only: Some(true),
+            ..Schema::default()
+        };
+        let head = object_with_property("id", id, true);
+
+        let diff = SchemaDiff::from_schemas(
+            &ReferenceOr::Item(base),
+            &ReferenceOr::Item(head),
+            Direction::Response,
+            &Resolver::default(),
+        );
+
+        assert_eq!(vec!["id"], diff.required_changed.unwrap().added);
+        assert_eq!(1, diff.properties_changed.unwrap().added.len());
+    }
+
+    #[test]
+    fn property_became_write_only() {
+        let base = object_with_property("password", Schema::default(), false);
+        let password = Schema {
+            write_only: Some(true),
+            ..Schema::default()
+        };
+        let head = object_with_property("password", password, false);
+
+        let diff = SchemaDiff::from_schemas(
+            &ReferenceOr::Item(base),
+            &ReferenceOr::Item(head),
+            Direction::Response,
+            &Resolver::default(),
+        );
+        let properties_diff = diff.properties_changed.unwrap();
+        let write_only_diff = properties_diff
+            .changed
+            .get("password")
+            .unwrap()
+            .write_only_changed
+            .as_ref()
+            .unwrap();
+
+        assert!(!write_only_diff.from);
+        assert!(write_only_diff.to);
+    }
+
+    #[test]
+    fn default_changed() {
+        let base = Schema {
+            default: Some(serde_json::json!(10)),
+            ..Schema::default()
+        };
+        let head = Schema {
+            default: Some(serde_json::json!("10")),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+        let default_diff = diff.default_changed.unwrap();
+
+        assert_eq!(Some(serde_json::json!(10)), default_diff.from);
+        assert_eq!(Some(serde_json::json!("10")), default_diff.to);
+    }
+
+    #[test]
+    fn deprecated_changed() {
+        let base = Schema::default();
+        let head = Schema {
+            deprecated: Some(true),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+
+        assert!(diff.deprecated_changed.unwrap().to);
+    }
+
+    #[test]
+    fn enum_values_are_type_aware() {
+        let base = Schema {
+            enum_values: Some(vec![serde_json::json!(1), serde_json::json!(2)]),
+            ..Schema::default()
+        };
+        let head = Schema {
+            enum_values: Some(vec![serde_json::json!("1"), serde_json::json!(2.0)]),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+        let enum_diff = diff.enum_changed.unwrap();
+
+        assert_eq!(vec![serde_json::json!("1")], enum_diff.values_added);
+        assert_eq!(vec![serde_json::json!(1)], enum_diff.values_removed);
+    }
+
+    #[test]
+    fn const_is_a_single_value_enum() {
+        let base = Schema {
+            enum_values: Some(vec![serde_json::json!(true), serde_json::json!(false)]),
+            ..Schema::default()
+        };
+        let head = Schema {
+            const_value: Some(serde_json::json!(true)),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+        let enum_diff = diff.enum_changed.unwrap();
+
+        assert!(enum_diff.values_added.is_empty());
+        assert_eq!(vec![serde_json::json!(false)], enum_diff.values_removed);
+    }
+
+    #[test]
+    fn parses_non_string_enums() {
+        let schema: Schema = serde_yaml::from_str("type: integer\nenum: [1, 2, 3]\n")
+            .expect("Failed to parse schema");
+
+        assert_eq!(
+            Some(vec![
+                serde_json::json!(1),
+                serde_json::json!(2),
+                serde_json::json!(3)
+            ]),
+            schema.enum_values
+        );
+    }
+
+    #[test]
+    fn nullable_is_a_null_type() {
+        let base: Schema =
+            serde_yaml::from_str("type: string\nnullable: true\n").expect("Failed to parse schema");
+        let head: Schema =
+            serde_yaml::from_str("type: [string, \"null\"]\n").expect("Failed to parse schema");
+
+        let diff = diff_schemas(base, head);
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn type_list_widened() {
+        let base: Schema =
+            serde_yaml::from_str("type: [string, \"null\"]\n").expect("Failed to parse schema");
+        let head: Schema =
+            serde_yaml::from_str("type: [integer, string]\n").expect("Failed to parse schema");
+
+        let diff = diff_schemas(base, head);
+        let type_diff = diff.type_changed.unwrap();
+
+        assert_eq!(Some("string".into()), type_diff.from);
+        assert_eq!(Some("integer | string".into()), type_diff.to);
+        assert_eq!(Compatibility::Widened, type_diff.compatibility);
+        assert!(!diff.nullable_changed.unwrap().to);
+    }
+
+    #[test]
+    fn type_and_format_changed_together() {
+        let base = Schema {
+            schema_type: Some("integer".into()),
+            format: Some("int32".into()),
+            ..Schema::default()
+        };
+        let head = Schema {
+            schema_type: Some("string".into()),
+            format: Some("date-time".into()),
+            ..Schema::default()
+        };
+
+        let diff = diff_schemas(base, head);
+        let type_diff = diff.type_changed.unwrap();
+
+        assert!(diff.format_changed.is_none());
+        assert_eq!(Some("integer (int32)".into()), type_diff.from);
+        assert_eq!(Some("string (date-time)".into()), type_diff.to);
+        assert_eq!(Compatibility::Incompatible, type_diff.compatibility);
+    }
+
+    #[test]
+    fn prefix_item_changed() {
+        let base: Schema = serde_yaml::from_str(
+            "type: array\nprefixItems:\n  - type: string\n  - type: integer\n",
+        )
+        .expect("Failed to parse schema");
+        let head: Schema = serde_yaml::from_str(
+            "type: array\nprefixItems:\n  - type: string\n  - type: number\n  - type: boolean\n",
+        )
+        .expect("Failed to parse schema");
+
+        let diff = diff_schemas(base, head);
+        let prefix_items_diff = diff.prefix_items_changed.unwrap();
+
+        assert!(prefix_items_diff.changed.contains_key(&1));
+        assert_eq!(2, prefix_items_diff.added[0].0);
+        assert!(prefix_items_diff.removed.is_empty());
+    }
+
+    #[test]
+    fn references_are_resolved() {
+        let base: Components = serde_yaml::from_str(
+            "schemas:\n  Pet:\n    type: object\n    properties:\n      friend:\n        $ref: '#/components/schemas/Pet'\n      name:\n        type: string\n",
+        )
+        .expect("Failed to parse components");
+        let head: Components = serde_yaml::from_str(
+            "schemas:\n  Animal:\n    type: object\n    properties:\n      friend:\n        $ref: '#/components/schemas/Animal'\n      name:\n        type: integer\n",
+        )
+        .expect("Failed to parse components");
+
+        let diff = SchemaDiff::from_schemas(
+            &ReferenceOr::ref_("#/components/schemas/Pet"),
+            &ReferenceOr::ref_("#/components/schemas/Animal"),
+            Direction::Request,
+            &Resolver::new(Some(&base), Some(&head)),
+        );
+        let properties_diff = diff.properties_changed.unwrap();
+
+        assert!(diff.reference_changed.is_none());
+        assert!(properties_diff.changed["name"].type_changed.is_some());
+        assert!(!properties_diff.changed.contains_key("friend"));
+    }
+
+    #[test]
+    fn referenced_read_only_property() {
+        let components: Components = serde_yaml::from_str(
+            "schemas:\n  Id:\n    type: string\n    readOnly: true\n  Pet:\n    type: object\n    properties:\n      name:\n        type: string\n  NewPet:\n    type: object\n    required: [id]\n    properties:\n      id:\n        $ref: '#/components/schemas/Id'\n      name:\n        type: string\n",
+        )
+        .expect("Failed to parse components");
+
+        let diff = SchemaDiff::from_schemas(
+            &ReferenceOr::ref_("#/components/schemas/Pet"),
+            &ReferenceOr::ref_("#/components/schemas/NewPet"),
+            Direction::Request,
+            &Resolver::new(Some(&components), Some(&components)),
+        );
+
+        assert!(!diff.has_changes());
+    }
+
+    #[test]
+    fn write_only_defs_in_responses() {
+        let base: Schema =
+            serde_yaml::from_str("$defs:\n  Password:\n    type: string\n    writeOnly: true\n")
+                .unwrap();
+        let head: Schema =
+            serde_yaml::from_str("$defs:\n  Password:\n    type: integer\n    writeOnly: true\n")
+                .unwrap();
+
+        let diff = SchemaDiff::from_schemas(
+            &ReferenceOr::Item(base),
+            &ReferenceOr::Item(head),
+            Direction::Response,
+            &Resolver::default(),
+        );
+
+        assert!(diff.defs_changed.unwrap().changed.contains_key("Password"));
+    }
+
+    #[test]
+    fn unresolved_reference_changed() {
+        let diff = SchemaDiff::from_schemas(
+            &ReferenceOr::ref_("other.yaml#/Pet"),
+            &ReferenceOr::Item(Schema::default()),
+            Direction::Request,
+            &Resolver::default(),
+        );
+        let reference_diff = diff.reference_changed.unwrap();
+
+        assert_eq!(Some("other.yaml#/Pet".into()), reference_diff.from);
+        assert_eq!(None, reference_diff.to);
+    }
+
+    #[test]
+    fn property_extension_added() {
+        let base: Schema =
+            serde_yaml::from_str("type: object\nproperties:\n  id:\n    type: string\n").unwrap();
+        let head: Schema = serde_yaml::from_str(
+            "type: object\nproperties:\n  id:\n    type: string\n    x-internal: true\n",
+        )
+        .unwrap();
+
+        let diff = SchemaDiff::from_schemas(
+            &ReferenceOr::Item(base),
+            &ReferenceOr::Item(head),
+            Direction::Request,
+            &Resolver::default(),
+        );
+        let property_diff = &diff.properties_changed.unwrap().changed["id"];
+        let extensions_diff = property_diff.extensions_changed.as_ref().unwrap();
+
+        assert_eq!("x-internal", extensions_diff.added[0].0);
+        assert!(extensions_diff.changed.is_empty());
+    }
+
+    #[test]
+    fn format_widened_in_response_is_breaking() {
+        let base = Schema {
+            schema_type: Some("integer".into()),
+            format: Some("int32".into()),
+            ..Schema::default()
+        };
+        let mut head = base.clone();
+        head.format = Some("int64".into());
+
+        let diff = SchemaDiff::from_schemas(
+            &ReferenceOr::Item(base),
+            &ReferenceOr::Item(head),
+            Direction::Response,
+            &Resolver::default(),
+        );
+        let format_diff = diff.format_changed.unwrap();
+
+        assert!(diff.type_changed.is_none());
+        assert_eq!(Compatibility::Widened, format_diff.compatibility);
+        assert!(format_diff.breaking);
+    }
+}
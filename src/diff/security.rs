@@ -0,0 +1,119 @@
+use super::common::StringListDiff;
+use crate::openapi::SecurityRequirement;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// A change to a list of alternative security requirements. Requirements
+/// are matched by their set of scheme names, so a requirement whose scopes
+/// changed is reported as changed rather than removed and added.
+#[derive(Debug, Serialize)]
+pub struct SecurityDiff {
+    pub added: Vec<SecurityRequirement>,
+    pub removed: Vec<SecurityRequirement>,
+    /// Keyed by the requirement's scheme names joined by ` + `, which is
+    /// empty for the requirement that allows anonymous access.
+    pub changed: HashMap<String, SecurityRequirementDiff>,
+}
+
+impl SecurityDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    pub fn from_requirements(base: &[SecurityRequirement], head: &[SecurityRequirement]) -> Self {
+        let mut requirements_added = vec![];
+        let mut requirements_removed = vec![];
+        let mut requirements_changed: HashMap<String, SecurityRequirementDiff> = HashMap::new();
+
+        for requirement in base {
+            let key = requirement_key(requirement);
+
+            match head.iter().find(|head| requirement_key(head) == key) {
+                Some(head_requirement) => {
+                    let requirement_diff =
+                        SecurityRequirementDiff::from_requirements(requirement, head_requirement);
+
+                    if requirement_diff.has_changes() {
+                        requirements_changed.insert(key, requirement_diff);
+                    }
+                }
+                None => requirements_removed.push(requirement.clone()),
+            }
+        }
+
+        for requirement in head {
+            let key = requirement_key(requirement);
+
+            if !base.iter().any(|base| requirement_key(base) == key) {
+                requirements_added.push(requirement.clone());
+            }
+        }
+
+        Self {
+            added: requirements_added,
+            removed: requirements_removed,
+            changed: requirements_changed,
+        }
+    }
+}
+
+/// The scheme names of a requirement in order, joined by ` + `.
+pub fn requirement_key(requirement: &SecurityRequirement) -> String {
+    requirement
+        .keys()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// The scopes of each scheme of a requirement.
+#[derive(Debug, Serialize)]
+pub struct SecurityRequirementDiff {
+    pub scopes: HashMap<String, StringListDiff>,
+}
+
+impl SecurityRequirementDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.scopes.is_empty()
+    }
+
+    pub fn from_requirements(base: &SecurityRequirement, head: &SecurityRequirement) -> Self {
+        let mut scopes = HashMap::new();
+
+        for (scheme, base_scopes) in base {
+            if let Some(head_scopes) = head.get(scheme) {
+                let scopes_diff = StringListDiff::from_lists(base_scopes, head_scopes);
+
+                if scopes_diff.has_changes() {
+                    scopes.insert(scheme.clone(), scopes_diff);
+                }
+            }
+        }
+
+        Self { scopes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scopes_changed_and_alternative_removed() {
+        let base: Vec<SecurityRequirement> =
+            serde_yaml::from_str("- petstore_auth: [read:pets]\n- api_key: []\n")
+                .expect("Failed to parse security");
+        let head: Vec<SecurityRequirement> =
+            serde_yaml::from_str("- petstore_auth: [read:pets, write:pets]\n")
+                .expect("Failed to parse security");
+
+        let diff = SecurityDiff::from_requirements(&base, &head);
+
+        assert!(diff.added.is_empty());
+        assert_eq!("api_key", requirement_key(&diff.removed[0]));
+        assert_eq!(
+            vec!["write:pets"],
+            diff.changed["petstore_auth"].scopes["petstore_auth"].added
+        );
+    }
+}
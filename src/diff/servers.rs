@@ -1,50 +1,58 @@
-use super::common::OptionalStringDiff;
+use super::common::{OptionalStringDiff, StringDiff, StringListDiff};
 use super::extensions::ExtensionsDiff;
-use crate::openapi::Server;
+use crate::openapi::{Server, ServerVariable};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Serialize)]
 pub struct ServersDiff {
     pub added: Vec<Server>,
     pub removed: Vec<Server>,
+    /// Keyed by the base server's URL template.
     pub changed: HashMap<String, ServerDiff>,
 }
 
 impl ServersDiff {
+    /// Servers are matched by URL template first. A server whose template
+    /// changed is matched with one that can expand to any of the same
+    /// concrete URLs.
     pub fn from_servers(base: &[Server], head: &[Server]) -> Self {
         let mut servers_added = vec![];
         let mut servers_removed = vec![];
         let mut servers_changed: HashMap<String, ServerDiff> = HashMap::new();
 
-        let base_map = base.iter().fold(HashMap::new(), |mut acc, server| {
-            acc.insert(server.url.clone(), server.clone());
-            acc
-        });
+        let mut head_unmatched: Vec<&Server> = head
+            .iter()
+            .filter(|server| !base.iter().any(|base| base.url == server.url))
+            .collect();
 
-        let head_map = head.iter().fold(HashMap::new(), |mut acc, server| {
-            acc.insert(server.url.clone(), server.clone());
-            acc
-        });
+        for server in base {
+            let head_server = match head.iter().find(|head| head.url == server.url) {
+                Some(head_server) => Some(head_server),
+                None => {
+                    let urls = server.urls();
 
-        for (url, server) in &base_map {
-            match head_map.get(url) {
+                    head_unmatched
+                        .iter()
+                        .position(|head| head.urls().iter().any(|url| urls.contains(url)))
+                        .map(|index| head_unmatched.remove(index))
+                }
+            };
+
+            match head_server {
                 Some(head_server) => {
-                    let server_diff = ServerDiff::from_servers(&server, &head_server);
+                    let server_diff = ServerDiff::from_servers(server, head_server);
 
                     if server_diff.has_changes() {
-                        servers_changed.insert(url.clone(), server_diff);
+                        servers_changed.insert(server.url.clone(), server_diff);
                     }
                 }
                 None => servers_removed.push(server.clone()),
             }
         }
 
-        for (url, server) in &head_map {
-            match base_map.get(url) {
-                Some(_) => {}
-                None => servers_added.push(server.clone()),
-            }
+        for server in head_unmatched {
+            servers_added.push(server.clone());
         }
 
         Self {
@@ -61,27 +69,200 @@ impl ServersDiff {
 
 #[derive(Debug, Serialize, Default)]
 pub struct ServerDiff {
+    pub url: Option<StringDiff>,
+    /// The concrete URLs that the server can no longer, or can now, be
+    /// reached at, e.g. after a region was removed from a variable's `enum`.
+    pub urls: Option<StringListDiff>,
     pub description: Option<OptionalStringDiff>,
+    pub variables: Option<ServerVariablesDiff>,
     pub extensions: Option<ExtensionsDiff>,
 }
 
 impl ServerDiff {
     pub fn from_servers(base: &Server, head: &Server) -> Self {
-        let mut diff = Self::default();
+        let mut diff = Self {
+            url: StringDiff::from_strings(base.url.clone(), head.url.clone()),
+            ..Self::default()
+        };
+
+        let urls_diff = StringListDiff::from_lists(&base.urls(), &head.urls());
+        if urls_diff.has_changes() {
+            diff.urls = Some(urls_diff);
+        }
 
         diff.description = OptionalStringDiff::from_strings(&base.description, &head.description);
 
+        let variables_diff = ServerVariablesDiff::from_variables(
+            &base.variables.clone().unwrap_or_default(),
+            &head.variables.clone().unwrap_or_default(),
+        );
+        if variables_diff.has_changes() {
+            diff.variables = Some(variables_diff);
+        }
+
         let extensions_diff = ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
         if extensions_diff.has_changes() {
             diff.extensions = Some(extensions_diff);
         }
 
-        // TODO: server variables diff
-
         diff
     }
 
     pub fn has_changes(&self) -> bool {
-        self.description.is_some() || self.extensions.is_some()
+        self.url.is_some()
+            || self.urls.is_some()
+            || self.description.is_some()
+            || self.variables.is_some()
+            || self.extensions.is_some()
+    }
+}
+
+pub type ServerVariablePair = (String, ServerVariable);
+
+#[derive(Debug, Serialize)]
+pub struct ServerVariablesDiff {
+    pub added: Vec<ServerVariablePair>,
+    pub removed: Vec<ServerVariablePair>,
+    pub changed: HashMap<String, ServerVariableDiff>,
+}
+
+impl ServerVariablesDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    pub fn from_variables(
+        base: &BTreeMap<String, ServerVariable>,
+        head: &BTreeMap<String, ServerVariable>,
+    ) -> Self {
+        let mut variables_added = vec![];
+        let mut variables_removed = vec![];
+        let mut variables_changed: HashMap<String, ServerVariableDiff> = HashMap::new();
+
+        for (name, variable) in base {
+            match head.get(name) {
+                Some(head_variable) => {
+                    let variable_diff = ServerVariableDiff::from_variables(variable, head_variable);
+
+                    if variable_diff.has_changes() {
+                        variables_changed.insert(name.clone(), variable_diff);
+                    }
+                }
+                None => variables_removed.push((name.clone(), variable.clone())),
+            }
+        }
+
+        for (name, variable) in head {
+            if !base.contains_key(name) {
+                variables_added.push((name.clone(), variable.clone()));
+            }
+        }
+
+        Self {
+            added: variables_added,
+            removed: variables_removed,
+            changed: variables_changed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct ServerVariableDiff {
+    pub enumeration: Option<StringListDiff>,
+    pub default: Option<StringDiff>,
+    pub description: Option<OptionalStringDiff>,
+    pub extensions: Option<ExtensionsDiff>,
+}
+
+impl ServerVariableDiff {
+    pub fn has_changes(&self) -> bool {
+        self.enumeration.is_some()
+            || self.default.is_some()
+            || self.description.is_some()
+            || self.extensions.is_some()
+    }
+
+    pub fn from_variables(base: &ServerVariable, head: &ServerVariable) -> Self {
+        let mut diff = Self::default();
+
+        let enumeration_diff = StringListDiff::from_lists(&base.enumeration, &head.enumeration);
+        if enumeration_diff.has_changes() {
+            diff.enumeration = Some(enumeration_diff);
+        }
+
+        diff.default = StringDiff::from_strings(base.default.clone(), head.default.clone());
+        diff.description = OptionalStringDiff::from_strings(&base.description, &head.description);
+
+        let extensions_diff = ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
+        if extensions_diff.has_changes() {
+            diff.extensions = Some(extensions_diff);
+        }
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn servers(yaml: &str) -> Vec<Server> {
+        serde_yaml::from_str(yaml).expect("Failed to parse servers")
+    }
+
+    #[test]
+    fn region_removed_from_enum() {
+        let base = servers(
+            "- url: https://{region}.api.example.com\n  variables:\n    region:\n      default: eu\n      enum: [eu, us]\n",
+        );
+        let head = servers(
+            "- url: https://{region}.api.example.com\n  variables:\n    region:\n      default: eu\n      enum: [eu]\n",
+        );
+
+        let diff = ServersDiff::from_servers(&base, &head);
+        let server_diff = &diff.changed["https://{region}.api.example.com"];
+        let variable_diff = &server_diff.variables.as_ref().unwrap().changed["region"];
+
+        assert_eq!(
+            vec!["us"],
+            variable_diff.enumeration.as_ref().unwrap().removed
+        );
+        assert_eq!(
+            vec!["https://us.api.example.com"],
+            server_diff.urls.as_ref().unwrap().removed
+        );
+    }
+
+    #[test]
+    fn template_changed_is_matched_by_urls() {
+        let base = servers(
+            "- url: https://{region}.api.example.com\n  variables:\n    region:\n      default: eu\n",
+        );
+        let head = servers(
+            "- url: https://{host}\n  variables:\n    host:\n      default: eu.api.example.com\n      enum: [eu.api.example.com, us.api.example.com]\n",
+        );
+
+        let diff = ServersDiff::from_servers(&base, &head);
+        let server_diff = &diff.changed["https://{region}.api.example.com"];
+
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!("https://{host}", server_diff.url.as_ref().unwrap().to);
+        assert_eq!(
+            vec!["https://us.api.example.com"],
+            server_diff.urls.as_ref().unwrap().added
+        );
+    }
+
+    #[test]
+    fn unrelated_templates_are_not_matched() {
+        let base = servers("- url: https://api.example.com\n");
+        let head = servers("- url: https://api.example.org\n");
+
+        let diff = ServersDiff::from_servers(&base, &head);
+
+        assert_eq!(1, diff.added.len());
+        assert_eq!(1, diff.removed.len());
+        assert!(diff.changed.is_empty());
     }
 }
@@ -0,0 +1,111 @@
+use super::common::OptionalStringDiff;
+use super::extensions::ExtensionsDiff;
+use super::external_docs::ExternalDocsDiff;
+use crate::openapi::Tag;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// The document-level tag definitions, matched by name.
+#[derive(Debug, Serialize)]
+pub struct TagsDiff {
+    pub added: Vec<Tag>,
+    pub removed: Vec<Tag>,
+    pub changed: HashMap<String, TagDiff>,
+}
+
+impl TagsDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    pub fn from_tags(base: &[Tag], head: &[Tag]) -> Self {
+        let mut tags_added = vec![];
+        let mut tags_removed = vec![];
+        let mut tags_changed: HashMap<String, TagDiff> = HashMap::new();
+
+        for tag in base {
+            match head.iter().find(|head_tag| head_tag.name == tag.name) {
+                Some(head_tag) => {
+                    let tag_diff = TagDiff::from_tags(tag, head_tag);
+
+                    if tag_diff.has_changes() {
+                        tags_changed.insert(tag.name.clone(), tag_diff);
+                    }
+                }
+                None => tags_removed.push(tag.clone()),
+            }
+        }
+
+        for tag in head {
+            if !base.iter().any(|base_tag| base_tag.name == tag.name) {
+                tags_added.push(tag.clone());
+            }
+        }
+
+        Self {
+            added: tags_added,
+            removed: tags_removed,
+            changed: tags_changed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct TagDiff {
+    pub description: Option<OptionalStringDiff>,
+    pub external_docs: Option<ExternalDocsDiff>,
+    pub extensions: Option<ExtensionsDiff>,
+}
+
+impl TagDiff {
+    pub fn has_changes(&self) -> bool {
+        self.description.is_some() || self.external_docs.is_some() || self.extensions.is_some()
+    }
+
+    pub fn from_tags(base: &Tag, head: &Tag) -> Self {
+        let mut diff = Self {
+            description: OptionalStringDiff::from_strings(&base.description, &head.description),
+            ..Self::default()
+        };
+
+        let external_docs_diff =
+            ExternalDocsDiff::from_docs(&base.external_docs, &head.external_docs);
+        if external_docs_diff.has_changes() {
+            diff.external_docs = Some(external_docs_diff);
+        }
+
+        let extensions_diff = ExtensionsDiff::from_extensions(&base.extensions, &head.extensions);
+        if extensions_diff.has_changes() {
+            diff.extensions = Some(extensions_diff);
+        }
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tag_description_changed() {
+        let base: Vec<Tag> =
+            serde_yaml::from_str("- name: pets\n  description: Pets\n- name: stores\n")
+                .expect("Failed to parse tags");
+        let head: Vec<Tag> = serde_yaml::from_str(
+            "- name: pets\n  description: Everything about pets\n  externalDocs:\n    url: https://example.com/pets\n",
+        )
+        .expect("Failed to parse tags");
+
+        let diff = TagsDiff::from_tags(&base, &head);
+        let tag_diff = &diff.changed["pets"];
+
+        assert!(diff.added.is_empty());
+        assert_eq!("stores", diff.removed[0].name);
+        assert_eq!(
+            Some("Everything about pets".into()),
+            tag_diff.description.as_ref().unwrap().to
+        );
+        assert!(tag_diff.external_docs.as_ref().unwrap().added.is_some());
+    }
+}
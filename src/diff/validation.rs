@@ -0,0 +1,899 @@
+use super::common::{escape, unescape, Direction};
+use super::resolver;
+use super::schema::{is_visible, EnumDiff};
+use crate::openapi::{AdditionalProperties, Components, ReferenceOr, Schema};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+use std::convert::TryFrom;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// A reason why an instance does not validate against a schema.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ValidationError {
+    /// A JSON Pointer to the invalid part of the instance.
+    pub path: String,
+    pub message: String,
+}
+
+/// Validates instances, such as examples, against the schemas of a single
+/// document. References that cannot be resolved accept any value.
+#[derive(Debug, Clone, Copy)]
+pub struct Validator<'a> {
+    components: Option<&'a Components>,
+    /// Required properties that are not part of the payload in this
+    /// direction, such as `readOnly` properties of a request, may be
+    /// missing.
+    direction: Direction,
+}
+
+impl<'a> Validator<'a> {
+    pub fn new(components: Option<&'a Components>, direction: Direction) -> Self {
+        Self {
+            components,
+            direction,
+        }
+    }
+
+    /// Validates against the subschema at a JSON Pointer returned by
+    /// `schema_examples`, or returns `None` when there is no such subschema.
+    pub fn validate_at(
+        &self,
+        schema: &ReferenceOr<Schema>,
+        pointer: &str,
+        value: &Value,
+    ) -> Option<Vec<ValidationError>> {
+        let schema = self.subschema(schema, pointer)?;
+        let mut errors = vec![];
+        self.validate_schema(schema, value, "", &mut vec![], &mut errors);
+        Some(errors)
+    }
+
+    /// Collects the `example` and `examples` of a schema and of each of its
+    /// subschemas, along with their JSON Pointer.
+    pub fn schema_examples(&self, schema: &ReferenceOr<Schema>) -> Vec<(String, Value)> {
+        let mut examples = vec![];
+        self.collect_ref(schema, String::new(), &mut vec![], &mut examples);
+        examples
+    }
+
+    fn subschema<'s>(
+        &'s self,
+        schema: &'s ReferenceOr<Schema>,
+        pointer: &str,
+    ) -> Option<&'s Schema> {
+        let mut current = resolver::schema(self.components, schema)?;
+        let mut tokens = pointer.split('/').skip(1).map(unescape);
+
+        while let Some(token) = tokens.next() {
+            current = match token.as_str() {
+                "properties" => resolver::inline_schema(
+                    self.components,
+                    current.properties.as_ref()?.get(&tokens.next()?)?,
+                )?,
+                "items" => resolver::inline_schema(self.components, current.items.as_deref()?)?,
+                "not" => resolver::schema(self.components, current.not.as_deref()?)?,
+                "examples" => {
+                    tokens.next()?;
+                    current
+                }
+                "additionalProperties" => match current.additional_properties.as_ref()? {
+                    AdditionalProperties::Schema(schema) => {
+                        resolver::schema(self.components, schema)?
+                    }
+                    AdditionalProperties::Any(_) => return None,
+                },
+                composition => {
+                    let schemas = match composition {
+                        "allOf" => current.all_of.as_ref()?,
+                        "anyOf" => current.any_of.as_ref()?,
+                        "oneOf" => current.one_of.as_ref()?,
+                        "prefixItems" => current.prefix_items.as_ref()?,
+                        _ => return None,
+                    };
+                    let index: usize = tokens.next()?.parse().ok()?;
+                    resolver::schema(self.components, schemas.get(index)?)?
+                }
+            };
+        }
+
+        Some(current)
+    }
+
+    fn collect_ref(
+        &self,
+        schema: &ReferenceOr<Schema>,
+        pointer: String,
+        references: &mut Vec<String>,
+        examples: &mut Vec<(String, Value)>,
+    ) {
+        if let ReferenceOr::Reference { reference } = schema {
+            if references.contains(reference) {
+                return;
+            }
+            references.push(reference.clone());
+        }
+
+        if let Some(resolved) = resolver::schema(self.components, schema) {
+            self.collect_schema(resolved, pointer, references, examples);
+        }
+
+        if let ReferenceOr::Reference { .. } = schema {
+            references.pop();
+        }
+    }
+
+    fn collect_schema(
+        &self,
+        schema: &Schema,
+        pointer: String,
+        references: &mut Vec<String>,
+        examples: &mut Vec<(String, Value)>,
+    ) {
+        if let Some(example) = &schema.example {
+            examples.push((pointer.clone(), example.clone()));
+        }
+
+        for (index, example) in schema.examples.iter().flatten().enumerate() {
+            examples.push((format!("{}/examples/{}", pointer, index), example.clone()));
+        }
+
+        for (name, property) in schema.properties.iter().flatten() {
+            self.collect_inline(
+                property,
+                format!("{}/properties/{}", pointer, escape(name)),
+                references,
+                examples,
+            );
+        }
+
+        if let Some(items) = &schema.items {
+            self.collect_inline(items, format!("{}/items", pointer), references, examples);
+        }
+
+        if let Some(AdditionalProperties::Schema(additional)) = &schema.additional_properties {
+            self.collect_ref(
+                additional,
+                format!("{}/additionalProperties", pointer),
+                references,
+                examples,
+            );
+        }
+
+        if let Some(not) = &schema.not {
+            self.collect_ref(not, format!("{}/not", pointer), references, examples);
+        }
+
+        let compositions = [
+            ("allOf", &schema.all_of),
+            ("anyOf", &schema.any_of),
+            ("oneOf", &schema.one_of),
+            ("prefixItems", &schema.prefix_items),
+        ];
+        for (keyword, schemas) in compositions.iter() {
+            for (index, subschema) in schemas.iter().flatten().enumerate() {
+                self.collect_ref(
+                    subschema,
+                    format!("{}/{}/{}", pointer, keyword, index),
+                    references,
+                    examples,
+                );
+            }
+        }
+    }
+
+    fn collect_inline(
+        &self,
+        schema: &Schema,
+        pointer: String,
+        references: &mut Vec<String>,
+        examples: &mut Vec<(String, Value)>,
+    ) {
+        match resolver::inline_reference(schema) {
+            Some(reference) => {
+                self.collect_ref(&ReferenceOr::ref_(reference), pointer, references, examples)
+            }
+            None => self.collect_schema(schema, pointer, references, examples),
+        }
+    }
+
+    /// `references` holds the references being validated, along with the
+    /// instance path they are validated at, to stop on recursive schemas
+    /// such as `A: { allOf: [A] }`.
+    fn validate_ref(
+        &self,
+        schema: &ReferenceOr<Schema>,
+        value: &Value,
+        path: &str,
+        references: &mut Vec<(String, String)>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let key = match schema {
+            ReferenceOr::Reference { reference } => {
+                let key = (reference.clone(), path.to_string());
+                if references.contains(&key) {
+                    return;
+                }
+                Some(key)
+            }
+            ReferenceOr::Item(_) => None,
+        };
+
+        if let Some(resolved) = resolver::schema(self.components, schema) {
+            if let Some(key) = &key {
+                references.push(key.clone());
+            }
+
+            self.validate_schema(resolved, value, path, references, errors);
+
+            if key.is_some() {
+                references.pop();
+            }
+        }
+    }
+
+    fn validate_inline(
+        &self,
+        schema: &Schema,
+        value: &Value,
+        path: &str,
+        references: &mut Vec<(String, String)>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        match resolver::inline_reference(schema) {
+            Some(reference) => self.validate_ref(
+                &ReferenceOr::ref_(reference),
+                value,
+                path,
+                references,
+                errors,
+            ),
+            None => self.validate_schema(schema, value, path, references, errors),
+        }
+    }
+
+    fn validate_schema(
+        &self,
+        schema: &Schema,
+        value: &Value,
+        path: &str,
+        references: &mut Vec<(String, String)>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mut error = |message: String| {
+            errors.push(ValidationError {
+                path: path.to_string(),
+                message,
+            })
+        };
+
+        if value.is_null() && schema.is_nullable() {
+            return;
+        }
+
+        let types = schema.types();
+        if !types.is_empty()
+            && !types
+                .iter()
+                .any(|schema_type| matches_type(schema_type, value))
+        {
+            error(format!(
+                "Expected {}, found {}.",
+                types.join(" or "),
+                type_name(value)
+            ));
+            return;
+        }
+
+        if let Some(format) = &schema.format {
+            if !matches_format(format, value) {
+                error(format!("Value does not match format `{}`.", format));
+            }
+        }
+
+        let allowed_values = match &schema.enum_values {
+            Some(values) => Some(values.clone()),
+            None => schema.const_value.clone().map(|value| vec![value]),
+        };
+        if let Some(allowed_values) = allowed_values {
+            if !allowed_values
+                .iter()
+                .any(|allowed| EnumDiff::values_equal(allowed, value))
+            {
+                error(String::from("Value is not one of the allowed values."));
+            }
+        }
+
+        match value {
+            Value::Number(number) => {
+                if let Some(number) = number.as_f64() {
+                    for message in number_errors(schema, number) {
+                        error(message);
+                    }
+                }
+            }
+            Value::String(string) => {
+                for message in string_errors(schema, string) {
+                    error(message);
+                }
+            }
+            Value::Array(items) => {
+                let count = items.len() as u32;
+                if schema.min_items.is_some_and(|min| count < min) {
+                    error(format!(
+                        "Expected at least {} items.",
+                        schema.min_items.unwrap()
+                    ));
+                }
+                if schema.max_items.is_some_and(|max| count > max) {
+                    error(format!(
+                        "Expected at most {} items.",
+                        schema.max_items.unwrap()
+                    ));
+                }
+                if schema.unique_items == Some(true) && !are_unique(items) {
+                    error(String::from("Expected unique items."));
+                }
+            }
+            Value::Object(object) => {
+                let count = object.len() as u32;
+                if schema.min_properties.is_some_and(|min| count < min) {
+                    error(format!(
+                        "Expected at least {} properties.",
+                        schema.min_properties.unwrap()
+                    ));
+                }
+                if schema.max_properties.is_some_and(|max| count > max) {
+                    error(format!(
+                        "Expected at most {} properties.",
+                        schema.max_properties.unwrap()
+                    ));
+                }
+                for name in schema.required.iter().flatten() {
+                    let visible = schema
+                        .properties
+                        .as_ref()
+                        .and_then(|properties| properties.get(name))
+                        .and_then(|property| resolver::inline_schema(self.components, property))
+                        .map_or(true, |property| is_visible(property, self.direction));
+                    if visible && !object.contains_key(name) {
+                        error(format!("Missing required property `{}`.", name));
+                    }
+                }
+                if schema.additional_properties == Some(AdditionalProperties::Any(false)) {
+                    for name in object.keys() {
+                        let declared = schema
+                            .properties
+                            .as_ref()
+                            .is_some_and(|properties| properties.contains_key(name));
+                        if !declared {
+                            error(format!("Unexpected property `{}`.", name));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if let Value::Array(items) = value {
+            // In OpenAPI 3.1, `items` only applies after the `prefixItems`.
+            let prefix_items = schema.prefix_items.as_deref().unwrap_or_default();
+            for (index, item) in items.iter().enumerate() {
+                let item_path = format!("{}/{}", path, index);
+                match prefix_items.get(index) {
+                    Some(prefix_item) => {
+                        self.validate_ref(prefix_item, item, &item_path, references, errors)
+                    }
+                    None => {
+                        if let Some(items_schema) = &schema.items {
+                            self.validate_inline(
+                                items_schema,
+                                item,
+                                &item_path,
+                                references,
+                                errors,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Value::Object(object) = value {
+            for (name, property) in object {
+                let property_path = format!("{}/{}", path, escape(name));
+                let property_schema = schema
+                    .properties
+                    .as_ref()
+                    .and_then(|properties| properties.get(name));
+
+                match (property_schema, &schema.additional_properties) {
+                    (Some(property_schema), _) => self.validate_inline(
+                        property_schema,
+                        property,
+                        &property_path,
+                        references,
+                        errors,
+                    ),
+                    (None, Some(AdditionalProperties::Schema(additional))) => {
+                        self.validate_ref(additional, property, &property_path, references, errors)
+                    }
+                    (None, _) => {}
+                }
+            }
+        }
+
+        for subschema in schema.all_of.iter().flatten() {
+            self.validate_ref(subschema, value, path, references, errors);
+        }
+
+        let mut matches = |schemas: &Option<Vec<ReferenceOr<Schema>>>| -> usize {
+            schemas
+                .iter()
+                .flatten()
+                .filter(|subschema| {
+                    let mut subschema_errors = vec![];
+                    self.validate_ref(subschema, value, path, references, &mut subschema_errors);
+                    subschema_errors.is_empty()
+                })
+                .count()
+        };
+
+        let any_of_matches = matches(&schema.any_of);
+        let one_of_matches = matches(&schema.one_of);
+        let not_matches = match &schema.not {
+            Some(not) => {
+                let mut not_errors = vec![];
+                self.validate_ref(not, value, path, references, &mut not_errors);
+                not_errors.is_empty()
+            }
+            None => false,
+        };
+
+        let mut error = |message: String| {
+            errors.push(ValidationError {
+                path: path.to_string(),
+                message,
+            })
+        };
+
+        if schema.any_of.is_some() && any_of_matches == 0 {
+            error(String::from("Value does not match any schema of `anyOf`."));
+        }
+
+        if schema.one_of.is_some() && one_of_matches != 1 {
+            error(format!(
+                "Value matches {} schemas of `oneOf`, expected exactly one.",
+                one_of_matches
+            ));
+        }
+
+        if not_matches {
+            error(String::from("Value matches the schema of `not`."));
+        }
+    }
+}
+
+fn matches_type(schema_type: &str, value: &Value) -> bool {
+    match schema_type {
+        "integer" => value.as_f64().is_some_and(|number| number.fract() == 0.0),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Checks the formats defined by OpenAPI and the common JSON Schema
+/// formats. Unknown formats accept any value.
+fn matches_format(format: &str, value: &Value) -> bool {
+    match (format, value) {
+        ("int32", Value::Number(number)) => number
+            .as_i64()
+            .is_some_and(|number| i32::try_from(number).is_ok()),
+        ("int64", Value::Number(number)) => number.as_i64().is_some() || number.is_f64(),
+        ("date", Value::String(string)) => is_date(string),
+        ("date-time", Value::String(string)) => is_date_time(string),
+        ("email", Value::String(string)) => is_email(string),
+        ("uuid", Value::String(string)) => is_uuid(string),
+        ("ipv4", Value::String(string)) => string.parse::<Ipv4Addr>().is_ok(),
+        ("ipv6", Value::String(string)) => string.parse::<Ipv6Addr>().is_ok(),
+        ("uri", Value::String(string)) => is_uri(string),
+        ("byte", Value::String(string)) => is_base64(string),
+        _ => true,
+    }
+}
+
+fn number_errors(schema: &Schema, number: f64) -> Vec<String> {
+    let mut errors = vec![];
+    let bound = |value: &Option<Value>| value.as_ref().and_then(Value::as_f64);
+
+    for (minimum, exclusive) in bounds(&schema.minimum, &schema.exclusive_minimum) {
+        if number < minimum || (exclusive && number == minimum) {
+            errors.push(format!(
+                "Expected a value {} {}.",
+                if exclusive { ">" } else { ">=" },
+                minimum
+            ));
+        }
+    }
+
+    for (maximum, exclusive) in bounds(&schema.maximum, &schema.exclusive_maximum) {
+        if number > maximum || (exclusive && number == maximum) {
+            errors.push(format!(
+                "Expected a value {} {}.",
+                if exclusive { "<" } else { "<=" },
+                maximum
+            ));
+        }
+    }
+
+    if let Some(multiple_of) = bound(&schema.multiple_of) {
+        let quotient = number / multiple_of;
+        if multiple_of > 0.0 && (quotient - quotient.round()).abs() > 1e-9 {
+            errors.push(format!("Expected a multiple of {}.", multiple_of));
+        }
+    }
+
+    errors
+}
+
+/// The lower or upper bounds of a number, and whether each is exclusive.
+/// `exclusiveMinimum` is a boolean modifying `minimum` in OpenAPI 3.0, and
+/// a number in 3.1 that applies along with `minimum`.
+fn bounds(inclusive: &Option<Value>, exclusive: &Option<Value>) -> Vec<(f64, bool)> {
+    let inclusive = inclusive.as_ref().and_then(Value::as_f64);
+
+    match exclusive {
+        Some(Value::Bool(exclusive)) => inclusive
+            .map(|bound| (bound, *exclusive))
+            .into_iter()
+            .collect(),
+        Some(Value::Number(exclusive)) => inclusive
+            .map(|bound| (bound, false))
+            .into_iter()
+            .chain(exclusive.as_f64().map(|bound| (bound, true)))
+            .collect(),
+        _ => inclusive.map(|bound| (bound, false)).into_iter().collect(),
+    }
+}
+
+fn string_errors(schema: &Schema, string: &str) -> Vec<String> {
+    let mut errors = vec![];
+    let length = string.chars().count() as u32;
+
+    if let Some(min_length) = schema.min_length {
+        if length < min_length {
+            errors.push(format!("Expected at least {} characters.", min_length));
+        }
+    }
+
+    if let Some(max_length) = schema.max_length {
+        if length > max_length {
+            errors.push(format!("Expected at most {} characters.", max_length));
+        }
+    }
+
+    // Patterns that are not valid regular expressions are ignored.
+    if let Some(pattern) = &schema.pattern {
+        if let Ok(regex) = Regex::new(pattern) {
+            if !regex.is_match(string) {
+                errors.push(format!("Value does not match pattern `{}`.", pattern));
+            }
+        }
+    }
+
+    errors
+}
+
+fn are_unique(items: &[Value]) -> bool {
+    items.iter().enumerate().all(|(index, item)| {
+        items[index + 1..]
+            .iter()
+            .all(|other| !EnumDiff::values_equal(item, other))
+    })
+}
+
+fn is_digits(value: &str, length: usize) -> bool {
+    value.len() == length && value.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// A `full-date` as defined by RFC 3339, e.g. `2020-02-29`.
+fn is_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+
+    match parts.as_slice() {
+        [year, month, day] if is_digits(year, 4) && is_digits(month, 2) && is_digits(day, 2) => {
+            let month: u32 = month.parse().unwrap_or(0);
+            let day: u32 = day.parse().unwrap_or(0);
+            (1..=12).contains(&month) && (1..=31).contains(&day)
+        }
+        _ => false,
+    }
+}
+
+/// A `date-time` as defined by RFC 3339, e.g. `2020-02-29T12:00:00.5+01:00`.
+fn is_date_time(value: &str) -> bool {
+    let (date, time) = match value.find(['T', 't']) {
+        Some(index) => (&value[..index], &value[index + 1..]),
+        None => return false,
+    };
+
+    let (time, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(index) => (&time[..index], &time[index..]),
+        None => return false,
+    };
+
+    let time = time.split('.').next().unwrap_or_default();
+    let valid_time = match time.split(':').collect::<Vec<_>>().as_slice() {
+        [hours, minutes, seconds] => {
+            is_digits(hours, 2) && is_digits(minutes, 2) && is_digits(seconds, 2)
+        }
+        _ => false,
+    };
+    let valid_offset = match offset {
+        "Z" | "z" => true,
+        offset => match offset[1..].split(':').collect::<Vec<_>>().as_slice() {
+            [hours, minutes] => is_digits(hours, 2) && is_digits(minutes, 2),
+            _ => false,
+        },
+    };
+
+    is_date(date) && valid_time && valid_offset
+}
+
+fn is_email(value: &str) -> bool {
+    match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.is_empty()
+                && !domain.contains('@')
+                && !value.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// An absolute URI, which must start with a scheme.
+fn is_uri(value: &str) -> bool {
+    match value.split_once(':') {
+        Some((scheme, _)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+                && !value.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+fn is_base64(value: &str) -> bool {
+    value.len() % 4 == 0
+        && value
+            .trim_end_matches('=')
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema(yaml: &str) -> ReferenceOr<Schema> {
+        serde_yaml::from_str(yaml).expect("Failed to parse schema")
+    }
+
+    fn validate(
+        validator: &Validator,
+        schema: &ReferenceOr<Schema>,
+        value: &Value,
+    ) -> Vec<ValidationError> {
+        validator
+            .validate_at(schema, "", value)
+            .expect("Failed to resolve schema")
+    }
+
+    fn is_valid(validator: &Validator, schema: &ReferenceOr<Schema>, value: &Value) -> bool {
+        validate(validator, schema, value).is_empty()
+    }
+
+    #[test]
+    fn types_and_formats() {
+        let validator = Validator::new(None, Direction::Request);
+
+        assert!(is_valid(&validator, &schema("type: integer"), &json!(3)));
+        assert!(!is_valid(&validator, &schema("type: integer"), &json!(3.5)));
+        assert!(is_valid(
+            &validator,
+            &schema("type: string\nformat: date"),
+            &json!("2020-02-29")
+        ));
+        assert!(!is_valid(
+            &validator,
+            &schema("type: string\nformat: date"),
+            &json!("29/02/2020")
+        ));
+        assert!(!is_valid(
+            &validator,
+            &schema("type: integer\nformat: int32"),
+            &json!(1u64 << 40)
+        ));
+        assert!(is_valid(
+            &validator,
+            &schema("type: string\nnullable: true"),
+            &json!(null)
+        ));
+    }
+
+    #[test]
+    fn json_schema_2020_12() {
+        let validator = Validator::new(None, Direction::Request);
+        let pair = schema(
+            "type: array\nprefixItems:\n  - type: [string, \"null\"]\nitems:\n  type: integer\nexamples:\n  - [a, 1]\n  - [1, a]\n",
+        );
+
+        assert!(is_valid(&validator, &pair, &json!([null, 1, 2])));
+        assert_eq!(
+            "Expected string or null, found integer.",
+            validate(&validator, &pair, &json!([1]))[0].message
+        );
+
+        let examples = validator.schema_examples(&pair);
+        assert_eq!("/examples/1", examples[1].0);
+        let errors = validator
+            .validate_at(&pair, &examples[1].0, &examples[1].1)
+            .expect("Failed to resolve schema");
+        assert_eq!(2, errors.len());
+    }
+
+    #[test]
+    fn objects() {
+        let validator = Validator::new(None, Direction::Request);
+        let pet = schema(
+            "type: object\nrequired: [name]\nadditionalProperties: false\nproperties:\n  name:\n    type: string\n    minLength: 1\n",
+        );
+
+        assert!(is_valid(&validator, &pet, &json!({ "name": "Tom" })));
+
+        let errors = validate(&validator, &pet, &json!({ "name": "", "age": 3 }));
+        assert_eq!(2, errors.len());
+        assert_eq!("/name", errors[1].path);
+
+        let errors = validate(&validator, &pet, &json!({}));
+        assert_eq!("Missing required property `name`.", errors[0].message);
+    }
+
+    #[test]
+    fn constraints() {
+        let validator = Validator::new(None, Direction::Request);
+        let ids = schema(
+            "type: array\nuniqueItems: true\nmaxItems: 2\nitems:\n  type: integer\n  minimum: 0\n  exclusiveMinimum: true\n",
+        );
+
+        assert!(is_valid(&validator, &ids, &json!([1, 2])));
+        assert!(!is_valid(&validator, &ids, &json!([1, 1])));
+        assert!(!is_valid(&validator, &ids, &json!([1, 2, 3])));
+        assert_eq!("/0", validate(&validator, &ids, &json!([0]))[0].path);
+        assert!(!is_valid(
+            &validator,
+            &schema("type: string\npattern: '^[a-z]+$'"),
+            &json!("A1")
+        ));
+    }
+
+    #[test]
+    fn numeric_exclusive_bounds() {
+        let validator = Validator::new(None, Direction::Request);
+        let quantity = schema(
+            "type: integer\nminimum: 5\nexclusiveMinimum: 0\nmaximum: 100\nexclusiveMaximum: 10\n",
+        );
+
+        assert!(is_valid(&validator, &quantity, &json!(5)));
+        assert!(is_valid(&validator, &quantity, &json!(9)));
+        assert!(!is_valid(&validator, &quantity, &json!(1)));
+        assert!(!is_valid(&validator, &quantity, &json!(10)));
+        assert_eq!(
+            "Expected a value >= 5.",
+            validate(&validator, &quantity, &json!(1))[0].message
+        );
+    }
+
+    #[test]
+    fn composition() {
+        let validator = Validator::new(None, Direction::Request);
+        let one_of = schema("oneOf:\n  - type: integer\n  - type: number\n");
+
+        assert!(is_valid(&validator, &one_of, &json!(1.5)));
+        assert!(!is_valid(&validator, &one_of, &json!(1)));
+        assert!(!is_valid(
+            &validator,
+            &schema("not:\n  type: string\n"),
+            &json!("a")
+        ));
+        assert!(is_valid(
+            &validator,
+            &schema("anyOf:\n  - type: string\n  - type: boolean\n"),
+            &json!(true)
+        ));
+    }
+
+    #[test]
+    fn recursive_references() {
+        let components: Components = serde_yaml::from_str(
+            "schemas:\n  Node:\n    type: object\n    allOf:\n      - $ref: '#/components/schemas/Node'\n    additionalProperties:\n      $ref: '#/components/schemas/Node'\n    example:\n      child: {}\n",
+        )
+        .expect("Failed to parse components");
+        let validator = Validator::new(Some(&components), Direction::Request);
+        let node = ReferenceOr::ref_("#/components/schemas/Node");
+
+        assert!(is_valid(
+            &validator,
+            &node,
+            &json!({ "child": { "child": {} } })
+        ));
+        assert!(!is_valid(&validator, &node, &json!({ "child": 1 })));
+        assert_eq!(1, validator.schema_examples(&node).len());
+    }
+
+    #[test]
+    fn referenced_properties() {
+        let components: Components = serde_yaml::from_str(
+            "schemas:\n  Owner:\n    type: object\n    properties:\n      age:\n        type: integer\n",
+        )
+        .expect("Failed to parse components");
+        let validator = Validator::new(Some(&components), Direction::Request);
+        let pet = schema(
+            "type: object\nproperties:\n  owner:\n    $ref: '#/components/schemas/Owner'\n  owners:\n    type: array\n    items:\n      $ref: '#/components/schemas/Owner'\n",
+        );
+
+        let errors = validate(&validator, &pet, &json!({ "owner": { "age": "x" } }));
+        assert_eq!(1, errors.len());
+        assert_eq!("/owner/age", errors[0].path);
+        assert_eq!(
+            "/owners/0/age",
+            validate(&validator, &pet, &json!({ "owners": [{ "age": "x" }] }))[0].path
+        );
+    }
+
+    #[test]
+    fn required_properties_by_direction() {
+        let pet = schema(
+            "type: object\nrequired: [id, password]\nproperties:\n  id:\n    type: integer\n    readOnly: true\n  password:\n    type: string\n    writeOnly: true\n",
+        );
+        let request = Validator::new(None, Direction::Request);
+        let response = Validator::new(None, Direction::Response);
+
+        let errors = validate(&request, &pet, &json!({}));
+        assert_eq!(1, errors.len());
+        assert_eq!("Missing required property `password`.", errors[0].message);
+
+        let errors = validate(&response, &pet, &json!({}));
+        assert_eq!(1, errors.len());
+        assert_eq!("Missing required property `id`.", errors[0].message);
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
-use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Holds a set of reusable objects for different aspects of the OAS.
 /// All objects defined within the components object will have no effect
@@ -11,34 +11,38 @@ use serde::{Deserialize, Serialize};
 pub struct Components {
     /// An object to hold reusable Security Scheme Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub security_schemes:BTreeMap<String, ReferenceOr<SecurityScheme>>,
+    pub security_schemes: BTreeMap<String, ReferenceOr<SecurityScheme>>,
     /// An object to hold reusable Response Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub responses:BTreeMap<String, ReferenceOr<Response>>,
+    pub responses: BTreeMap<String, ReferenceOr<Response>>,
     /// An object to hold reusable Parameter Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub parameters:BTreeMap<String, ReferenceOr<Parameter>>,
+    pub parameters: BTreeMap<String, ReferenceOr<Parameter>>,
     /// An object to hold reusable Example Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub examples:BTreeMap<String, ReferenceOr<Example>>,
+    pub examples: BTreeMap<String, ReferenceOr<Example>>,
     /// An object to hold reusable Request Body Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub request_bodies:BTreeMap<String, ReferenceOr<RequestBody>>,
+    pub request_bodies: BTreeMap<String, ReferenceOr<RequestBody>>,
     /// An object to hold reusable Header Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub headers:BTreeMap<String, ReferenceOr<Header>>,
+    pub headers: BTreeMap<String, ReferenceOr<Header>>,
     /// An object to hold reusable Schema Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub schemas:BTreeMap<String, ReferenceOr<Schema>>,
+    pub schemas: BTreeMap<String, ReferenceOr<Schema>>,
     /// An object to hold reusable Link Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub links:BTreeMap<String, ReferenceOr<Link>>,
+    pub links: BTreeMap<String, ReferenceOr<Link>>,
     /// An object to hold reusable Callback Objects.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub callbacks:BTreeMap<String, ReferenceOr<Callback>>,
+    pub callbacks: BTreeMap<String, ReferenceOr<Callback>>,
+
+    /// OpenAPI 3.1 only.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub path_items: BTreeMap<String, ReferenceOr<PathItem>>,
     /// Inline extensions to this object.
     #[serde(flatten)]
-    pub extensions:BTreeMap<String, serde_json::Value>,
+    pub extensions: BTreeMap<String, serde_json::Value>,
 }
 
 /// A map of possible out-of band callbacks related to the parent operation.
@@ -46,4 +50,4 @@ pub struct Components {
 /// requests that may be initiated by the API provider and the expected responses.
 /// The key value used to identify the callback object is an expression,
 /// evaluated at runtime, that identifies a URL to use for the callback operation.
-pub type Callback =BTreeMap<String, PathItem>;
+pub type Callback = BTreeMap<String, PathItem>;
@@ -15,7 +15,20 @@ pub struct OpenAPI {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub servers: Vec<Server>,
+    /// Optional in OpenAPI 3.1, where a document may only describe
+    /// webhooks or components.
+    #[serde(default)]
     pub paths: Paths,
+    /// OpenAPI 3.1 only. The incoming requests that the API provider may
+    /// initiate, keyed by a unique name.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub webhooks: Paths,
+    /// OpenAPI 3.1 only. The default `$schema` for Schema Objects in
+    /// this document.
+    #[serde(rename = "jsonSchemaDialect")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_schema_dialect: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub components: Option<Components>,
     #[serde(default)]
@@ -15,6 +15,9 @@ pub struct MediaType {
     pub examples: BTreeMap<String, ReferenceOr<Example>>,
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub encoding: BTreeMap<String, Encoding>,
+    /// Inline extensions to this object.
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, serde_json::Value>,
 }
 
 /// A single encoding definition applied to a single schema property.
@@ -1,6 +1,6 @@
 use super::*;
-use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Describes a single API operation on a path.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -46,6 +46,10 @@ pub struct Operation {
     /// the HTTP spec is vague, requestBody SHALL be ignored by consumers.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_body: Option<ReferenceOr<RequestBody>>,
+    /// A map of possible out-of band callbacks related to the parent
+    /// operation. The key is a unique identifier for the Callback Object.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub callbacks: BTreeMap<String, ReferenceOr<Callback>>,
     /// REQUIRED. The list of possible responses as they are returned
     /// from executing this operation.
     pub responses: Responses,
@@ -67,5 +71,5 @@ pub struct Operation {
     pub servers: Vec<Server>,
     /// Inline extensions to this object.
     #[serde(flatten)]
-    pub extensions:BTreeMap<String, serde_json::Value>,
+    pub extensions: BTreeMap<String, serde_json::Value>,
 }
@@ -48,6 +48,7 @@ pub struct ParameterData {
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum ParameterSchemaOrContent {
     #[serde(rename = "schema")]
     Schema(ReferenceOr<Schema>),
@@ -112,24 +113,21 @@ impl SkipSerializeIfDefault {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub enum PathStyle {
     #[serde(rename = "matrix")]
     Matrix,
     #[serde(rename = "label")]
     Label,
     #[serde(rename = "simple")]
+    #[default]
     Simple,
 }
 
-impl Default for PathStyle {
-    fn default() -> Self {
-        PathStyle::Simple
-    }
-}
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub enum QueryStyle {
     #[serde(rename = "form")]
+    #[default]
     Form,
     #[serde(rename = "spaceDelimited")]
     SpaceDelimited,
@@ -139,34 +137,20 @@ pub enum QueryStyle {
     DeepObject,
 }
 
-impl Default for QueryStyle {
-    fn default() -> Self {
-        QueryStyle::Form
-    }
-}
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub enum CookieStyle {
     #[serde(rename = "form")]
+    #[default]
     Form,
 }
 
-impl Default for CookieStyle {
-    fn default() -> CookieStyle {
-        CookieStyle::Form
-    }
-}
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub enum HeaderStyle {
     #[serde(rename = "simple")]
+    #[default]
     Simple,
 }
 
-impl Default for HeaderStyle {
-    fn default() -> HeaderStyle {
-        HeaderStyle::Simple
-    }
-}
-
 fn default_as_false() -> bool {
     false
 }
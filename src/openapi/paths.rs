@@ -47,6 +47,33 @@ pub struct PathItem {
     pub extensions: BTreeMap<String, serde_json::Value>,
 }
 
+impl PathItem {
+    /// The operations of the path item and their lowercase HTTP methods.
+    pub fn operations(&self) -> Vec<(&'static str, &Operation)> {
+        vec![
+            ("get", &self.get),
+            ("put", &self.put),
+            ("post", &self.post),
+            ("delete", &self.delete),
+            ("options", &self.options),
+            ("head", &self.head),
+            ("patch", &self.patch),
+            ("trace", &self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| Some((method, operation.as_ref()?)))
+        .collect()
+    }
+
+    /// The operation for a lowercase HTTP method.
+    pub fn operation(&self, method: &str) -> Option<&Operation> {
+        self.operations()
+            .into_iter()
+            .find(|(operation_method, _)| *operation_method == method)
+            .map(|(_, operation)| operation)
+    }
+}
+
 /// Holds the relative paths to the individual endpoints and
 /// their operations. The path is appended to the URL from the
 /// Server Object in order to construct the full URL. The Paths
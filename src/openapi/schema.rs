@@ -1,6 +1,6 @@
 use super::reference::ReferenceOr;
-use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// The Schema Object allows the definition of input and output data types.
 /// These types can be objects, but also primitives and arrays.
@@ -17,16 +17,26 @@ pub struct Schema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// A single type, or in OpenAPI 3.1 a list of types such as
+    /// `[string, "null"]`.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "type")]
-    pub schema_type: Option<String>,
+    pub schema_type: Option<SchemaType>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
 
+    /// The allowed values for this schema. Values can be of any JSON type,
+    /// including `null`.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "enum")]
-    pub enum_values: Option<Vec<Option<String>>>,
+    pub enum_values: Option<Vec<serde_json::Value>>,
+
+    /// OpenAPI 3.1 (JSON Schema 2020-12) `const`, equivalent to an `enum`
+    /// with a single value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "const")]
+    pub const_value: Option<serde_json::Value>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<Vec<String>>,
@@ -34,12 +44,26 @@ pub struct Schema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Box<Schema>>,
 
+    /// OpenAPI 3.1 (JSON Schema 2020-12) tuple validation: the schemas of
+    /// the leading array items, by position.
+    #[serde(rename = "prefixItems", skip_serializing_if = "Option::is_none")]
+    pub prefix_items: Option<Vec<ReferenceOr<Schema>>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<BTreeMap<String, Schema>>,
 
+    /// Relevant only for Schema "properties" definitions. Declares the property
+    /// as "read only". This means that it MAY be sent as part of a response but
+    /// SHOULD NOT be sent as part of the request. Default value is false.
     #[serde(skip_serializing_if = "Option::is_none", rename = "readOnly")]
     pub read_only: Option<bool>,
 
+    /// Relevant only for Schema "properties" definitions. Declares the property
+    /// as "write only". Therefore, it MAY be sent as part of a request but
+    /// SHOULD NOT be sent as part of the response. Default value is false.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "writeOnly")]
+    pub write_only: Option<bool>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nullable: Option<bool>,
 
@@ -64,9 +88,18 @@ pub struct Schema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub example: Option<serde_json::value::Value>,
 
+    /// OpenAPI 3.1 (JSON Schema 2020-12) replacement for `example`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<serde_json::Value>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
+    /// Specifies that a schema is deprecated and SHOULD be transitioned out
+    /// of usage. Default value is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+
     /// The default value represents what would be assumed by the consumer of the input as the value
     /// of the schema if one is not provided. Unlike JSON Schema, the value MUST conform to the
     /// defined type for the Schema Object defined at the same level. For example, if type is
@@ -77,6 +110,22 @@ pub struct Schema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub minimum: Option<serde_json::Value>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<serde_json::Value>,
+
+    /// A boolean modifying `minimum` in OpenAPI 3.0, or the exclusive bound
+    /// itself in OpenAPI 3.1.
+    #[serde(rename = "exclusiveMinimum", skip_serializing_if = "Option::is_none")]
+    pub exclusive_minimum: Option<serde_json::Value>,
+
+    /// A boolean modifying `maximum` in OpenAPI 3.0, or the exclusive bound
+    /// itself in OpenAPI 3.1.
+    #[serde(rename = "exclusiveMaximum", skip_serializing_if = "Option::is_none")]
+    pub exclusive_maximum: Option<serde_json::Value>,
+
+    #[serde(rename = "multipleOf", skip_serializing_if = "Option::is_none")]
+    pub multiple_of: Option<serde_json::Value>,
+
     /// Inline or referenced schema MUST be of a [Schema Object](#schemaObject) and not a standard
     /// JSON Schema.
     /// [allOf](https://swagger.io/docs/specification/data-models/oneof-anyof-allof-not/#allof)
@@ -99,7 +148,7 @@ pub struct Schema {
     /// JSON Schema.
     /// [not](https://swagger.io/docs/specification/data-models/oneof-anyof-allof-not/#not)
     #[serde(rename = "not", skip_serializing_if = "Option::is_none")]
-    pub not: Option<Vec<ReferenceOr<Schema>>>,
+    pub not: Option<Box<ReferenceOr<Schema>>>,
 
     #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
     pub max_length: Option<u32>,
@@ -107,9 +156,63 @@ pub struct Schema {
     #[serde(rename = "minLength", skip_serializing_if = "Option::is_none")]
     pub min_length: Option<u32>,
 
+    /// A regular expression, as defined by ECMA 262, that string values must match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    #[serde(rename = "maxItems", skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<u32>,
+
+    #[serde(rename = "minItems", skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<u32>,
+
+    #[serde(rename = "uniqueItems", skip_serializing_if = "Option::is_none")]
+    pub unique_items: Option<bool>,
+
+    #[serde(rename = "maxProperties", skip_serializing_if = "Option::is_none")]
+    pub max_properties: Option<u32>,
+
+    #[serde(rename = "minProperties", skip_serializing_if = "Option::is_none")]
+    pub min_properties: Option<u32>,
+
+    /// OpenAPI 3.1 (JSON Schema 2020-12) schemas local to this schema,
+    /// referenced as `#/$defs/{name}` from within the schema resource.
+    #[serde(rename = "$defs", skip_serializing_if = "Option::is_none")]
+    pub defs: Option<BTreeMap<String, Schema>>,
+
     /// [Specification extensions](https://github.com/OAI/OpenAPI-Specification/blob/master/versions/3.0.2.md#specificationExtensions)
     #[serde(flatten)]
-    pub extensions:BTreeMap<String, serde_json::Value>,
+    pub extensions: BTreeMap<String, serde_json::Value>,
+}
+
+impl Schema {
+    /// The declared types, including `null` if it is listed.
+    pub fn types(&self) -> Vec<&str> {
+        match &self.schema_type {
+            Some(SchemaType::Single(schema_type)) => vec![schema_type.as_str()],
+            Some(SchemaType::Multiple(types)) => types.iter().map(String::as_str).collect(),
+            None => vec![],
+        }
+    }
+
+    /// Whether `null` is accepted, either through the OpenAPI 3.0 `nullable`
+    /// keyword or a 3.1 `null` type.
+    pub fn is_nullable(&self) -> bool {
+        self.nullable == Some(true) || self.types().contains(&"null")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum SchemaType {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl From<&str> for SchemaType {
+    fn from(schema_type: &str) -> Self {
+        SchemaType::Single(schema_type.to_string())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
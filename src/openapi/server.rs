@@ -1,5 +1,5 @@
-use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct Server {
@@ -9,7 +9,7 @@ pub struct Server {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variables: Option<BTreeMap<String, ServerVariable>>,
     #[serde(flatten)]
-    pub extensions:BTreeMap<String, serde_json::Value>,
+    pub extensions: BTreeMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -21,5 +21,109 @@ pub struct ServerVariable {
     pub default: String,
     pub description: Option<String>,
     #[serde(flatten)]
-    pub extensions:BTreeMap<String, serde_json::Value>,
+    pub extensions: BTreeMap<String, serde_json::Value>,
+}
+
+/// The most URLs `Server::urls` expands a template to.
+const MAX_URLS: usize = 256;
+
+impl Server {
+    /// The concrete URLs the template can expand to, using each
+    /// variable's `enum` values, or its `default` when there is no `enum`
+    /// or expanding the `enum` would give more than `MAX_URLS` URLs.
+    /// Placeholders without a variable are kept as they are.
+    pub fn urls(&self) -> Vec<String> {
+        let mut urls = vec![String::new()];
+        let mut rest = self.url.as_str();
+
+        while let Some(start) = rest.find('{') {
+            let end = match rest[start..].find('}') {
+                Some(end) => start + end,
+                None => break,
+            };
+
+            let name = &rest[start + 1..end];
+            let values = match self.variables.as_ref().and_then(|v| v.get(name)) {
+                Some(variable)
+                    if !variable.enumeration.is_empty()
+                        && urls.len() * variable.enumeration.len() <= MAX_URLS =>
+                {
+                    variable.enumeration.clone()
+                }
+                Some(variable) => vec![variable.default.clone()],
+                None => vec![rest[start..=end].to_string()],
+            };
+
+            urls = urls
+                .iter()
+                .flat_map(|url| {
+                    values
+                        .iter()
+                        .map(move |value| format!("{}{}{}", url, &rest[..start], value))
+                })
+                .collect();
+            rest = &rest[end + 1..];
+        }
+
+        urls.into_iter().map(|url| url + rest).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variable(enumeration: Vec<String>) -> ServerVariable {
+        ServerVariable {
+            default: enumeration[0].clone(),
+            enumeration,
+            ..ServerVariable::default()
+        }
+    }
+
+    #[test]
+    fn expands_variables() {
+        let server = Server {
+            url: "https://{region}.example.com/{version}/{id}".into(),
+            variables: Some(
+                vec![
+                    (
+                        "region".to_string(),
+                        variable(vec!["eu".into(), "us".into()]),
+                    ),
+                    ("version".to_string(), variable(vec!["v1".into()])),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            ..Server::default()
+        };
+
+        assert_eq!(
+            server.urls(),
+            vec![
+                "https://eu.example.com/v1/{id}",
+                "https://us.example.com/v1/{id}"
+            ]
+        );
+    }
+
+    #[test]
+    fn caps_expansion() {
+        let values: Vec<String> = (0..100).map(|n| n.to_string()).collect();
+        let server = Server {
+            url: "https://{a}.example.com/{b}/{c}".into(),
+            variables: Some(
+                vec!["a", "b", "c"]
+                    .into_iter()
+                    .map(|name| (name.to_string(), variable(values.clone())))
+                    .collect(),
+            ),
+            ..Server::default()
+        };
+        let urls = server.urls();
+
+        assert_eq!(urls.len(), 100);
+        assert!(urls.contains(&"https://42.example.com/0/0".to_string()));
+    }
 }
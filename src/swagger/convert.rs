@@ -0,0 +1,593 @@
+use super::document::{
+    Items, Operation, Parameter, ParameterLocation, PathItem, Response, SecurityScheme, Swagger,
+};
+use crate::openapi::{self, ReferenceOr, Schema};
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The media type assumed when neither the document nor the operation
+/// declares `consumes` or `produces`.
+const DEFAULT_MEDIA_TYPE: &str = "application/json";
+
+const FORM_MEDIA_TYPES: [&str; 2] = ["application/x-www-form-urlencoded", "multipart/form-data"];
+
+/// Converts a Swagger 2.0 document to the OpenAPI 3 model, the way
+/// the OpenAPI 3.0 specification maps each 2.0 construct.
+///
+/// The `openapi` field keeps the original `2.0` version, so that
+/// comparing a converted document with a 3.0 one still reports the
+/// version change.
+impl From<Swagger> for openapi::OpenAPI {
+    fn from(swagger: Swagger) -> Self {
+        let converter = Converter { swagger: &swagger };
+
+        let paths = swagger
+            .paths
+            .iter()
+            .filter(|(path, _)| !path.starts_with("x-"))
+            .map(|(path, path_item)| {
+                (
+                    path.clone(),
+                    ReferenceOr::Item(converter.path_item(path_item)),
+                )
+            })
+            .collect();
+
+        let components = openapi::Components {
+            schemas: swagger
+                .definitions
+                .iter()
+                .map(|(name, schema)| {
+                    (
+                        name.clone(),
+                        rewrite_schema(&ReferenceOr::Item(schema.clone())),
+                    )
+                })
+                .collect(),
+            parameters: swagger
+                .parameters
+                .iter()
+                .filter(|(_, parameter)| !is_body(parameter))
+                .map(|(name, parameter)| {
+                    (
+                        name.clone(),
+                        ReferenceOr::Item(converter.parameter(parameter)),
+                    )
+                })
+                .collect(),
+            responses: swagger
+                .responses
+                .iter()
+                .map(|(name, response)| {
+                    (
+                        name.clone(),
+                        ReferenceOr::Item(converter.response(response, &swagger.produces)),
+                    )
+                })
+                .collect(),
+            security_schemes: swagger
+                .security_definitions
+                .iter()
+                .map(|(name, scheme)| (name.clone(), ReferenceOr::Item(security_scheme(scheme))))
+                .collect(),
+            ..openapi::Components::default()
+        };
+
+        openapi::OpenAPI {
+            openapi: swagger.swagger.clone(),
+            info: swagger.info.clone(),
+            servers: servers(&swagger),
+            paths,
+            webhooks: BTreeMap::new(),
+            json_schema_dialect: None,
+            components: Some(components),
+            security: swagger.security.clone(),
+            tags: swagger.tags.clone(),
+            external_docs: swagger.external_docs.clone(),
+            extensions: extensions(&swagger.extensions),
+        }
+    }
+}
+
+struct Converter<'a> {
+    swagger: &'a Swagger,
+}
+
+impl<'a> Converter<'a> {
+    /// Body and form parameters become the request body of each operation,
+    /// so only the other path level parameters are kept.
+    fn path_item(&self, path_item: &PathItem) -> openapi::PathItem {
+        let operation = |operation: &Option<Operation>| {
+            operation
+                .as_ref()
+                .map(|operation| self.operation(operation, &path_item.parameters))
+        };
+
+        openapi::PathItem {
+            get: operation(&path_item.get),
+            put: operation(&path_item.put),
+            post: operation(&path_item.post),
+            delete: operation(&path_item.delete),
+            options: operation(&path_item.options),
+            head: operation(&path_item.head),
+            patch: operation(&path_item.patch),
+            parameters: self.parameters(&path_item.parameters),
+            extensions: extensions(&path_item.extensions),
+            ..openapi::PathItem::default()
+        }
+    }
+
+    fn operation(
+        &self,
+        operation: &Operation,
+        path_parameters: &[ReferenceOr<Parameter>],
+    ) -> openapi::Operation {
+        let consumes = operation
+            .consumes
+            .as_ref()
+            .unwrap_or(&self.swagger.consumes);
+        let produces = operation
+            .produces
+            .as_ref()
+            .unwrap_or(&self.swagger.produces);
+
+        // Operation parameters override path parameters with the same
+        // name and location.
+        let operation_parameters: Vec<&Parameter> = operation
+            .parameters
+            .iter()
+            .filter_map(|parameter| self.resolve(parameter))
+            .collect();
+        let inherited_parameters = path_parameters
+            .iter()
+            .filter_map(|parameter| self.resolve(parameter))
+            .filter(|parameter| {
+                !operation_parameters.iter().any(|overriding| {
+                    overriding.name == parameter.name && overriding.location == parameter.location
+                })
+            });
+        let body_parameters: Vec<&Parameter> = operation_parameters
+            .iter()
+            .copied()
+            .chain(inherited_parameters)
+            .filter(|parameter| is_body(parameter))
+            .collect();
+
+        let mut responses = openapi::Responses::default();
+        for (status, response) in &operation.responses {
+            let response = match response {
+                ReferenceOr::Reference { reference } => ReferenceOr::ref_(&rewrite(reference)),
+                ReferenceOr::Item(response) => ReferenceOr::Item(self.response(response, produces)),
+            };
+
+            if status == "default" {
+                responses.default = Some(response);
+            } else if let Ok(status) =
+                openapi::StatusCode::deserialize(
+                    IntoDeserializer::<serde::de::value::Error>::into_deserializer(status.as_str()),
+                )
+            {
+                responses.responses.insert(status, response);
+            }
+        }
+
+        openapi::Operation {
+            tags: operation.tags.clone(),
+            summary: operation.summary.clone(),
+            description: operation.description.clone(),
+            external_docs: operation.external_docs.clone(),
+            operation_id: operation.operation_id.clone(),
+            parameters: self.parameters(&operation.parameters),
+            request_body: self
+                .request_body(&body_parameters, consumes)
+                .map(ReferenceOr::Item),
+            responses,
+            deprecated: operation.deprecated,
+            security: operation.security.clone(),
+            extensions: extensions(&operation.extensions),
+            ..openapi::Operation::default()
+        }
+    }
+
+    /// Converts the parameters that are not part of the request body,
+    /// keeping references to shared parameters.
+    fn parameters(
+        &self,
+        parameters: &[ReferenceOr<Parameter>],
+    ) -> Vec<ReferenceOr<openapi::Parameter>> {
+        parameters
+            .iter()
+            .filter(|parameter| !self.resolve(parameter).is_some_and(is_body))
+            .map(|parameter| match parameter {
+                ReferenceOr::Reference { reference } => ReferenceOr::ref_(&rewrite(reference)),
+                ReferenceOr::Item(parameter) => ReferenceOr::Item(self.parameter(parameter)),
+            })
+            .collect()
+    }
+
+    fn parameter(&self, parameter: &Parameter) -> openapi::Parameter {
+        let parameter_data = openapi::ParameterData {
+            name: parameter.name.clone(),
+            description: parameter.description.clone(),
+            required: parameter.required,
+            deprecated: None,
+            format: openapi::ParameterSchemaOrContent::Schema(ReferenceOr::Item(items_schema(
+                &parameter.items,
+            ))),
+            example: None,
+            examples: BTreeMap::new(),
+            explode: match parameter.items.collection_format.as_deref() {
+                Some("multi") => Some(true),
+                _ if parameter.items.item_type.as_deref() == Some("array") => Some(false),
+                _ => None,
+            },
+            extensions: extensions(&parameter.items.extensions),
+        };
+
+        match parameter.location {
+            ParameterLocation::Header => openapi::Parameter::Header {
+                parameter_data,
+                style: openapi::HeaderStyle::Simple,
+            },
+            ParameterLocation::Path => openapi::Parameter::Path {
+                parameter_data,
+                style: openapi::PathStyle::Simple,
+            },
+            ParameterLocation::Query | ParameterLocation::FormData | ParameterLocation::Body => {
+                openapi::Parameter::Query {
+                    parameter_data,
+                    allow_reserved: None,
+                    style: match parameter.items.collection_format.as_deref() {
+                        Some("ssv") => openapi::QueryStyle::SpaceDelimited,
+                        Some("pipes") => openapi::QueryStyle::PipeDelimited,
+                        _ => openapi::QueryStyle::Form,
+                    },
+                    allow_empty_value: parameter.allow_empty_value,
+                }
+            }
+        }
+    }
+
+    /// Builds the request body from an operation's `body` parameter, or
+    /// from its `formData` parameters as the properties of an object.
+    fn request_body(
+        &self,
+        parameters: &[&Parameter],
+        consumes: &[String],
+    ) -> Option<openapi::RequestBody> {
+        if let Some(body) = parameters
+            .iter()
+            .find(|parameter| parameter.location == ParameterLocation::Body)
+        {
+            let schema = body
+                .schema
+                .as_ref()
+                .map(rewrite_schema)
+                .unwrap_or_else(|| ReferenceOr::Item(Schema::default()));
+
+            let request_body = openapi::RequestBody {
+                description: body.description.clone(),
+                content: media_types(consumes, DEFAULT_MEDIA_TYPE, schema),
+                required: if body.required { Some(true) } else { None },
+                ..openapi::RequestBody::default()
+            };
+            return Some(request_body);
+        }
+
+        if parameters.is_empty() {
+            return None;
+        }
+
+        let mut schema = Schema {
+            schema_type: Some("object".into()),
+            properties: Some(
+                parameters
+                    .iter()
+                    .map(|parameter| {
+                        let mut property = items_schema(&parameter.items);
+                        property.description = parameter.description.clone();
+                        (parameter.name.clone(), property)
+                    })
+                    .collect(),
+            ),
+            ..Schema::default()
+        };
+        let required: Vec<String> = parameters
+            .iter()
+            .filter(|parameter| parameter.required)
+            .map(|parameter| parameter.name.clone())
+            .collect();
+        if !required.is_empty() {
+            schema.required = Some(required);
+        }
+
+        let has_files = parameters
+            .iter()
+            .any(|parameter| parameter.items.item_type.as_deref() == Some("file"));
+        let fallback = if has_files {
+            "multipart/form-data"
+        } else {
+            "application/x-www-form-urlencoded"
+        };
+        let form_media_types: Vec<String> = consumes
+            .iter()
+            .filter(|media_type| FORM_MEDIA_TYPES.contains(&media_type.as_str()))
+            .cloned()
+            .collect();
+
+        let request_body = openapi::RequestBody {
+            content: media_types(&form_media_types, fallback, ReferenceOr::Item(schema)),
+            required: if parameters.iter().any(|parameter| parameter.required) {
+                Some(true)
+            } else {
+                None
+            },
+            ..openapi::RequestBody::default()
+        };
+        Some(request_body)
+    }
+
+    fn response(&self, response: &Response, produces: &[String]) -> openapi::Response {
+        let mut converted = openapi::Response {
+            description: Some(response.description.clone()),
+            headers: response
+                .headers
+                .iter()
+                .map(|(name, header)| {
+                    let header = openapi::Header {
+                        description: header.description.clone(),
+                        style: openapi::HeaderStyle::Simple,
+                        required: None,
+                        deprecated: None,
+                        format: openapi::ParameterSchemaOrContent::Schema(ReferenceOr::Item(
+                            items_schema(&header.items),
+                        )),
+                        example: None,
+                        examples: BTreeMap::new(),
+                        extensions: extensions(&header.items.extensions),
+                    };
+                    (name.clone(), ReferenceOr::Item(header))
+                })
+                .collect(),
+            ..openapi::Response::default()
+        };
+
+        if let Some(schema) = &response.schema {
+            converted.content = media_types(produces, DEFAULT_MEDIA_TYPE, rewrite_schema(schema));
+
+            for (media_type, example) in &response.examples {
+                if let Some(content) = converted.content.get_mut(media_type) {
+                    content.example = Some(example.clone());
+                }
+            }
+        }
+
+        converted.extensions = extensions(&response.extensions);
+        converted
+    }
+
+    /// Resolves `#/parameters/{name}` references.
+    fn resolve<'b>(&'b self, parameter: &'b ReferenceOr<Parameter>) -> Option<&'b Parameter> {
+        match parameter {
+            ReferenceOr::Item(parameter) => Some(parameter),
+            ReferenceOr::Reference { reference } => self
+                .swagger
+                .parameters
+                .get(reference.strip_prefix("#/parameters/")?),
+        }
+    }
+}
+
+fn is_body(parameter: &Parameter) -> bool {
+    matches!(
+        parameter.location,
+        ParameterLocation::Body | ParameterLocation::FormData
+    )
+}
+
+/// Builds a content map with the same schema for each media type, using
+/// `fallback` when `media_types` is empty.
+fn media_types(
+    media_types: &[String],
+    fallback: &str,
+    schema: ReferenceOr<Schema>,
+) -> BTreeMap<String, openapi::MediaType> {
+    let media_types: Vec<&str> = if media_types.is_empty() {
+        vec![fallback]
+    } else {
+        media_types.iter().map(String::as_str).collect()
+    };
+
+    media_types
+        .into_iter()
+        .map(|media_type| {
+            let content = openapi::MediaType {
+                schema: Some(schema.clone()),
+                ..openapi::MediaType::default()
+            };
+            (media_type.to_string(), content)
+        })
+        .collect()
+}
+
+/// One server per scheme, built from `host` and `basePath`.
+fn servers(swagger: &Swagger) -> Vec<openapi::Server> {
+    let base_path = swagger.base_path.clone().unwrap_or_default();
+
+    let urls = match &swagger.host {
+        Some(host) if swagger.schemes.is_empty() => vec![format!("https://{}{}", host, base_path)],
+        Some(host) => swagger
+            .schemes
+            .iter()
+            .map(|scheme| format!("{}://{}{}", scheme, host, base_path))
+            .collect(),
+        None if base_path.is_empty() => vec![],
+        None => vec![base_path],
+    };
+
+    urls.into_iter()
+        .map(|url| openapi::Server {
+            url,
+            ..openapi::Server::default()
+        })
+        .collect()
+}
+
+/// Converts the type of a non-body parameter or header to a schema.
+/// `file` becomes a binary string.
+fn items_schema(items: &Items) -> Schema {
+    let mut schema = Schema::default();
+
+    match items.item_type.as_deref() {
+        Some("file") => {
+            schema.schema_type = Some("string".into());
+            schema.format = Some(String::from("binary"));
+        }
+        item_type => {
+            schema.schema_type = item_type.map(Into::into);
+            schema.format = items.format.clone();
+        }
+    }
+
+    schema.items = items
+        .items
+        .as_ref()
+        .map(|items| Box::new(items_schema(items)));
+    schema.default = items.default.clone();
+    schema.enum_values = items.enum_values.clone();
+    schema.maximum = items.maximum.clone();
+    schema.exclusive_maximum = items.exclusive_maximum.map(serde_json::Value::Bool);
+    schema.minimum = items.minimum.clone();
+    schema.exclusive_minimum = items.exclusive_minimum.map(serde_json::Value::Bool);
+    schema.max_length = items.max_length;
+    schema.min_length = items.min_length;
+    schema.pattern = items.pattern.clone();
+    schema.max_items = items.max_items;
+    schema.min_items = items.min_items;
+    schema.unique_items = items.unique_items;
+    schema.multiple_of = items.multiple_of.clone();
+    schema
+}
+
+fn security_scheme(scheme: &SecurityScheme) -> openapi::SecurityScheme {
+    match scheme {
+        SecurityScheme::Basic { .. } => openapi::SecurityScheme::Http {
+            scheme: String::from("basic"),
+            bearer_format: None,
+        },
+        SecurityScheme::APIKey { name, location, .. } => openapi::SecurityScheme::APIKey {
+            location: match location.as_str() {
+                "query" => openapi::APIKeyLocation::Query,
+                _ => openapi::APIKeyLocation::Header,
+            },
+            name: name.clone(),
+        },
+        SecurityScheme::OAuth2 {
+            flow,
+            authorization_url,
+            token_url,
+            scopes,
+            ..
+        } => {
+            let authorization_url = authorization_url.clone().unwrap_or_default();
+            let token_url = token_url.clone().unwrap_or_default();
+            let scopes = scopes.clone();
+
+            let mut flows = openapi::OAuth2Flows::default();
+            match flow.as_str() {
+                "implicit" => {
+                    flows.implicit = Some(openapi::OAuth2Flow::Implicit {
+                        authorization_url,
+                        refresh_url: None,
+                        scopes,
+                    })
+                }
+                "password" => {
+                    flows.password = Some(openapi::OAuth2Flow::Password {
+                        refresh_url: None,
+                        token_url,
+                        scopes,
+                    })
+                }
+                "application" => {
+                    flows.client_credentials = Some(openapi::OAuth2Flow::ClientCredentials {
+                        refresh_url: None,
+                        token_url,
+                        scopes,
+                    })
+                }
+                _ => {
+                    flows.authorization_code = Some(openapi::OAuth2Flow::AuthorizationCode {
+                        authorization_url,
+                        token_url,
+                        refresh_url: None,
+                        scopes,
+                    })
+                }
+            }
+
+            openapi::SecurityScheme::OAuth2 { flows }
+        }
+    }
+}
+
+/// Points a `$ref` to a definition, parameter or response at the
+/// matching component.
+fn rewrite(reference: &str) -> String {
+    let prefixes = [
+        ("#/definitions/", "#/components/schemas/"),
+        ("#/parameters/", "#/components/parameters/"),
+        ("#/responses/", "#/components/responses/"),
+    ];
+
+    for (from, to) in prefixes.iter() {
+        if let Some(name) = reference.strip_prefix(from) {
+            return format!("{}{}", to, name);
+        }
+    }
+
+    reference.to_string()
+}
+
+/// Rewrites every `$ref` in a schema. Nested `items` and `properties`
+/// are not `ReferenceOr` in the model, so their references are only
+/// reachable through the serialized schema.
+fn rewrite_schema(schema: &ReferenceOr<Schema>) -> ReferenceOr<Schema> {
+    fn rewrite_value(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(object) => {
+                for (key, value) in object.iter_mut() {
+                    match value {
+                        serde_json::Value::String(reference) if key == "$ref" => {
+                            *reference = rewrite(reference);
+                        }
+                        value => rewrite_value(value),
+                    }
+                }
+            }
+            serde_json::Value::Array(values) => values.iter_mut().for_each(rewrite_value),
+            _ => {}
+        }
+    }
+
+    let mut value = match serde_json::to_value(schema) {
+        Ok(value) => value,
+        Err(_) => return schema.clone(),
+    };
+    rewrite_value(&mut value);
+
+    serde_json::from_value(value).unwrap_or_else(|_| schema.clone())
+}
+
+/// Keeps the `x-` prefixed entries of a flattened map, which also
+/// receives the fields of other flattened structs.
+fn extensions(
+    extensions: &BTreeMap<String, serde_json::Value>,
+) -> BTreeMap<String, serde_json::Value> {
+    extensions
+        .iter()
+        .filter(|(key, _)| key.starts_with("x-"))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
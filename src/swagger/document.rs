@@ -0,0 +1,247 @@
+use crate::openapi::{ExternalDocumentation, Info, ReferenceOr, Schema, SecurityRequirement, Tag};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The root document object of a Swagger 2.0 API description.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#swagger-object>.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Swagger {
+    /// REQUIRED. The Swagger Specification version, which MUST be `2.0`.
+    pub swagger: String,
+    pub info: Info,
+    /// The host, and optionally the port, serving the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// The base path on which the API is served, relative to the host.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_path: Option<String>,
+    /// The transfer protocols of the API, such as `https`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schemes: Vec<String>,
+    /// The MIME types the API can consume, unless an operation overrides them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumes: Vec<String>,
+    /// The MIME types the API can produce, unless an operation overrides them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub produces: Vec<String>,
+    #[serde(default)]
+    pub paths: BTreeMap<String, PathItem>,
+    /// Schemas referenced as `#/definitions/{name}`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub definitions: BTreeMap<String, Schema>,
+    /// Parameters referenced as `#/parameters/{name}`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameters: BTreeMap<String, Parameter>,
+    /// Responses referenced as `#/responses/{name}`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub responses: BTreeMap<String, Response>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub security_definitions: BTreeMap<String, SecurityScheme>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<Vec<SecurityRequirement>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocumentation>,
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, serde_json::Value>,
+}
+
+/// Describes the operations available on a single path.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct PathItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub put: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<Operation>,
+    /// Parameters shared by all operations of the path. Operations can
+    /// override them, but cannot remove them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<ReferenceOr<Parameter>>,
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, serde_json::Value>,
+}
+
+/// Describes a single API operation on a path.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocumentation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+    /// Overrides the document's `consumes` when set, even to an empty list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumes: Option<Vec<String>>,
+    /// Overrides the document's `produces` when set, even to an empty list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub produces: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<ReferenceOr<Parameter>>,
+    /// Responses keyed by status code or `default`.
+    #[serde(default)]
+    pub responses: BTreeMap<String, ReferenceOr<Response>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schemes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<Vec<SecurityRequirement>>,
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, serde_json::Value>,
+}
+
+/// Describes a single operation parameter. Unlike OpenAPI 3, request
+/// bodies and form fields are parameters too.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Parameter {
+    pub name: String,
+    #[serde(rename = "in")]
+    pub location: ParameterLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    /// The schema of a `body` parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<ReferenceOr<Schema>>,
+    #[serde(rename = "allowEmptyValue", skip_serializing_if = "Option::is_none")]
+    pub allow_empty_value: Option<bool>,
+    /// The type of any other parameter.
+    #[serde(flatten)]
+    pub items: Items,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ParameterLocation {
+    Query,
+    Header,
+    Path,
+    FormData,
+    Body,
+}
+
+/// The type of a non-body parameter, a header or an array item, which is
+/// a limited subset of a schema.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#items-object>.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Items {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<Items>>,
+    /// How array values are serialized: `csv` (the default), `ssv`, `tsv`,
+    /// `pipes` or `multi`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub enum_values: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive_maximum: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive_minimum: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_items: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiple_of: Option<serde_json::Value>,
+    /// Inline extensions to this object.
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, serde_json::Value>,
+}
+
+/// Describes a single response from an API operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Response {
+    #[serde(default)]
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<ReferenceOr<Schema>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, Header>,
+    /// Examples keyed by MIME type.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub examples: BTreeMap<String, serde_json::Value>,
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Header {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(flatten)]
+    pub items: Items,
+}
+
+/// A security scheme that can be used by the operations.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#security-scheme-object>.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum SecurityScheme {
+    #[serde(rename = "basic")]
+    Basic {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
+    #[serde(rename = "apiKey")]
+    APIKey {
+        name: String,
+        #[serde(rename = "in")]
+        location: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
+    #[serde(rename = "oauth2")]
+    OAuth2 {
+        /// `implicit`, `password`, `application` or `accessCode`.
+        flow: String,
+        #[serde(rename = "authorizationUrl", skip_serializing_if = "Option::is_none")]
+        authorization_url: Option<String>,
+        #[serde(rename = "tokenUrl", skip_serializing_if = "Option::is_none")]
+        token_url: Option<String>,
+        #[serde(default)]
+        scopes: BTreeMap<String, String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
+}
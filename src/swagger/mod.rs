@@ -0,0 +1,86 @@
+mod convert;
+mod document;
+
+pub use document::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openapi::{OpenAPI, Parameter, ReferenceOr};
+
+    fn convert(yaml: &str) -> OpenAPI {
+        let swagger: Swagger = serde_yaml::from_str(yaml).expect("Failed to parse Swagger");
+        OpenAPI::from(swagger)
+    }
+
+    #[test]
+    fn servers_and_definitions() {
+        let openapi = convert(
+            "swagger: '2.0'\ninfo:\n  title: Pets\n  version: '1'\nhost: api.example.com\nbasePath: /v1\nschemes: [http, https]\npaths: {}\ndefinitions:\n  Pets:\n    type: array\n    items:\n      $ref: '#/definitions/Pet'\n  Pet:\n    type: object\n",
+        );
+
+        assert_eq!("2.0", openapi.openapi);
+        assert_eq!("http://api.example.com/v1", openapi.servers[0].url);
+        assert_eq!("https://api.example.com/v1", openapi.servers[1].url);
+
+        let components = openapi.components.unwrap();
+        let pets = match &components.schemas["Pets"] {
+            ReferenceOr::Item(schema) => schema,
+            ReferenceOr::Reference { .. } => panic!("Expected an inline schema"),
+        };
+        assert_eq!(
+            Some(&serde_json::json!("#/components/schemas/Pet")),
+            pets.items.as_ref().unwrap().extensions.get("$ref")
+        );
+    }
+
+    #[test]
+    fn body_and_form_parameters() {
+        let openapi = convert(
+            "swagger: '2.0'\ninfo:\n  title: Pets\n  version: '1'\nconsumes: [application/json]\nproduces: [application/xml]\nparameters:\n  Pet:\n    name: pet\n    in: body\n    required: true\n    schema:\n      $ref: '#/definitions/Pet'\npaths:\n  /pets:\n    parameters:\n      - $ref: '#/parameters/Pet'\n    post:\n      parameters:\n        - name: dryRun\n          in: query\n          type: boolean\n      responses:\n        '201':\n          description: Created\n          schema:\n            $ref: '#/definitions/Pet'\n  /photos:\n    put:\n      consumes: [multipart/form-data]\n      parameters:\n        - name: photo\n          in: formData\n          type: file\n          required: true\n      responses:\n        default:\n          description: Error\n",
+        );
+        let path_item = match &openapi.paths["/pets"] {
+            ReferenceOr::Item(path_item) => path_item,
+            ReferenceOr::Reference { .. } => panic!("Expected an inline path item"),
+        };
+        assert!(path_item.parameters.is_empty());
+
+        let post = path_item.post.as_ref().unwrap();
+        assert!(matches!(
+            post.parameters[0],
+            ReferenceOr::Item(Parameter::Query { .. })
+        ));
+        let request_body = match post.request_body.as_ref().unwrap() {
+            ReferenceOr::Item(request_body) => request_body,
+            ReferenceOr::Reference { .. } => panic!("Expected an inline request body"),
+        };
+        assert_eq!(Some(true), request_body.required);
+        assert_eq!(
+            Some(ReferenceOr::ref_("#/components/schemas/Pet")),
+            request_body.content["application/json"].schema
+        );
+        let created = match &post.responses.responses.values().next().unwrap() {
+            ReferenceOr::Item(response) => response.clone(),
+            ReferenceOr::Reference { .. } => panic!("Expected an inline response"),
+        };
+        assert!(created.content.contains_key("application/xml"));
+
+        let put = match &openapi.paths["/photos"] {
+            ReferenceOr::Item(path_item) => path_item.put.as_ref().unwrap(),
+            ReferenceOr::Reference { .. } => panic!("Expected an inline path item"),
+        };
+        let form = match put.request_body.as_ref().unwrap() {
+            ReferenceOr::Item(request_body) => &request_body.content["multipart/form-data"],
+            ReferenceOr::Reference { .. } => panic!("Expected an inline request body"),
+        };
+        let form_schema = match form.schema.as_ref().unwrap() {
+            ReferenceOr::Item(schema) => schema,
+            ReferenceOr::Reference { .. } => panic!("Expected an inline schema"),
+        };
+        let photo = &form_schema.properties.as_ref().unwrap()["photo"];
+        assert_eq!(Some(String::from("binary")), photo.format);
+        assert_eq!(Some(vec![String::from("photo")]), form_schema.required);
+        assert!(put.responses.default.is_some());
+        assert!(put.parameters.is_empty());
+    }
+}